@@ -0,0 +1,73 @@
+use crate::adressable::Addressable;
+use std::ops::{Add, Neg, Sub};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Address3D {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Addressable<i32, 3usize> for Address3D {
+    fn get_value_at_dimension_index(&self, index: usize) -> i32 {
+        match index {
+            0 => self.x,
+            1 => self.y,
+            2 => self.z,
+            _ => panic!("Invalid Dimension Index"),
+        }
+    }
+}
+
+impl From<[i32; 3]> for Address3D {
+    fn from(value: [i32; 3]) -> Self {
+        Self {
+            x: value[0],
+            y: value[1],
+            z: value[2],
+        }
+    }
+}
+
+impl From<Address3D> for [i32; 3] {
+    fn from(value: Address3D) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl Add for Address3D {
+    type Output = Address3D;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Address3D {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub for Address3D {
+    type Output = Address3D;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Address3D {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl Neg for Address3D {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Address3D {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
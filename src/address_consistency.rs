@@ -0,0 +1,149 @@
+//! Cross-checks of the row-major numbering of addresses, which the address iterator, the tensors'
+//! `index_of` and `address_of`, and the random access of the iterator each implement on their own.
+
+use crate::address_3d::Address3D;
+use crate::address_iterator::AddressIterator;
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::nd_address::NDAddress;
+use crate::nd_tensor::NDTensor;
+use crate::tensor::Tensor;
+use crate::tensor_3d::Tensor3D;
+use proptest::prelude::*;
+use std::collections::VecDeque;
+
+/// A step taken on an address iterator and on the list of addresses it should yield.
+#[derive(Debug, Clone)]
+enum Step {
+    Next,
+    NextBack,
+    Nth(usize),
+    NthBack(usize),
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    prop_oneof![
+        Just(Step::Next),
+        Just(Step::NextBack),
+        (0usize..12).prop_map(Step::Nth),
+        (0usize..12).prop_map(Step::NthBack),
+    ]
+}
+
+/// Applies the steps to the iterator and to the expected addresses, checking that both agree.
+fn check_steps<A: PartialEq + std::fmt::Debug>(
+    mut iterator: impl DoubleEndedIterator<Item = A> + ExactSizeIterator,
+    mut expected: VecDeque<A>,
+    steps: &[Step],
+) {
+    for step in steps {
+        let (actual, wanted) = match *step {
+            Step::Next => (iterator.next(), expected.pop_front()),
+            Step::NextBack => (iterator.next_back(), expected.pop_back()),
+            Step::Nth(n) => {
+                let skipped = n.min(expected.len());
+                expected.drain(..skipped);
+                (iterator.nth(n), expected.pop_front())
+            }
+            Step::NthBack(n) => {
+                let kept = expected.len().saturating_sub(n);
+                expected.truncate(kept);
+                (iterator.nth_back(n), expected.pop_back())
+            }
+        };
+        assert_eq!(actual, wanted, "after {:?}", step);
+        assert_eq!(iterator.len(), expected.len());
+    }
+    assert!(iterator.eq(expected));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn matrix_numbering_test(width in 1usize..12, height in 1usize..12) {
+        let matrix = Matrix::new(width, height, |_| ()).unwrap();
+        for (index, address) in matrix.address_iter().enumerate() {
+            assert_eq!(matrix.index_of(address), Some(index));
+            assert_eq!(matrix.address_of(index), Some(address));
+        }
+        assert_eq!(matrix.address_of(width * height), None);
+        assert_eq!(matrix.index_of(MatrixAddress { x: width as i32, y: 0 }), None);
+        assert_eq!(matrix.index_of(MatrixAddress { x: 0, y: -1 }), None);
+    }
+
+    #[test]
+    fn tensor_3d_numbering_test(width in 1usize..6, height in 1usize..6, depth in 1usize..6) {
+        let tensor = Tensor3D::new(width, height, depth, |_| ()).unwrap();
+        for (index, address) in tensor.address_iter().enumerate() {
+            assert_eq!(tensor.index_of(address), Some(index));
+            assert_eq!(tensor.address_of(index), Some(address));
+        }
+        assert_eq!(tensor.address_of(width * height * depth), None);
+        assert_eq!(tensor.index_of(Address3D { x: 0, y: 0, z: depth as i32 }), None);
+    }
+
+    #[test]
+    fn nd_tensor_numbering_test(shape in proptest::array::uniform4(1usize..4)) {
+        let tensor = NDTensor::new(shape, |_| ()).unwrap();
+        for (index, address) in tensor.address_iter().enumerate() {
+            assert_eq!(tensor.index_of(address), Some(index));
+            assert_eq!(tensor.address_of(index), Some(address));
+        }
+        assert_eq!(tensor.address_of(shape.iter().product()), None);
+        assert_eq!(tensor.index_of(NDAddress([0, 0, shape[2] as i32, 0])), None);
+    }
+
+    #[test]
+    fn matrix_matches_2d_tensor_test(width in 1usize..10, height in 1usize..10) {
+        let matrix = Matrix::new(width, height, |address| address).unwrap();
+        let tensor = NDTensor::new([width, height], |address| address).unwrap();
+        assert!(matrix.iter().map(|address| [address.x, address.y]).eq(tensor.address_iter().map(|address| address.0)));
+        for index in 0..width * height {
+            assert_eq!(matrix.address_of(index).map(|address| [address.x, address.y]), tensor.address_of(index).map(|address| address.0));
+        }
+    }
+
+    #[test]
+    fn iterator_steps_test(
+        lower in proptest::array::uniform2(-6i32..6),
+        span in proptest::array::uniform2(-1i32..6),
+        steps in proptest::collection::vec(step(), 0..12),
+    ) {
+        let upper = [lower[0] + span[0], lower[1] + span[1]];
+        let mut expected = VecDeque::new();
+        for y in lower[1]..=upper[1] {
+            for x in lower[0]..=upper[0] {
+                expected.push_back(MatrixAddress { x, y });
+            }
+        }
+        let iterator = AddressIterator::<i32, MatrixAddress, 2>::new(lower, upper);
+        assert_eq!(iterator.len(), expected.len());
+        check_steps(iterator, expected, &steps);
+    }
+
+    #[test]
+    fn iterator_nth_matches_position_test(
+        lower in proptest::array::uniform3(-4i32..4),
+        span in proptest::array::uniform3(0i32..4),
+        n in 0usize..80,
+    ) {
+        let upper = std::array::from_fn(|dimension| lower[dimension] + span[dimension]);
+        let addresses = AddressIterator::<i32, Address3D, 3>::new(lower, upper).collect::<Vec<_>>();
+        assert_eq!(AddressIterator::<i32, Address3D, 3>::new(lower, upper).nth(n), addresses.get(n).copied());
+        assert_eq!(
+            AddressIterator::<i32, Address3D, 3>::new(lower, upper).nth_back(n),
+            addresses.len().checked_sub(n + 1).map(|index| addresses[index])
+        );
+        let origin_anchored = span.map(|span| span as usize + 1);
+        let tensor = Tensor3D::new(origin_anchored[0], origin_anchored[1], origin_anchored[2], |_| ()).unwrap();
+        for (index, address) in addresses.iter().enumerate() {
+            let shifted = Address3D {
+                x: address.x - lower[0],
+                y: address.y - lower[1],
+                z: address.z - lower[2],
+            };
+            assert_eq!(tensor.index_of(shifted), Some(index));
+        }
+    }
+}
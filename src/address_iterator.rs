@@ -2,11 +2,28 @@ use crate::adressable::{AddressValue, Addressable};
 use crate::tensor::Tensor;
 use std::marker::PhantomData;
 
+/// Iterates over every address between two bounds, with dimension 0 varying fastest.
+///
+/// Addresses are numbered by their position in the iteration order. Alongside the next address to
+/// yield from each end, the iterator keeps its number, which makes the number of remaining addresses
+/// known without iterating.
+///
+/// When the bounds hold more addresses than fit in a `usize`, the addresses are not numbered. The
+/// iterator then still walks every address, but skipping is done one address at a time, the size
+/// hint has no upper bound, and `len` panics.
 pub struct AddressIterator<V: Copy + From<u8>, A: Addressable<V, DIMENSION>, const DIMENSION: usize>
 {
     lower_bounds_inclusive: [V; DIMENSION],
     upper_bounds_inclusive: [V; DIMENSION],
-    current_position: [V; DIMENSION],
+    /// The number of values along each dimension, None if the addresses are not numbered.
+    lengths: Option<[usize; DIMENSION]>,
+    front_position: [V; DIMENSION],
+    front_index: usize,
+    back_position: [V; DIMENSION],
+    /// One past the number of the next address to yield from the back.
+    end_index: usize,
+    /// Whether every address has been yielded, from either end.
+    exhausted: bool,
     _marker: PhantomData<A>,
 }
 
@@ -30,15 +47,87 @@ impl<V: AddressValue, A: Addressable<V, DIMENSION>, const DIMENSION: usize>
         lower_bounds_inclusive: [V; DIMENSION],
         upper_bounds_inclusive: [V; DIMENSION],
     ) -> Self {
-        let mut lower_bounds_copy: [V; DIMENSION] = lower_bounds_inclusive;
-        lower_bounds_copy[0] = lower_bounds_copy[0] - 1.into();
+        let exhausted = (0..DIMENSION)
+            .any(|dimension| upper_bounds_inclusive[dimension] < lower_bounds_inclusive[dimension]);
+        let lengths: Option<[Option<usize>; DIMENSION]> = (!exhausted).then(|| {
+            std::array::from_fn(|dimension| {
+                span(
+                    lower_bounds_inclusive[dimension],
+                    upper_bounds_inclusive[dimension],
+                )?
+                .checked_add(1)
+            })
+        });
+        let lengths = lengths
+            .filter(|lengths| lengths.iter().all(Option::is_some))
+            .map(|lengths| lengths.map(|length| length.unwrap_or_default()));
+        let end_index = lengths.and_then(|lengths| {
+            lengths
+                .iter()
+                .try_fold(1usize, |count, &length| count.checked_mul(length))
+        });
         Self {
             lower_bounds_inclusive,
             upper_bounds_inclusive,
-            current_position: lower_bounds_copy,
+            lengths: end_index.and(lengths),
+            front_position: lower_bounds_inclusive,
+            front_index: 0,
+            back_position: upper_bounds_inclusive,
+            end_index: end_index.unwrap_or(0),
+            exhausted,
             _marker: PhantomData,
         }
     }
+
+    /// The number of addresses left to yield, None if the addresses are not numbered.
+    fn remaining(&self) -> Option<usize> {
+        if self.exhausted {
+            Some(0)
+        } else {
+            self.lengths.map(|_| self.end_index - self.front_index)
+        }
+    }
+
+    /// Computes the address at a position in the iteration order, in O(DIMENSION) time. Only called
+    /// when the addresses are numbered.
+    fn address_at(&self, lengths: [usize; DIMENSION], mut index: usize) -> [V; DIMENSION] {
+        let mut address = self.lower_bounds_inclusive;
+        for (value, length) in address.iter_mut().zip(lengths) {
+            *value = add_offset(*value, index % length);
+            index /= length;
+        }
+        address
+    }
+}
+
+/// Adds an offset which may not fit in the address value type itself, such as a distance from a
+/// negative lower bound, by adding it in halves.
+fn add_offset<V: AddressValue>(value: V, offset: usize) -> V {
+    match V::try_from(offset) {
+        Ok(offset) => value + offset,
+        Err(_) => {
+            let half = offset / 2;
+            add_offset(add_offset(value, half), offset - half)
+        }
+    }
+}
+
+/// Computes `upper - lower` for `lower <= upper` as a `usize`, without overflowing the address value
+/// type when the bounds lie on either side of zero. Returns None if the span does not fit.
+fn span<V: AddressValue>(lower: V, upper: V) -> Option<usize> {
+    let (zero, one) = (V::from(0), V::from(1));
+    let magnitude = |value: V| -> Option<usize> { value.try_into().ok() };
+    // For a negative value, `0 - (value + 1)` is one less than its magnitude and cannot overflow.
+    let below_zero = |value: V| magnitude(zero - (value + one));
+    if lower >= zero {
+        Some(magnitude(upper)? - magnitude(lower)?)
+    } else if upper >= zero {
+        magnitude(upper)?
+            .checked_add(below_zero(lower)?)?
+            .checked_add(1)
+    } else {
+        Some(below_zero(lower)? - below_zero(upper)?)
+    }
 }
 
 impl<
@@ -68,21 +157,98 @@ impl<V: AddressValue, A: Addressable<V, DIMENSION>, const DIMENSION: usize> Iter
     type Item = A;
 
     fn next(&mut self) -> Option<Self::Item> {
-        for dimension_index in 0..DIMENSION {
-            if self.current_position[dimension_index] < self.upper_bounds_inclusive[dimension_index]
-            {
-                self.current_position[dimension_index] =
-                    self.current_position[dimension_index] + 1.into();
-                return Some(self.current_position.into());
-            } else {
-                self.current_position[dimension_index] =
-                    self.lower_bounds_inclusive[dimension_index];
+        if self.exhausted {
+            return None;
+        }
+        let address = self.front_position;
+        self.front_index += usize::from(self.lengths.is_some());
+        if self.front_position == self.back_position {
+            self.exhausted = true;
+            return Some(address.into());
+        }
+        for dimension in 0..DIMENSION {
+            if self.front_position[dimension] < self.upper_bounds_inclusive[dimension] {
+                self.front_position[dimension] = self.front_position[dimension] + 1.into();
+                break;
             }
+            self.front_position[dimension] = self.lower_bounds_inclusive[dimension];
+        }
+        Some(address.into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    /// Skips directly to the `n`th next address without visiting the addresses before it, if the
+    /// addresses are numbered.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (Some(lengths), Some(remaining)) = (self.lengths, self.remaining()) else {
+            for _ in 0..n {
+                self.next()?;
+            }
+            return self.next();
+        };
+        if n >= remaining {
+            self.exhausted = true;
+            return None;
         }
-        None
+        self.front_index += n;
+        self.front_position = self.address_at(lengths, self.front_index);
+        self.next()
     }
 }
 
+impl<V: AddressValue, A: Addressable<V, DIMENSION>, const DIMENSION: usize> DoubleEndedIterator
+    for AddressIterator<V, A, DIMENSION>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let address = self.back_position;
+        self.end_index -= usize::from(self.lengths.is_some());
+        if self.front_position == self.back_position {
+            self.exhausted = true;
+            return Some(address.into());
+        }
+        for dimension in 0..DIMENSION {
+            if self.back_position[dimension] > self.lower_bounds_inclusive[dimension] {
+                self.back_position[dimension] = self.back_position[dimension] - 1.into();
+                break;
+            }
+            self.back_position[dimension] = self.upper_bounds_inclusive[dimension];
+        }
+        Some(address.into())
+    }
+
+    /// Skips directly to the `n`th previous address without visiting the addresses after it, if the
+    /// addresses are numbered.
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (Some(lengths), Some(remaining)) = (self.lengths, self.remaining()) else {
+            for _ in 0..n {
+                self.next_back()?;
+            }
+            return self.next_back();
+        };
+        if n >= remaining {
+            self.exhausted = true;
+            return None;
+        }
+        self.end_index -= n;
+        self.back_position = self.address_at(lengths, self.end_index - 1);
+        self.next_back()
+    }
+}
+
+impl<V: AddressValue, A: Addressable<V, DIMENSION>, const DIMENSION: usize> ExactSizeIterator
+    for AddressIterator<V, A, DIMENSION>
+{
+}
+
 impl<
     'a,
     T: 'a,
@@ -101,13 +267,47 @@ impl<
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.address_iterator.size_hint()
+    }
+}
+
+impl<
+    'a,
+    T: 'a,
+    V: AddressValue,
+    A: Addressable<V, DIMENSION>,
+    TENSOR: Tensor<'a, T, V, A, DIMENSION>,
+    const DIMENSION: usize,
+> DoubleEndedIterator for AddressValueIterator<'a, T, V, A, TENSOR, DIMENSION>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.address_iterator
+            .next_back()
+            .map(|address| (address, &self.tensor[address]))
+    }
+}
+
+impl<
+    'a,
+    T: 'a,
+    V: AddressValue,
+    A: Addressable<V, DIMENSION>,
+    TENSOR: Tensor<'a, T, V, A, DIMENSION>,
+    const DIMENSION: usize,
+> ExactSizeIterator for AddressValueIterator<'a, T, V, A, TENSOR, DIMENSION>
+{
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::address_3d::Address3D;
+    use crate::address_iterator::AddressIterator;
     use crate::matrix::Matrix;
     use crate::matrix_address::MatrixAddress;
     use crate::tensor::Tensor;
+    use crate::tensor_3d::Tensor3D;
 
     // Working address iterator from the previous version
     pub struct MatrixAddressIterator {
@@ -156,7 +356,8 @@ mod tests {
         let (width, height) = (1000, 2000);
         let matrix = Matrix::new(width, height, |address| {
             address.y * width as i32 + address.x
-        }).unwrap();
+        })
+        .unwrap();
         let address_iter = matrix.address_iter();
         let address_value_iter = matrix.address_value_iter();
         address_iter
@@ -172,6 +373,186 @@ mod tests {
         let (width, height) = (1000, 1000);
         let matrix = Matrix::new(width, height, |_| 0u8).unwrap();
         let matrix = matrix.transform(|address, _value| address.y * width as i32 + address.x);
-        matrix.address_value_iter().for_each(|(address, value)| assert_eq!(address.y * width as i32 + address.x, *value));
+        matrix
+            .address_value_iter()
+            .for_each(|(address, value)| assert_eq!(address.y * width as i32 + address.x, *value));
+    }
+
+    #[test]
+    fn exact_size_test() {
+        let matrix = Matrix::new(7, 5, |_| 0).unwrap();
+        let mut address_iter = matrix.address_iter();
+        assert_eq!(address_iter.len(), 7 * 5);
+        assert_eq!(address_iter.size_hint(), (35, Some(35)));
+        for remaining in (0..35).rev() {
+            assert!(address_iter.next().is_some());
+            assert_eq!(address_iter.len(), remaining);
+        }
+        assert_eq!(address_iter.next(), None);
+        assert_eq!(address_iter.next(), None);
+        assert_eq!(address_iter.len(), 0);
+        assert_eq!(matrix.address_value_iter().len(), 35);
+
+        let tensor = Tensor3D::new(3, 4, 5, |_| 0).unwrap();
+        assert_eq!(tensor.address_iter().len(), 60);
+        assert_eq!(
+            Matrix::<i32>::from_rows(vec![vec![], vec![]])
+                .unwrap()
+                .address_iter()
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn reverse_test() {
+        let matrix = Matrix::new(13, 7, |address| address.x * address.y).unwrap();
+        let mut forward = matrix.address_iter().collect::<Vec<_>>();
+        forward.reverse();
+        assert_eq!(matrix.address_iter().rev().collect::<Vec<_>>(), forward);
+        let tensor = Tensor3D::new(3, 4, 5, |address| address.x + address.y + address.z).unwrap();
+        let mut forward = tensor.address_value_iter().collect::<Vec<_>>();
+        forward.reverse();
+        assert_eq!(
+            tensor.address_value_iter().rev().collect::<Vec<_>>(),
+            forward
+        );
+        assert_eq!(
+            Matrix::<i32>::from_rows(vec![])
+                .unwrap()
+                .address_iter()
+                .next_back(),
+            None
+        );
+    }
+
+    #[test]
+    fn meet_in_the_middle_test() {
+        let matrix = Matrix::new(4, 3, |_| 0).unwrap();
+        let expected = matrix.address_iter().collect::<Vec<_>>();
+        for front_count in 0..=12 {
+            let mut address_iter = matrix.address_iter();
+            let front = address_iter.by_ref().take(front_count).collect::<Vec<_>>();
+            let mut back = address_iter.by_ref().rev().collect::<Vec<_>>();
+            back.reverse();
+            assert_eq!(address_iter.len(), 0);
+            assert_eq!(address_iter.next(), None);
+            assert_eq!(address_iter.next_back(), None);
+            assert_eq!([front, back].concat(), expected);
+        }
+        let mut address_iter = matrix.address_iter();
+        let mut alternating = Vec::new();
+        while let Some(address) = address_iter.next() {
+            alternating.push(address);
+            alternating.extend(address_iter.next_back());
+        }
+        assert_eq!(alternating.len(), 12);
+        assert_eq!(alternating[1], MatrixAddress { x: 3, y: 2 });
+        assert_eq!(alternating[10], MatrixAddress { x: 1, y: 1 });
+        assert_eq!(alternating[11], MatrixAddress { x: 2, y: 1 });
+    }
+
+    #[test]
+    fn nth_test() {
+        let tensor = Tensor3D::new(4, 3, 5, |_| 0).unwrap();
+        let expected = tensor.address_iter().collect::<Vec<_>>();
+        for n in 0..70 {
+            assert_eq!(tensor.address_iter().nth(n), expected.get(n).copied());
+            assert_eq!(
+                tensor.address_iter().nth_back(n),
+                expected.iter().rev().nth(n).copied()
+            );
+        }
+        let mut address_iter = tensor.address_iter();
+        assert_eq!(address_iter.nth(10), Some(expected[10]));
+        assert_eq!(address_iter.next(), Some(expected[11]));
+        assert_eq!(address_iter.nth_back(5), Some(expected[54]));
+        assert_eq!(address_iter.next_back(), Some(expected[53]));
+        assert_eq!(address_iter.len(), 53 - 12);
+        assert_eq!(address_iter.nth(20), Some(expected[32]));
+        assert_eq!(address_iter.nth(100), None);
+        assert_eq!(address_iter.next(), None);
+        assert_eq!(address_iter.next_back(), None);
+    }
+
+    #[test]
+    fn nth_large_test() {
+        let address_iter =
+            || AddressIterator::<i32, MatrixAddress, 2>::new([0, 0], [19_999, 19_999]);
+        assert_eq!(address_iter().len(), 400_000_000);
+        let mut skipped = address_iter().skip(399_999_999);
+        assert_eq!(
+            skipped.next(),
+            Some(MatrixAddress {
+                x: 19_999,
+                y: 19_999
+            })
+        );
+        assert_eq!(skipped.next(), None);
+        assert_eq!(
+            address_iter().nth(123_456_789),
+            Some(MatrixAddress {
+                x: 16_789,
+                y: 6_172
+            })
+        );
+    }
+
+    #[test]
+    fn full_range_test() {
+        let mut address_iter =
+            AddressIterator::<i32, MatrixAddress, 2>::new([i32::MIN, -1], [i32::MAX, -1]);
+        assert_eq!(address_iter.len(), 1 << 32);
+        assert_eq!(
+            address_iter.next(),
+            Some(MatrixAddress { x: i32::MIN, y: -1 })
+        );
+        assert_eq!(
+            address_iter.next_back(),
+            Some(MatrixAddress { x: i32::MAX, y: -1 })
+        );
+        assert_eq!(
+            address_iter.nth(i32::MAX as usize),
+            Some(MatrixAddress { x: 0, y: -1 })
+        );
+        let negative = AddressIterator::<i32, MatrixAddress, 2>::new([i32::MIN, 0], [-1, 0]);
+        assert_eq!(negative.len(), 1 << 31);
+    }
+
+    #[test]
+    fn too_many_to_count_test() {
+        let side = (1 << 22) + 1;
+        let mut address_iter =
+            AddressIterator::<i32, Address3D, 3>::new([0, 0, 0], [side - 1, side - 1, side - 1]);
+        assert_eq!(address_iter.size_hint(), (usize::MAX, None));
+        assert_eq!(address_iter.next(), Some(Address3D { x: 0, y: 0, z: 0 }));
+        assert_eq!(
+            address_iter.nth(side as usize),
+            Some(Address3D { x: 1, y: 1, z: 0 })
+        );
+        assert_eq!(
+            address_iter.next_back(),
+            Some(Address3D {
+                x: side - 1,
+                y: side - 1,
+                z: side - 1
+            })
+        );
+        assert_eq!(
+            address_iter.nth_back(1),
+            Some(Address3D {
+                x: side - 3,
+                y: side - 1,
+                z: side - 1
+            })
+        );
+
+        let mut meeting = AddressIterator::<i32, MatrixAddress, 2>::new([0, 0], [1, 0]);
+        meeting.lengths = None;
+        assert_eq!(meeting.next_back(), Some(MatrixAddress { x: 1, y: 0 }));
+        assert_eq!(meeting.next(), Some(MatrixAddress { x: 0, y: 0 }));
+        assert_eq!(meeting.next(), None);
+        assert_eq!(meeting.next_back(), None);
+        assert_eq!(meeting.size_hint(), (0, Some(0)));
     }
 }
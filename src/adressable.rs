@@ -9,9 +9,28 @@ pub trait Addressable<V, const DIMENSION: usize>:
     fn get_value_at_dimension_index(&self, index: usize) -> V;
 }
 
+/// A value along one dimension of an address.
+/// Converting spans of values to and from `usize` lets address iteration count and skip addresses
+/// without stepping.
 pub trait AddressValue:
-    Copy + From<u8> + Add<Output = Self> + Sub<Output = Self> + PartialOrd
+    Copy
+    + From<u8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + PartialOrd
+    + TryInto<usize>
+    + TryFrom<usize>
 {
 }
 
-impl<T: Copy + From<u8> + Add<Output = Self> + Sub<Output = Self> + PartialOrd> AddressValue for T {}
+impl<
+    T: Copy
+        + From<u8>
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + PartialOrd
+        + TryInto<usize>
+        + TryFrom<usize>,
+> AddressValue for T
+{
+}
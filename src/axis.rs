@@ -0,0 +1,21 @@
+/// An axis of a two-dimensional tensor, used to pick the direction of reductions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Axis {
+    /// Each row is reduced to a single value, leaving one value per row.
+    Rows,
+    /// Each column is reduced to a single value, leaving one value per column.
+    Columns,
+}
+
+impl Axis {
+    /// The index of the dimension which is collapsed when reducing along this axis,
+    /// matching the dimension indices used by `Addressable::get_value_at_dimension_index`.
+    ///
+    /// Returns: `usize`, 0 (the x dimension) for `Rows` and 1 (the y dimension) for `Columns`.
+    pub fn collapsed_dimension_index(self) -> usize {
+        match self {
+            Axis::Rows => 0,
+            Axis::Columns => 1,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+/// The byte order used to encode the elements of a matrix.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+/// A primitive numeric type with a fixed size binary representation.
+pub trait BinaryElement: Copy {
+    /// The number of bytes in the binary representation of a value.
+    const SIZE: usize;
+
+    /// Decodes a value from exactly `SIZE` bytes.
+    fn from_bytes(bytes: &[u8], endianness: Endianness) -> Self;
+
+    /// Appends the `SIZE` bytes encoding this value to `output`.
+    fn write_bytes(&self, endianness: Endianness, output: &mut Vec<u8>);
+}
+
+macro_rules! impl_binary_element {
+    ($($t:ty),*) => {
+        $(
+            impl BinaryElement for $t {
+                const SIZE: usize = size_of::<$t>();
+
+                fn from_bytes(bytes: &[u8], endianness: Endianness) -> Self {
+                    let bytes = bytes.try_into().expect("Incorrect number of bytes for element");
+                    match endianness {
+                        Endianness::Little => <$t>::from_le_bytes(bytes),
+                        Endianness::Big => <$t>::from_be_bytes(bytes),
+                    }
+                }
+
+                fn write_bytes(&self, endianness: Endianness, output: &mut Vec<u8>) {
+                    match endianness {
+                        Endianness::Little => output.extend_from_slice(&self.to_le_bytes()),
+                        Endianness::Big => output.extend_from_slice(&self.to_be_bytes()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_element!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
@@ -1,5 +1,15 @@
+pub mod address_3d;
+#[cfg(test)]
+mod address_consistency;
 mod address_iterator;
 pub mod adressable;
+pub mod axis;
+pub mod binary;
 pub mod matrix;
 pub mod matrix_address;
+pub mod matrix_error;
+pub mod nd_address;
+pub mod nd_tensor;
+pub mod s_matrix;
 pub mod tensor;
+pub mod tensor_3d;
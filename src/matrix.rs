@@ -1,9 +1,67 @@
+use crate::axis::Axis;
 use crate::matrix_address::MatrixAddress;
+use crate::matrix_error::{DimensionMismatchError, MatrixError};
 use crate::tensor::Tensor;
 use std::fmt::{Display, Formatter};
 use std::ops::{Index, IndexMut};
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+mod aggregate;
+#[cfg(feature = "approx")]
+mod approx_eq;
+mod arithmetic;
+mod binary;
+mod convolution;
+mod cow;
+mod csv;
+mod frozen;
+mod linalg;
+mod lockstep;
+mod mapped;
+mod mutate;
+#[cfg(feature = "npy")]
+mod npy;
+mod op;
+mod orientation;
+mod padding;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod quadtree;
+mod render;
+mod search;
+#[cfg(feature = "serde")]
+mod serialization;
+mod sparse;
+mod stencil;
+mod structure;
+mod tagged;
+mod validation;
+mod view;
+mod windows;
+
+pub use cow::CowMatrix;
+pub use frozen::FrozenMatrix;
+pub use linalg::LuDecomposition;
+pub use lockstep::Lockstep;
+pub use mapped::MappedView;
+pub use op::MatrixOp;
+pub use padding::EdgeMode;
+pub use quadtree::QuadTree;
+pub use render::DEFAULT_PALETTE;
+pub use search::manhattan_heuristic;
+pub use sparse::{OutOfBoundsPolicy, SparseMatrix};
+pub use stencil::IterationOutcome;
+pub use structure::EditRecord;
+pub use tagged::TaggedMatrix;
+pub use validation::{ValidationRule, Violation};
+pub use view::{MatrixView, MatrixViewMut};
+pub use windows::WindowIter;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "serialization::SerializedMatrix<T>")
+)]
 pub struct Matrix<T> {
     width: usize,
     height: usize,
@@ -60,9 +118,218 @@ impl<T> Matrix<T> {
         matrix
             .address_iter()
             .for_each(|address| matrix.data.push(address_value_converter(address)));
+        matrix.debug_assert_invariants();
         Some(matrix)
     }
 
+    /// Creates a matrix from a list of rows, moving the values into the matrix.
+    /// Will return an Err naming the first row whose length differs from the first row's.
+    /// An empty list of rows produces a 0x0 matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: The rows of the matrix, from top to bottom
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+    /// assert_eq!(matrix, Matrix::new(3, 2, |address| address.x + 3 * address.y).unwrap());
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Matrix<T>, DimensionMismatchError> {
+        let (width, height) = Self::check_uniform_lengths(&rows, "Row")?;
+        Ok(Matrix::from_parts(
+            width,
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    /// Creates a matrix from a list of columns, moving the values into the matrix.
+    /// Will return an Err naming the first column whose length differs from the first column's.
+    /// An empty list of columns produces a 0x0 matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `cols`: The columns of the matrix, from left to right
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_cols(vec![vec![0, 3], vec![1, 4], vec![2, 5]]).unwrap();
+    /// assert_eq!(matrix, Matrix::new(3, 2, |address| address.x + 3 * address.y).unwrap());
+    /// ```
+    pub fn from_cols(cols: Vec<Vec<T>>) -> Result<Matrix<T>, DimensionMismatchError> {
+        let (height, width) = Self::check_uniform_lengths(&cols, "Column")?;
+        let mut columns = cols
+            .into_iter()
+            .map(|column| column.into_iter())
+            .collect::<Vec<_>>();
+        let mut data = Vec::with_capacity(width * height);
+        for _ in 0..height {
+            data.extend(columns.iter_mut().map(|column| column.next().unwrap()));
+        }
+        Ok(Matrix::from_parts(width, height, data))
+    }
+
+    /// Creates a matrix from its values in row-major order, taking ownership of the buffer without copying.
+    /// Will return an Err if the number of values is not `width * height`.
+    ///
+    /// # Arguments
+    ///
+    /// * `width`: The width of the matrix
+    /// * `height`: The height of the matrix
+    /// * `data`: The values of the matrix, row by row from the top left
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_flat_vec(3, 2, vec![0, 1, 2, 3, 4, 5]).unwrap();
+    /// assert_eq!(matrix, Matrix::new(3, 2, |address| address.x + 3 * address.y).unwrap());
+    /// assert_eq!(matrix.into_flat_vec(), (3, 2, vec![0, 1, 2, 3, 4, 5]));
+    /// ```
+    pub fn from_flat_vec(
+        width: usize,
+        height: usize,
+        data: Vec<T>,
+    ) -> Result<Matrix<T>, DimensionMismatchError> {
+        if width.checked_mul(height) != Some(data.len()) {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "A {}x{} matrix cannot hold {} values",
+                    width,
+                    height,
+                    data.len()
+                ),
+            });
+        }
+        Ok(Matrix::from_parts(width, height, data))
+    }
+
+    /// Consumes the matrix, returning its width, height, and values in row-major order.
+    /// The inverse of `from_flat_vec`.
+    ///
+    /// Returns: `(usize, usize, Vec<T>)`
+    pub fn into_flat_vec(self) -> (usize, usize, Vec<T>) {
+        (self.width, self.height, self.data)
+    }
+
+    /// The number of columns in the matrix.
+    ///
+    /// Returns: `usize`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+    /// assert_eq!(matrix.width(), 3);
+    /// ```
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows in the matrix.
+    ///
+    /// Returns: `usize`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+    /// assert_eq!(matrix.height(), 2);
+    /// ```
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The width and height of the matrix.
+    ///
+    /// Returns: `(usize, usize)`, as `(width, height)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+    /// assert_eq!(matrix.dimensions(), (3, 2));
+    /// ```
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// The number of values in the matrix, which is always `width * height`.
+    ///
+    /// Returns: `usize`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+    /// assert_eq!(matrix.len(), 6);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether the matrix holds no values, which is the case when either dimension is zero.
+    ///
+    /// Returns: `bool`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// assert!(Matrix::<i32>::from_rows(vec![vec![], vec![]]).unwrap().is_empty());
+    /// assert!(!Matrix::from_rows(vec![vec![1]]).unwrap().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the shared length of the lines and the number of lines.
+    fn check_uniform_lengths(
+        lines: &[Vec<T>],
+        line_name: &str,
+    ) -> Result<(usize, usize), DimensionMismatchError> {
+        let length = lines.first().map_or(0, |line| line.len());
+        if let Some((index, line)) = lines
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.len() != length)
+        {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "{} {} has length {}, expected {}",
+                    line_name,
+                    index,
+                    line.len(),
+                    length
+                ),
+            });
+        }
+        Ok((length, lines.len()))
+    }
+
     /// Makes a string fit for displaying the contents of the matrix
     ///
     /// # Arguments
@@ -97,8 +364,8 @@ impl<T> Matrix<T> {
                 format!(
                     "{}{}",
                     display_func(&self[address]),
-                    if (i + 1) % (self.width) == 0 {
-                        if i != self.width * self.height - 1 {
+                    if (i + 1) % self.width() == 0 {
+                        if i != self.len() - 1 {
                             column_delimiter
                         } else {
                             ""
@@ -178,6 +445,75 @@ impl<T> Matrix<T> {
         }
     }
 
+    /// Parses a matrix from the start of a string, stopping early once the given limits are reached,
+    /// so that a preview of a huge input or a grid followed by other content can be read without
+    /// consuming the rest of the string.
+    ///
+    /// Rows without any values are skipped, unless `stop_at_blank_line` is set and at least one row
+    /// has been read, in which case parsing stops after the blank row and its delimiter.
+    /// Values past `max_columns` in a row are ignored, and parsing stops once `max_rows` rows are read.
+    /// If no rows are read the result is a 0x0 matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_str`: The string to be parsed
+    /// * `column_delimiter`: The string which separates the items in the columns
+    /// * `row_delimiter`: The string which separates the rows
+    /// * `str_to_t_converter`: The function which converts the item strings to a value
+    /// * `max_rows`: The largest number of rows to read, or None to read every row
+    /// * `max_columns`: The largest number of values to read from each row, or None to read every value
+    /// * `stop_at_blank_line`: Whether to stop at the first blank row following the matrix
+    ///
+    /// Returns: `Result<(Matrix<T>, usize), MatrixError>`, The matrix and the byte offset in `data_str`
+    /// at which parsing stopped. An Err if the rows read differ in length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let input = "#.#\n.#.\n\nflip 1\n";
+    /// let (matrix, offset) =
+    ///     Matrix::parse_matrix_limited(input, "", "\n", |s| s == "#", None, None, true).unwrap();
+    /// assert_eq!(matrix.to_display_string(|&b| if b { '#' } else { '.' }, "", "\n"), "#.#\n.#.");
+    /// assert_eq!(&input[offset..], "flip 1\n");
+    /// ```
+    pub fn parse_matrix_limited<F>(
+        data_str: &str,
+        column_delimiter: &str,
+        row_delimiter: &str,
+        str_to_t_converter: F,
+        max_rows: Option<usize>,
+        max_columns: Option<usize>,
+        stop_at_blank_line: bool,
+    ) -> Result<(Matrix<T>, usize), MatrixError>
+    where
+        F: Fn(&str) -> T,
+    {
+        let mut rows = Vec::new();
+        let mut offset = 0;
+        for line in data_str.split_inclusive(row_delimiter) {
+            if max_rows.is_some_and(|max_rows| rows.len() >= max_rows) {
+                break;
+            }
+            offset += line.len();
+            let row = line
+                .strip_suffix(row_delimiter)
+                .unwrap_or(line)
+                .split(column_delimiter)
+                .filter(|string| !string.is_empty())
+                .take(max_columns.unwrap_or(usize::MAX))
+                .map(&str_to_t_converter)
+                .collect::<Vec<T>>();
+            if !row.is_empty() {
+                rows.push(row);
+            } else if stop_at_blank_line && !rows.is_empty() {
+                break;
+            }
+        }
+        Ok((Matrix::from_rows(rows)?, offset))
+    }
+
     pub fn transform<TNew, F: Fn(MatrixAddress, &T) -> TNew>(
         self,
         mapper_function: F,
@@ -186,15 +522,427 @@ impl<T> Matrix<T> {
             .address_value_iter()
             .map(|(address, value)| mapper_function(address, value))
             .collect::<Vec<TNew>>();
-        Matrix {
+        Matrix::from_parts(self.width, self.height, data)
+    }
+
+    /// Combines two matrices of the same dimensions element by element.
+    /// Will return an Err if the dimensions of the two matrices differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The matrix whose elements are paired with the elements of this matrix
+    /// * `combiner`: Combines a value of this matrix with the value at the same address in `other`
+    ///
+    /// Returns: `Result<Matrix<R>, DimensionMismatchError>`, The combined matrix if the dimensions match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(3, 2, |address| address.x).unwrap();
+    /// let b = Matrix::new(3, 2, |address| address.y).unwrap();
+    /// assert_eq!(
+    ///     a.zip_with(&b, |x, y| x + y).unwrap(),
+    ///     Matrix::new(3, 2, |address| address.x + address.y).unwrap()
+    /// );
+    /// ```
+    pub fn zip_with<U, R, F: Fn(&T, &U) -> R>(
+        &self,
+        other: &Matrix<U>,
+        combiner: F,
+    ) -> Result<Matrix<R>, DimensionMismatchError> {
+        self.check_same_dimensions(other)?;
+        Ok(Matrix::from_parts(
+            self.width,
+            self.height,
+            self.data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, b)| combiner(a, b))
+                .collect(),
+        ))
+    }
+
+    /// Combines another matrix of the same dimensions into this one element by element,
+    /// without allocating a new matrix.
+    /// Will return an Err, leaving this matrix untouched, if the dimensions of the two matrices differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The matrix whose elements are paired with the elements of this matrix
+    /// * `combiner`: Updates a value of this matrix using the value at the same address in `other`
+    ///
+    /// Returns: `Result<(), DimensionMismatchError>`
+    pub fn zip_with_in_place<U, F: Fn(&mut T, &U)>(
+        &mut self,
+        other: &Matrix<U>,
+        combiner: F,
+    ) -> Result<(), DimensionMismatchError> {
+        self.check_same_dimensions(other)?;
+        self.data
+            .iter_mut()
+            .zip(other.data.iter())
+            .for_each(|(a, b)| combiner(a, b));
+        Ok(())
+    }
+
+    /// Collapses the matrix along an axis by reducing every row or every column to a single value.
+    /// Reducing along `Axis::Rows` returns a 1 wide, `height` tall matrix with one value per row,
+    /// and reducing along `Axis::Columns` returns a `width` wide, 1 tall matrix with one value per column.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: The axis to reduce along
+    /// * `reducer`: Reduces the values of a single row or column, in increasing address order
+    ///
+    /// Returns: `Matrix<U>`, The reduced matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::axis::Axis;
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix =
+    ///     Matrix::<i32>::parse_matrix("1 2 3|4 5 6", " ", "|", |s| s.parse().unwrap()).unwrap();
+    /// let row_sums = matrix.reduce_along(Axis::Rows, |row| row.iter().sum::<i32>());
+    /// assert_eq!(row_sums, Matrix::new(1, 2, |address| [6, 15][address.y as usize]).unwrap());
+    /// let column_sums = matrix.reduce_along(Axis::Columns, |column| column.iter().sum::<i32>());
+    /// assert_eq!(column_sums, Matrix::new(3, 1, |address| [5, 7, 9][address.x as usize]).unwrap());
+    /// ```
+    pub fn reduce_along<U, F: Fn(&[T]) -> U>(&self, axis: Axis, reducer: F) -> Matrix<U>
+    where
+        T: Clone,
+    {
+        match axis {
+            Axis::Rows => Matrix::from_parts(1, self.height, self.rows().map(reducer).collect()),
+            Axis::Columns => Matrix::from_parts(
+                self.width,
+                1,
+                (0..self.width)
+                    .map(|x| {
+                        let column = self
+                            .data
+                            .iter()
+                            .skip(x)
+                            .step_by(self.width)
+                            .cloned()
+                            .collect::<Vec<T>>();
+                        reducer(&column)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The values of the matrix in row-major order, as stored, for passing the data to code which
+    /// expects a flat buffer. `into_flat_vec` gives up ownership of the buffer instead.
+    ///
+    /// Returns: `&[T]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// The values of the matrix in row-major order, as stored, mutably. The dimensions of the matrix
+    /// cannot be changed through the slice.
+    ///
+    /// Returns: `&mut [T]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// matrix.as_mut_slice().reverse();
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![4, 3], vec![2, 1]]).unwrap());
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// The values of a single row of the matrix, from left to right.
+    ///
+    /// # Arguments
+    ///
+    /// * `y`: The index of the row
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is not smaller than the height of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// assert_eq!(matrix.row_slice(1), &[3, 4]);
+    /// ```
+    pub fn row_slice(&self, y: usize) -> &[T] {
+        self.row(y).unwrap_or_else(|| {
+            panic!(
+                "Row index {} is out of bounds for a {}x{} matrix",
+                y, self.width, self.height
+            )
+        })
+    }
+
+    /// The values of a single row of the matrix, from left to right, without copying.
+    ///
+    /// # Arguments
+    ///
+    /// * `y`: The index of the row
+    ///
+    /// Returns: `Option<&[T]>`, None if `y` is not smaller than the height of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.row(1), Some(&[3, 4][..]));
+    /// assert_eq!(matrix.row(2), None);
+    /// ```
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The values of a single row of the matrix, from left to right, as a mutable slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `y`: The index of the row
+    ///
+    /// Returns: `Option<&mut [T]>`, None if `y` is not smaller than the height of the matrix.
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        (y < self.height).then(|| &mut self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    /// References to the values of a single column of the matrix, from top to bottom.
+    /// The values of a column are not contiguous in storage, so this allocates a new vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: The index of the column
+    ///
+    /// Returns: `Option<Vec<&T>>`, None if `x` is not smaller than the width of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.column(1), Some(vec![&2, &4]));
+    /// assert_eq!(matrix.column(2), None);
+    /// ```
+    pub fn column(&self, x: usize) -> Option<Vec<&T>> {
+        (x < self.width).then(|| self.data.iter().skip(x).step_by(self.width).collect())
+    }
+
+    /// Creates an iterator over the rows of the matrix, from top to bottom.
+    /// Each row is a slice of the underlying storage, so no values are copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let sums = matrix.rows().map(|row| row.iter().sum()).collect::<Vec<i32>>();
+    /// assert_eq!(sums, vec![6, 15]);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Creates an iterator over the rows of the matrix as mutable slices, from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![3, 1, 2], vec![6, 5, 4]]).unwrap();
+    /// matrix.rows_mut().for_each(|row| row.sort());
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap());
+    /// ```
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let width = self.width;
+        let mut remaining = self.data.as_mut_slice();
+        (0..self.height).map(move |_| {
+            let (row, tail) = std::mem::take(&mut remaining).split_at_mut(width);
+            remaining = tail;
+            row
+        })
+    }
+
+    /// Creates an iterator over the columns of the matrix, from left to right.
+    /// Each column is an iterator over references to its values, from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let sums = matrix.columns().map(|column| column.sum()).collect::<Vec<i32>>();
+    /// assert_eq!(sums, vec![5, 7, 9]);
+    /// ```
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.data.iter().skip(x).step_by(self.width))
+    }
+
+    /// Creates an iterator over references to the values of the matrix in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Creates an iterator over mutable references to the values of the matrix in row-major order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    fn check_same_dimensions<U>(&self, other: &Matrix<U>) -> Result<(), DimensionMismatchError> {
+        if self.width != other.width || self.height != other.height {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Matrix dimensions differ: {}x{} and {}x{}",
+                    self.width, self.height, other.width, other.height
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that the matrix is internally consistent, which every method of the crate maintains.
+    /// Useful for defensive callers handling matrices built from untrusted data.
+    ///
+    /// Returns: `Result<(), MatrixError>`, An Err if the number of values is not `width * height`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert!(matrix.check_invariants().is_ok());
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), MatrixError> {
+        if self.width.checked_mul(self.height) != Some(self.data.len()) {
+            return Err(MatrixError::DimensionMismatch(DimensionMismatchError {
+                message: format!(
+                    "A {}x{} matrix holds {} values",
+                    self.width,
+                    self.height,
+                    self.data.len()
+                ),
+            }));
+        }
+        Ok(())
+    }
+
+    /// Panics if the matrix is not internally consistent, in debug builds only.
+    /// Called at the end of every constructor and method which changes the shape of a matrix.
+    pub(crate) fn debug_assert_invariants(&self) {
+        if cfg!(debug_assertions)
+            && let Err(error) = self.check_invariants()
+        {
+            panic!("Matrix invariant violated: {}", error);
+        }
+    }
+
+    /// Assembles a matrix from its parts, which must already be consistent with each other.
+    pub(crate) fn from_parts(width: usize, height: usize, data: Vec<T>) -> Matrix<T> {
+        let matrix = Matrix {
+            width,
+            height,
             data,
-            width: self.width,
-            height: self.height,
+        };
+        matrix.debug_assert_invariants();
+        matrix
+    }
+
+    /// Finds the position of an address in the row-major backing storage of the matrix,
+    /// such that `matrix.as_slice()[matrix.index_of(address).unwrap()]` is `matrix[address]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `address`: The address to locate
+    ///
+    /// Returns: `Option<usize>`, None if the address is not contained in the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let matrix = Matrix::new(3, 2, |address| address.x * 10 + address.y).unwrap();
+    /// assert_eq!(matrix.index_of(MatrixAddress { x: 1, y: 1 }), Some(4));
+    /// assert_eq!(matrix.index_of(MatrixAddress { x: 3, y: 0 }), None);
+    /// ```
+    pub fn index_of(&self, address: MatrixAddress) -> Option<usize> {
+        self.contains_address(address)
+            .then(|| address.y as usize * self.width + address.x as usize)
+    }
+
+    /// Finds the address of a position in the row-major backing storage of the matrix, the inverse of
+    /// `index_of`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The position in the backing storage
+    ///
+    /// Returns: `Option<MatrixAddress>`, None if the index is not smaller than the number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let matrix = Matrix::new(3, 2, |address| address.x * 10 + address.y).unwrap();
+    /// assert_eq!(matrix.address_of(4), Some(MatrixAddress { x: 1, y: 1 }));
+    /// assert_eq!(matrix.address_of(6), None);
+    /// ```
+    pub fn address_of(&self, index: usize) -> Option<MatrixAddress> {
+        (index < self.data.len()).then(|| self.address_of_index(index))
+    }
+
+    /// The inverse of `index_of`, for an index smaller than the number of values.
+    fn address_of_index(&self, index: usize) -> MatrixAddress {
+        Self::row_major_address(self.width, index)
+    }
+
+    /// The address of a position in row-major values whose rows are `width` values long, for use
+    /// where the values are borrowed mutably and `address_of_index` cannot be called.
+    fn row_major_address(width: usize, index: usize) -> MatrixAddress {
+        MatrixAddress {
+            x: (index % width) as i32,
+            y: (index / width) as i32,
         }
     }
 
     fn index_address(&self, address: MatrixAddress) -> usize {
-        address.y as usize * self.width + address.x as usize
+        self.index_of(address).unwrap_or_else(|| {
+            panic!(
+                "Address ({}, {}) is out of bounds for a {}x{} matrix",
+                address.x, address.y, self.width, self.height
+            )
+        })
     }
 }
 
@@ -205,10 +953,26 @@ impl<'a, T: 'a> Tensor<'a, T, i32, MatrixAddress, 2> for Matrix<T> {
 
     fn largest_contained_address(&self) -> MatrixAddress {
         MatrixAddress {
-            x: (self.width - 1) as i32,
-            y: (self.height - 1) as i32,
+            x: self.width as i32 - 1,
+            y: self.height as i32 - 1,
         }
     }
+
+    fn fold_values<B, F: FnMut(B, &T) -> B>(&'a self, init: B, f: F) -> B {
+        self.data.iter().fold(init, f)
+    }
+
+    fn count_matching<F: Fn(&T) -> bool>(&'a self, predicate: F) -> usize {
+        self.data.iter().filter(|value| predicate(value)).count()
+    }
+
+    fn any_value<F: Fn(&T) -> bool>(&'a self, predicate: F) -> bool {
+        self.data.iter().any(predicate)
+    }
+
+    fn all_values<F: Fn(&T) -> bool>(&'a self, predicate: F) -> bool {
+        self.data.iter().all(predicate)
+    }
 }
 
 impl<T: Display> Display for Matrix<T> {
@@ -256,13 +1020,54 @@ impl<T> IndexMut<(i32, i32)> for Matrix<T> {
     }
 }
 
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = DimensionMismatchError;
+
+    /// Creates a matrix from a list of rows, as `Matrix::from_rows` does.
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Matrix::from_rows(rows)
+    }
+}
+
+impl<T> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Consumes the matrix, yielding its values in row-major order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::address_iterator::AddressIterator;
+    use crate::axis::Axis;
     use crate::matrix::Matrix;
     use crate::matrix_address::MatrixAddress;
+    use crate::matrix_error::MatrixError;
     use crate::tensor::Tensor;
     use proptest::proptest;
+    use std::collections::HashSet;
+    use std::hash::{DefaultHasher, Hash, Hasher};
     use std::str::FromStr;
 
     #[test]
@@ -285,12 +1090,12 @@ mod tests {
         let mut matrix = Matrix::new(width, height, |_address| 0usize).unwrap();
         matrix.address_iter().for_each(|address| {
             assert_eq!(matrix[address], 0usize);
-            matrix[address] = matrix.index_address(address);
-            assert_eq!(matrix[address], matrix.index_address(address));
+            matrix[address] = matrix.index_of(address).unwrap();
+            assert_eq!(matrix[address], matrix.index_of(address).unwrap());
         });
         matrix
             .address_iter()
-            .for_each(|address| assert_eq!(matrix.index_address(address), matrix[address]))
+            .for_each(|address| assert_eq!(matrix.index_of(address), Some(matrix[address])))
     }
 
     #[test]
@@ -300,14 +1105,103 @@ mod tests {
             address.x as usize + address.y as usize * width
         })
         .unwrap();
-        assert_eq!(matrix.index_address(MatrixAddress { x: 999, y: 0 }), 999);
-        assert_eq!(matrix.index_address(MatrixAddress { x: 0, y: 1 }), 1000);
-        assert_eq!(matrix.index_address(MatrixAddress { x: 1, y: 1 }), 1001);
+        assert_eq!(matrix.index_of(MatrixAddress { x: 999, y: 0 }), Some(999));
+        assert_eq!(matrix.index_of(MatrixAddress { x: 0, y: 1 }), Some(1000));
+        assert_eq!(matrix.index_of(MatrixAddress { x: 1, y: 1 }), Some(1001));
+        assert_eq!(matrix.index_of(MatrixAddress { x: 1000, y: 0 }), None);
+        assert_eq!(matrix.index_of(MatrixAddress { x: 0, y: -1 }), None);
         matrix.address_iter().for_each(|address| {
-            assert_eq!(matrix.index_address(address), matrix[address]);
+            assert_eq!(matrix.index_of(address), Some(matrix[address]));
             assert_eq!(Some(&matrix[address]), matrix.get(address));
         })
     }
+    #[test]
+    fn slice_test() {
+        let mut matrix = Matrix::new(4, 3, |address| address.x * 10 + address.y).unwrap();
+        for address in matrix.address_iter() {
+            assert_eq!(
+                matrix.as_slice()[matrix.index_of(address).unwrap()],
+                matrix[address]
+            );
+        }
+        assert_eq!(matrix.row_slice(2), &[2, 12, 22, 32]);
+        matrix.as_mut_slice()[5] = -1;
+        assert_eq!(matrix[(1, 1)], -1);
+        assert_eq!(matrix.as_slice().len(), 12);
+    }
+
+    #[test]
+    fn rows_and_columns_test() {
+        let mut matrix = Matrix::new(5, 3, |address| address.x * 10 + address.y).unwrap();
+        assert_eq!(matrix.rows().count(), 3);
+        for (y, row) in matrix.rows().enumerate() {
+            assert_eq!(row.len(), 5);
+            for (x, value) in row.iter().enumerate() {
+                assert_eq!(*value, matrix[(x as i32, y as i32)]);
+            }
+        }
+        assert_eq!(matrix.columns().count(), 5);
+        for (x, column) in matrix.columns().enumerate() {
+            let column = column.collect::<Vec<_>>();
+            assert_eq!(column.len(), 3);
+            for (y, value) in column.into_iter().enumerate() {
+                assert_eq!(*value, matrix[(x as i32, y as i32)]);
+            }
+        }
+        for (y, row) in matrix.rows_mut().enumerate() {
+            row[y] = -1;
+        }
+        assert_eq!(matrix[(0, 0)], -1);
+        assert_eq!(matrix[(1, 1)], -1);
+        assert_eq!(matrix[(2, 2)], -1);
+        assert_eq!(matrix.iter().filter(|&&value| value == -1).count(), 3);
+
+        let mut flat = Matrix::<i32>::from_rows(vec![vec![], vec![]]).unwrap();
+        assert_eq!(flat.rows().collect::<Vec<_>>(), vec![&[] as &[i32]; 2]);
+        assert_eq!(flat.rows_mut().count(), 2);
+        assert_eq!(flat.columns().count(), 0);
+    }
+
+    #[test]
+    fn row_and_column_test() {
+        let mut single_row = Matrix::from_rows(vec![vec![1, 2, 3]]).unwrap();
+        assert_eq!(single_row.row(0), Some(&[1, 2, 3][..]));
+        assert_eq!(single_row.row(1), None);
+        assert_eq!(single_row.column(0), Some(vec![&1]));
+        assert_eq!(single_row.column(2), Some(vec![&3]));
+        assert_eq!(single_row.column(3), None);
+        single_row.row_mut(0).unwrap()[2] = 4;
+        assert_eq!(single_row[(2, 0)], 4);
+        assert!(single_row.row_mut(1).is_none());
+
+        let mut single_column = Matrix::from_cols(vec![vec![1, 2, 3]]).unwrap();
+        assert_eq!(single_column.row(0), Some(&[1][..]));
+        assert_eq!(single_column.row(2), Some(&[3][..]));
+        assert_eq!(single_column.row(3), None);
+        assert_eq!(single_column.column(0), Some(vec![&1, &2, &3]));
+        assert_eq!(single_column.column(1), None);
+        single_column.row_mut(2).unwrap()[0] = 4;
+        assert_eq!(single_column[(0, 2)], 4);
+        assert!(single_column.row_mut(3).is_none());
+
+        let empty = Matrix::<i32>::from_rows(vec![]).unwrap();
+        assert_eq!(empty.row(0), None);
+        assert_eq!(empty.column(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn row_slice_out_of_bounds_test() {
+        Matrix::new(2, 2, |_| 0).unwrap().row_slice(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds_test() {
+        let matrix = Matrix::new(2, 2, |_| 0).unwrap();
+        let _ = matrix[(2, 0)];
+    }
+
     #[test]
     fn parse_test() {
         let data_str = "0,1,2,3,4,5,6,0,1,2,3|4,5,6,0,1,2,3,4,5,6,0|1,2,3,4,5,6,0,1,2,3,4|5,6,0,1,2,3,4,5,6,0,1|2,3,4,5,6,0,1,2,3,4,5|6,0,1,2,3,4,5,6,0,1,2|3,4,5,6,0,1,2,3,4,5,6|0,1,2,3,4,5,6,0,1,2,3|4,5,6,0,1,2,3,4,5,6,0|1,2,3,4,5,6,0,1,2,3,4|5,6,0,1,2,3,4,5,6,0,1";
@@ -324,6 +1218,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hash_test() {
+        fn hash_of<H: Hash>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        let matrix = Matrix::new(3, 2, |address| address.x - address.y).unwrap();
+        let equal = Matrix::from_rows(vec![vec![0, 1, 2], vec![-1, 0, 1]]).unwrap();
+        assert_eq!(hash_of(&matrix), hash_of(&equal));
+        let reshaped = equal.reshape(2, 3).unwrap();
+        assert_ne!(hash_of(&matrix), hash_of(&reshaped));
+
+        let mut seen = HashSet::new();
+        assert!(seen.insert(matrix));
+        assert!(!seen.insert(equal));
+        assert!(seen.insert(reshaped));
+        assert_eq!(seen.len(), 2);
+
+        let addresses = (0..4)
+            .flat_map(|y| (0..4).map(move |x| MatrixAddress { x: x % 2, y }))
+            .collect::<HashSet<_>>();
+        assert_eq!(addresses.len(), 8);
+        assert_eq!(
+            hash_of(&MatrixAddress { x: 3, y: -1 }),
+            hash_of(&MatrixAddress::from([3, -1]))
+        );
+    }
+
+    #[test]
+    fn parse_fixture_aggregates_test() {
+        let data_str = "0,1,2,3,4,5,6,0,1,2,3|4,5,6,0,1,2,3,4,5,6,0|1,2,3,4,5,6,0,1,2,3,4|5,6,0,1,2,3,4,5,6,0,1|2,3,4,5,6,0,1,2,3,4,5|6,0,1,2,3,4,5,6,0,1,2|3,4,5,6,0,1,2,3,4,5,6|0,1,2,3,4,5,6,0,1,2,3|4,5,6,0,1,2,3,4,5,6,0|1,2,3,4,5,6,0,1,2,3,4|5,6,0,1,2,3,4,5,6,0,1";
+        let matrix =
+            Matrix::parse_matrix(data_str, ",", "|", |string| i64::from_str(string).unwrap())
+                .unwrap();
+        // 121 values cycling through 0 to 6: 17 full cycles of 21, then 0 and 1.
+        assert_eq!(matrix.sum(), 358);
+        assert_eq!(matrix.fold_values(0, |count, _| count + 1), 121);
+        assert_eq!(matrix.fold_values(0, |sum, value| sum + value), 358);
+        let doubled_odds = matrix.transform(|_, &value| value % 2 + 1);
+        assert_eq!(doubled_odds.product(), 1 << 52);
+        assert_eq!(
+            doubled_odds.product(),
+            doubled_odds.fold_values(1, |product, value| product * value)
+        );
+    }
+
     #[test]
     #[should_panic]
     fn parse_err_test() {
@@ -388,7 +1329,313 @@ mod tests {
         )
     }
 
+    #[test]
+    fn zip_with_test() {
+        let (width, height) = (30, 20);
+        let a = Matrix::new(width, height, |address| address.x).unwrap();
+        let b = Matrix::new(width, height, |address| address.y as f64).unwrap();
+        let zipped = a.zip_with(&b, |x, y| (*x, *y)).unwrap();
+        zipped.address_value_iter().for_each(|(address, value)| {
+            assert_eq!(*value, (address.x, address.y as f64));
+        });
+        let c = Matrix::new(height, width, |_| 0.0).unwrap();
+        assert!(a.zip_with(&c, |x, y| *x as f64 + y).is_err());
+    }
+
+    #[test]
+    fn zip_with_in_place_test() {
+        let (width, height) = (30, 20);
+        let mut a = Matrix::new(width, height, |address| address.x).unwrap();
+        let b = Matrix::new(width, height, |address| address.y).unwrap();
+        a.zip_with_in_place(&b, |x, y| *x *= y).unwrap();
+        assert_eq!(
+            a,
+            Matrix::new(width, height, |address| address.x * address.y).unwrap()
+        );
+
+        let c = Matrix::new(width + 1, height, |_| 1).unwrap();
+        let before = a.clone();
+        assert!(a.zip_with_in_place(&c, |x, y| *x += y).is_err());
+        assert_eq!(a, before);
+    }
+
+    #[test]
+    fn reduce_along_test() {
+        let (width, height) = (7, 4);
+        let matrix = Matrix::new(width, height, |address| {
+            address.y * width as i32 + address.x
+        })
+        .unwrap();
+
+        let rows = matrix.reduce_along(Axis::Rows, |row| row.to_vec());
+        assert_eq!(rows.width, 1);
+        assert_eq!(rows.height, height);
+        rows.address_value_iter().for_each(|(address, row)| {
+            assert_eq!(address.x, 0);
+            let expected = (0..width as i32)
+                .map(|x| matrix[(x, address.y)])
+                .collect::<Vec<i32>>();
+            assert_eq!(*row, expected);
+        });
+
+        let columns = matrix.reduce_along(Axis::Columns, |column| column.to_vec());
+        assert_eq!(columns.width, width);
+        assert_eq!(columns.height, 1);
+        columns.address_value_iter().for_each(|(address, column)| {
+            assert_eq!(address.y, 0);
+            let expected = (0..height as i32)
+                .map(|y| matrix[(address.x, y)])
+                .collect::<Vec<i32>>();
+            assert_eq!(*column, expected);
+        });
+    }
+
+    #[test]
+    fn into_iter_test() {
+        let (width, height) = (13, 7);
+        let mut matrix = Matrix::new(width, height, |address| {
+            (address.y * width as i32 + address.x).to_string()
+        })
+        .unwrap();
+
+        let mut count = 0;
+        for (address, value) in matrix.address_iter().zip(&matrix) {
+            assert_eq!(*value, matrix[address]);
+            count += 1;
+        }
+        assert_eq!(count, width * height);
+
+        for value in &mut matrix {
+            value.push('!');
+        }
+        matrix.address_value_iter().for_each(|(address, value)| {
+            assert_eq!(*value, format!("{}!", address.y * width as i32 + address.x))
+        });
+
+        let owned = matrix.clone().into_iter().collect::<Vec<String>>();
+        assert_eq!(owned, matrix.iter().cloned().collect::<Vec<String>>());
+        assert_eq!(owned[width + 2], format!("{}!", width + 2));
+    }
+
+    #[test]
+    fn from_rows_test() {
+        let (width, height) = (6, 4);
+        let expected = Matrix::new(width, height, |address| {
+            (address.y * width as i32 + address.x).to_string()
+        })
+        .unwrap();
+        let rows = (0..height)
+            .map(|y| (0..width).map(|x| (y * width + x).to_string()).collect())
+            .collect::<Vec<Vec<String>>>();
+        let cols = (0..width)
+            .map(|x| (0..height).map(|y| (y * width + x).to_string()).collect())
+            .collect::<Vec<Vec<String>>>();
+        assert_eq!(Matrix::from_rows(rows.clone()).unwrap(), expected);
+        assert_eq!(Matrix::try_from(rows).unwrap(), expected);
+        assert_eq!(Matrix::from_cols(cols).unwrap(), expected);
+    }
+
+    #[test]
+    fn from_rows_err_test() {
+        assert!(Matrix::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+        assert!(Matrix::from_cols(vec![vec![1, 2], vec![3]]).is_err());
+        assert!(Matrix::<i32>::try_from(vec![vec![1], vec![2, 3]]).is_err());
+        assert_eq!(
+            Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5]])
+                .unwrap_err()
+                .message,
+            "Row 2 has length 1, expected 2"
+        );
+        assert_eq!(
+            Matrix::from_cols(vec![vec![1, 2], vec![3, 4, 5]])
+                .unwrap_err()
+                .message,
+            "Column 1 has length 3, expected 2"
+        );
+    }
+
+    #[test]
+    fn parse_matrix_limited_blank_line_test() {
+        let input = "é ü ß\nö ä ñ\n\n日本 語\n";
+        let (matrix, offset) =
+            Matrix::parse_matrix_limited(input, " ", "\n", |s| s.to_string(), None, None, true)
+                .unwrap();
+        assert_eq!(
+            matrix.to_display_string(|s| s.clone(), " ", "|"),
+            "é ü ß|ö ä ñ"
+        );
+        assert_eq!(offset, "é ü ß\nö ä ñ\n\n".len());
+        assert_eq!(&input[offset..], "日本 語\n");
+
+        let (rest, offset) = Matrix::parse_matrix_limited(
+            &input[offset..],
+            " ",
+            "\n",
+            |s| s.to_string(),
+            None,
+            None,
+            true,
+        )
+        .unwrap();
+        assert_eq!(rest.to_display_string(|s| s.clone(), " ", "|"), "日本 語");
+        assert_eq!(offset, "日本 語\n".len());
+
+        let (whole, offset) = Matrix::parse_matrix_limited(
+            "1 2\n\n3 4",
+            " ",
+            "\n",
+            |s| s.to_string(),
+            None,
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(whole.to_display_string(|s| s.clone(), " ", "|"), "1 2|3 4");
+        assert_eq!(offset, 8);
+    }
+
+    #[test]
+    fn parse_matrix_limited_shape_test() {
+        let input = "0,1,2,3\n4,5,6,7\n8,9,10,11\n12,13,14,15";
+        let parse = |max_rows, max_columns| {
+            Matrix::parse_matrix_limited(
+                input,
+                ",",
+                "\n",
+                |s| s.parse::<i32>().unwrap(),
+                max_rows,
+                max_columns,
+                false,
+            )
+            .unwrap()
+        };
+        let (corner, offset) = parse(Some(2), Some(3));
+        assert_eq!(
+            corner,
+            Matrix::new(3, 2, |address| address.x + 4 * address.y).unwrap()
+        );
+        assert_eq!(&input[offset..], "8,9,10,11\n12,13,14,15");
+        let (whole, offset) = parse(None, None);
+        assert_eq!(
+            whole,
+            Matrix::new(4, 4, |address| address.x + 4 * address.y).unwrap()
+        );
+        assert_eq!(offset, input.len());
+        let (empty, offset) = parse(Some(0), None);
+        assert_eq!((empty.width, empty.height, offset), (0, 0, 0));
+
+        let ragged = Matrix::parse_matrix_limited(
+            "1 2\n3\n4 5",
+            " ",
+            "\n",
+            |s| s.to_string(),
+            None,
+            None,
+            false,
+        );
+        assert!(matches!(ragged, Err(MatrixError::DimensionMismatch(_))));
+        assert!(
+            Matrix::parse_matrix_limited(
+                "1 2\n3",
+                " ",
+                "\n",
+                |s| s.to_string(),
+                Some(1),
+                None,
+                false
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn flat_vec_round_trip_test() {
+        #[derive(Debug, Eq, PartialEq)]
+        struct Unique(u32);
+        let data = (0..6).map(Unique).collect::<Vec<_>>();
+        let pointer = data.as_ptr();
+        let matrix = Matrix::from_flat_vec(2, 3, data).unwrap();
+        assert_eq!(matrix[(1, 2)], Unique(5));
+        let (width, height, data) = matrix.into_flat_vec();
+        assert_eq!((width, height), (2, 3));
+        assert_eq!(data.as_ptr(), pointer);
+        assert_eq!(data, (0..6).map(Unique).collect::<Vec<_>>());
+
+        assert!(Matrix::from_flat_vec(2, 3, vec![0; 5]).is_err());
+        assert!(Matrix::from_flat_vec(usize::MAX, 2, vec![0; 2]).is_err());
+        assert_eq!(
+            Matrix::<i32>::from_flat_vec(0, 0, vec![])
+                .unwrap()
+                .into_flat_vec(),
+            (0, 0, vec![])
+        );
+    }
+
+    #[test]
+    fn from_rows_empty_test() {
+        let matrix = Matrix::<String>::from_rows(vec![]).unwrap();
+        assert_eq!((matrix.width, matrix.height), (0, 0));
+        assert_eq!(matrix.address_iter().count(), 0);
+        assert_eq!(matrix.iter().count(), 0);
+        assert_eq!(matrix.to_display_string(|s| s.clone(), " ", "\n"), "");
+        assert_eq!(
+            Matrix::<i32>::from_cols(vec![]).unwrap(),
+            matrix.transform(|_, _| 0)
+        );
+        let flat = Matrix::<i32>::from_rows(vec![vec![], vec![]]).unwrap();
+        assert_eq!((flat.width, flat.height), (0, 2));
+        assert_eq!(
+            flat.reduce_along(Axis::Rows, |row| row.len())
+                .iter()
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn check_invariants_test() {
+        let broken = Matrix {
+            width: 2,
+            height: 2,
+            data: vec![1, 2, 3],
+        };
+        assert_eq!(
+            broken.check_invariants().unwrap_err().to_string(),
+            "A 2x2 matrix holds 3 values"
+        );
+        let overflowing = Matrix::<u8> {
+            width: usize::MAX,
+            height: 2,
+            data: vec![],
+        };
+        assert!(overflowing.check_invariants().is_err());
+    }
+
     proptest! {
+        #[test]
+        fn value_predicates_match_address_scan_test(values in proptest::collection::vec(0u8..4, 0..30), width in 1usize..6) {
+            let height = values.len() / width;
+            let matrix = Matrix::from_flat_vec(width, height, values[..width * height].to_vec()).unwrap();
+            for target in 0..5 {
+                let scanned = matrix.address_iter().map(|address| matrix[address]).filter(|&value| value == target).count();
+                assert_eq!(matrix.count_matching(|&value| value == target), scanned);
+                assert_eq!(matrix.any_value(|&value| value == target), scanned > 0);
+                assert_eq!(matrix.all_values(|&value| value != target), scanned == 0);
+            }
+        }
+
+        #[test]
+        fn find_address_matches_scan_test(values in proptest::collection::vec(0u8..6, 0..30), width in 1usize..6) {
+            let height = values.len() / width;
+            let matrix = Matrix::from_flat_vec(width, height, values[..width * height].to_vec()).unwrap();
+            for target in 0..6 {
+                let matches = matrix.data.iter().enumerate().filter(|&(_, value)| *value == target).map(|(index, _)| matrix.address_of_index(index)).collect::<Vec<_>>();
+                assert_eq!(matrix.find_all_addresses(|&value| value == target).collect::<Vec<_>>(), matches);
+                assert_eq!(matrix.find_address(|&value| value == target), matches.first().copied());
+                assert_eq!(matrix.position_of(&target), matches.first().copied());
+            }
+        }
+
         #[test]
         fn address_sugar_test(x in 0..100, y in 0..200) {
             let matrix = Matrix::new(100, 200, |address| address.y * 100 + address.x).unwrap();
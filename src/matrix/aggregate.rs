@@ -0,0 +1,703 @@
+use crate::matrix::{Matrix, SparseMatrix};
+use crate::matrix_address::MatrixAddress;
+use std::cmp::Ordering;
+use std::ops::{Add, Mul};
+
+impl<T> Matrix<T> {
+    /// Adds up every value of the matrix.
+    ///
+    /// Returns: `T`, where the sum of a matrix without any values is `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.sum(), 10);
+    /// ```
+    pub fn sum(&self) -> T
+    where
+        T: Add<Output = T> + Copy + Default,
+    {
+        Self::sum_of(&self.data)
+    }
+
+    /// Multiplies every value of the matrix together.
+    ///
+    /// Returns: `T`, where the product of a matrix without any values is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.product(), 24);
+    /// ```
+    pub fn product(&self) -> T
+    where
+        T: Mul<Output = T> + Copy + From<u8>,
+    {
+        self.data
+            .iter()
+            .fold(T::from(1), |product, value| product * *value)
+    }
+
+    /// Finds the smallest value of the matrix, the first one in row-major order if there are several.
+    ///
+    /// Returns: `Option<&T>`, None if the matrix has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![3, 1], vec![4, 1]]).unwrap();
+    /// assert_eq!(matrix.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.data.iter().min()
+    }
+
+    /// Finds the largest value of the matrix, the last one in row-major order if there are several.
+    ///
+    /// Returns: `Option<&T>`, None if the matrix has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![3, 1], vec![4, 1]]).unwrap();
+    /// assert_eq!(matrix.max(), Some(&4));
+    /// ```
+    pub fn max(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.data.iter().max()
+    }
+
+    /// Computes the arithmetic mean of every value of the matrix.
+    ///
+    /// Returns: `Option<f64>`, None if the matrix has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 5]]).unwrap();
+    /// assert_eq!(matrix.mean(), Some(2.75));
+    /// ```
+    pub fn mean(&self) -> Option<f64>
+    where
+        T: Into<f64> + Copy,
+    {
+        (!self.is_empty()).then(|| Self::mean_of(self.data.iter()))
+    }
+
+    /// Adds up the values of each row.
+    ///
+    /// Returns: `Vec<T>`, The sum of every row, from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(matrix.row_sums(), vec![6, 15]);
+    /// ```
+    pub fn row_sums(&self) -> Vec<T>
+    where
+        T: Add<Output = T> + Copy + Default,
+    {
+        self.reduce_rows(T::default(), |sum, value| sum + *value)
+    }
+
+    /// Adds up the values of each column.
+    ///
+    /// Returns: `Vec<T>`, The sum of every column, from left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(matrix.col_sums(), vec![5, 7, 9]);
+    /// ```
+    pub fn col_sums(&self) -> Vec<T>
+    where
+        T: Add<Output = T> + Copy + Default,
+    {
+        self.reduce_columns(T::default(), |sum, value| sum + *value)
+    }
+
+    /// Combines the values of each row into an accumulator, from left to right.
+    ///
+    /// # Arguments
+    ///
+    /// * `init`: The initial value of the accumulator of every row
+    /// * `f`: Combines an accumulator with a value
+    ///
+    /// Returns: `Vec<B>`, The accumulator of every row, from top to bottom, with one per row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let table = Matrix::from_rows(vec![vec!["a", "bb"], vec!["ccc", ""]]).unwrap();
+    /// assert_eq!(table.reduce_rows(0, |length, cell| length + cell.len()), vec![3, 3]);
+    /// ```
+    pub fn reduce_rows<B: Clone, F: FnMut(B, &T) -> B>(&self, init: B, mut f: F) -> Vec<B> {
+        (0..self.height)
+            .map(|y| {
+                self.data[y * self.width..(y + 1) * self.width]
+                    .iter()
+                    .fold(init.clone(), &mut f)
+            })
+            .collect()
+    }
+
+    /// Combines the values of each column into an accumulator, from top to bottom. The values are
+    /// visited in a single pass through the matrix in row-major order, advancing every column's
+    /// accumulator a row at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `init`: The initial value of the accumulator of every column
+    /// * `f`: Combines an accumulator with a value
+    ///
+    /// Returns: `Vec<B>`, The accumulator of every column, from left to right, with one per column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![3, 1], vec![2, 5]]).unwrap();
+    /// assert_eq!(matrix.reduce_columns(i32::MIN, |max, &value| max.max(value)), vec![3, 5]);
+    /// ```
+    pub fn reduce_columns<B: Clone, F: FnMut(B, &T) -> B>(&self, init: B, mut f: F) -> Vec<B> {
+        let mut accumulators = vec![init; self.width];
+        for y in 0..self.height {
+            accumulators = accumulators
+                .into_iter()
+                .zip(&self.data[y * self.width..(y + 1) * self.width])
+                .map(|(accumulator, value)| f(accumulator, value))
+                .collect();
+        }
+        accumulators
+    }
+
+    /// Computes the arithmetic mean of each row. A matrix of zero width has a mean of NaN in every row.
+    ///
+    /// Returns: `Vec<f64>`, The mean of every row, from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 7]]).unwrap();
+    /// assert_eq!(matrix.row_means(), vec![2.0, 16.0 / 3.0]);
+    /// ```
+    pub fn row_means(&self) -> Vec<f64>
+    where
+        T: Into<f64> + Copy,
+    {
+        self.rows().map(|row| Self::mean_of(row.iter())).collect()
+    }
+
+    /// Computes the arithmetic mean of each column. A matrix of zero height has a mean of NaN in every
+    /// column.
+    ///
+    /// Returns: `Vec<f64>`, The mean of every column, from left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 7]]).unwrap();
+    /// assert_eq!(matrix.col_means(), vec![2.5, 3.5, 5.0]);
+    /// ```
+    pub fn col_means(&self) -> Vec<f64>
+    where
+        T: Into<f64> + Copy,
+    {
+        self.columns().map(Self::mean_of).collect()
+    }
+
+    /// Finds the address of the smallest value of the matrix, the first one in row-major order if
+    /// there are several. Values which cannot be compared, such as NaN, are never chosen unless the
+    /// first value is one.
+    ///
+    /// Returns: `Option<MatrixAddress>`, None if the matrix has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let costs = Matrix::from_rows(vec![vec![4.0, 2.5], vec![0.5, 3.0]]).unwrap();
+    /// assert_eq!(costs.argmin(), Some(MatrixAddress { x: 0, y: 1 }));
+    /// ```
+    pub fn argmin(&self) -> Option<MatrixAddress>
+    where
+        T: PartialOrd,
+    {
+        Self::extreme_position(self.data.iter(), |value, best| value < best)
+            .map(|index| self.address_of_index(index))
+    }
+
+    /// Finds the address of the largest value of the matrix, the first one in row-major order if
+    /// there are several. Values which cannot be compared, such as NaN, are never chosen unless the
+    /// first value is one.
+    ///
+    /// Returns: `Option<MatrixAddress>`, None if the matrix has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let heat = Matrix::from_rows(vec![vec![4.0, 2.5], vec![0.5, 3.0]]).unwrap();
+    /// assert_eq!(heat.argmax(), Some(MatrixAddress { x: 0, y: 0 }));
+    /// ```
+    pub fn argmax(&self) -> Option<MatrixAddress>
+    where
+        T: PartialOrd,
+    {
+        Self::extreme_position(self.data.iter(), |value, best| value > best)
+            .map(|index| self.address_of_index(index))
+    }
+
+    /// Finds the largest value of the matrix according to a comparison function, together with its
+    /// address. When several values are the largest, the first one in row-major order is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmp`: Compares two values
+    ///
+    /// Returns: `Option<(MatrixAddress, &T)>`, None if the matrix has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let heat = Matrix::from_rows(vec![vec![20.5f64, 31.0], vec![31.0, 18.25]]).unwrap();
+    /// assert_eq!(
+    ///     heat.max_by(|a, b| a.total_cmp(b)),
+    ///     Some((MatrixAddress { x: 1, y: 0 }, &31.0))
+    /// );
+    /// ```
+    pub fn max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut cmp: F) -> Option<(MatrixAddress, &T)> {
+        let index = Self::extreme_position(self.data.iter(), |value, best| {
+            cmp(value, best) == Ordering::Greater
+        })?;
+        Some((self.address_of_index(index), &self.data[index]))
+    }
+
+    /// Finds the smallest value of the matrix according to a comparison function, together with its
+    /// address. When several values are the smallest, the first one in row-major order is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmp`: Compares two values
+    ///
+    /// Returns: `Option<(MatrixAddress, &T)>`, None if the matrix has no values.
+    pub fn min_by<F: FnMut(&T, &T) -> Ordering>(&self, mut cmp: F) -> Option<(MatrixAddress, &T)> {
+        let index = Self::extreme_position(self.data.iter(), |value, best| {
+            cmp(value, best) == Ordering::Less
+        })?;
+        Some((self.address_of_index(index), &self.data[index]))
+    }
+
+    /// Finds the largest value of the matrix together with its address, the first one in row-major
+    /// order if there are several.
+    ///
+    /// Returns: `Option<(MatrixAddress, &T)>`, None if the matrix has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let votes = Matrix::from_rows(vec![vec![3, 9, 1], vec![9, 0, 2]]).unwrap();
+    /// assert_eq!(votes.max_with_address(), Some((MatrixAddress { x: 1, y: 0 }, &9)));
+    /// assert_eq!(votes.min_with_address(), Some((MatrixAddress { x: 1, y: 1 }, &0)));
+    /// ```
+    pub fn max_with_address(&self) -> Option<(MatrixAddress, &T)>
+    where
+        T: Ord,
+    {
+        self.max_by(T::cmp)
+    }
+
+    /// Finds the smallest value of the matrix together with its address, the first one in row-major
+    /// order if there are several.
+    ///
+    /// Returns: `Option<(MatrixAddress, &T)>`, None if the matrix has no values.
+    pub fn min_with_address(&self) -> Option<(MatrixAddress, &T)>
+    where
+        T: Ord,
+    {
+        self.min_by(T::cmp)
+    }
+
+    /// Finds the address of the smallest value of a row, the leftmost one if there are several.
+    ///
+    /// # Arguments
+    ///
+    /// * `y`: The index of the row
+    ///
+    /// Returns: `Option<MatrixAddress>`, None if the row does not exist or has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![3, 1, 1], vec![0, 5, 2]]).unwrap();
+    /// assert_eq!(matrix.row_argmin(0), Some(MatrixAddress { x: 1, y: 0 }));
+    /// assert_eq!(matrix.row_argmin(2), None);
+    /// ```
+    pub fn row_argmin(&self, y: usize) -> Option<MatrixAddress>
+    where
+        T: PartialOrd,
+    {
+        self.row_extreme(y, |value, best| value < best)
+    }
+
+    /// Finds the address of the largest value of a row, the leftmost one if there are several.
+    ///
+    /// # Arguments
+    ///
+    /// * `y`: The index of the row
+    ///
+    /// Returns: `Option<MatrixAddress>`, None if the row does not exist or has no values.
+    pub fn row_argmax(&self, y: usize) -> Option<MatrixAddress>
+    where
+        T: PartialOrd,
+    {
+        self.row_extreme(y, |value, best| value > best)
+    }
+
+    /// Finds the address of the smallest value of a column, the topmost one if there are several.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: The index of the column
+    ///
+    /// Returns: `Option<MatrixAddress>`, None if the column does not exist or has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![3, 1, 1], vec![0, 5, 2]]).unwrap();
+    /// assert_eq!(matrix.col_argmin(0), Some(MatrixAddress { x: 0, y: 1 }));
+    /// assert_eq!(matrix.col_argmin(3), None);
+    /// ```
+    pub fn col_argmin(&self, x: usize) -> Option<MatrixAddress>
+    where
+        T: PartialOrd,
+    {
+        self.col_extreme(x, |value, best| value < best)
+    }
+
+    /// Finds the address of the largest value of a column, the topmost one if there are several.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: The index of the column
+    ///
+    /// Returns: `Option<MatrixAddress>`, None if the column does not exist or has no values.
+    pub fn col_argmax(&self, x: usize) -> Option<MatrixAddress>
+    where
+        T: PartialOrd,
+    {
+        self.col_extreme(x, |value, best| value > best)
+    }
+
+    /// Counts the values matching a predicate in each cell of a coarse grid laid over the matrix, with
+    /// cells of `cell_size` by `cell_size` addresses as given by `MatrixAddress::bucket`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell_size`: The width and height of a coarse cell
+    /// * `predicate`: Whether a value is counted
+    ///
+    /// Returns: `SparseMatrix<u32>`, The number of matching values in each coarse cell, where cells
+    /// without any matching values are not occupied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let map = Matrix::from_rows(vec![vec!['#', '.', '#'], vec!['#', '.', '.']]).unwrap();
+    /// let counts = map.bucketed_counts(2, |&cell| cell == '#');
+    /// assert_eq!(counts.get(MatrixAddress { x: 0, y: 0 }), Some(&2));
+    /// assert_eq!(counts.get(MatrixAddress { x: 1, y: 0 }), Some(&1));
+    /// assert_eq!(counts.len(), 2);
+    /// ```
+    pub fn bucketed_counts<F>(&self, cell_size: u32, predicate: F) -> SparseMatrix<u32>
+    where
+        F: Fn(&T) -> bool,
+    {
+        assert!(cell_size != 0, "Bucket size must be nonzero");
+        let mut counts = SparseMatrix::new();
+        for (index, value) in self.data.iter().enumerate() {
+            if predicate(value) {
+                let bucket = self.address_of_index(index).bucket(cell_size);
+                let count = counts.get(bucket).copied().unwrap_or(0);
+                counts.insert(bucket, count + 1);
+            }
+        }
+        counts
+    }
+
+    fn row_extreme(&self, y: usize, replaces: fn(&T, &T) -> bool) -> Option<MatrixAddress> {
+        let x = Self::extreme_position(self.row(y)?.iter(), replaces)?;
+        Some(MatrixAddress {
+            x: x as i32,
+            y: y as i32,
+        })
+    }
+
+    fn col_extreme(&self, x: usize, replaces: fn(&T, &T) -> bool) -> Option<MatrixAddress> {
+        let y = Self::extreme_position(self.column(x)?.into_iter(), replaces)?;
+        Some(MatrixAddress {
+            x: x as i32,
+            y: y as i32,
+        })
+    }
+
+    /// The position of the first value which no later value `replaces`.
+    fn extreme_position<'a>(
+        values: impl Iterator<Item = &'a T>,
+        mut replaces: impl FnMut(&T, &T) -> bool,
+    ) -> Option<usize>
+    where
+        T: 'a,
+    {
+        let mut best: Option<(usize, &T)> = None;
+        for (position, value) in values.enumerate() {
+            if best.is_none_or(|(_, best)| replaces(value, best)) {
+                best = Some((position, value));
+            }
+        }
+        best.map(|(position, _)| position)
+    }
+
+    fn sum_of(values: &[T]) -> T
+    where
+        T: Add<Output = T> + Copy + Default,
+    {
+        values.iter().fold(T::default(), |sum, value| sum + *value)
+    }
+
+    fn mean_of<'a>(values: impl Iterator<Item = &'a T>) -> f64
+    where
+        T: Into<f64> + Copy + 'a,
+    {
+        let (sum, count) = values.fold((0.0, 0usize), |(sum, count), value| {
+            (sum + (*value).into(), count + 1)
+        });
+        sum / count as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    #[test]
+    fn empty_aggregates_test() {
+        let empty = Matrix::<i32>::from_rows(vec![]).unwrap();
+        assert_eq!(empty.sum(), 0);
+        assert_eq!(empty.product(), 1);
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.mean(), None);
+        assert!(empty.row_sums().is_empty());
+        assert!(empty.col_sums().is_empty());
+
+        let no_columns = Matrix::<i32>::from_rows(vec![vec![]; 2]).unwrap();
+        assert_eq!(no_columns.row_sums(), vec![0, 0]);
+        assert!(no_columns.row_means().iter().all(|mean| mean.is_nan()));
+        assert!(no_columns.col_means().is_empty());
+    }
+
+    #[test]
+    fn float_aggregates_test() {
+        let matrix = Matrix::from_rows(vec![vec![0.5, -1.5], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(matrix.sum(), 5.0);
+        assert_eq!(matrix.product(), -6.0);
+        assert_eq!(matrix.mean(), Some(1.25));
+        assert_eq!(matrix.row_means(), vec![-0.5, 3.0]);
+        assert_eq!(matrix.col_means(), vec![1.25, 1.25]);
+    }
+
+    #[test]
+    fn argmin_argmax_test() {
+        let single = Matrix::from_rows(vec![vec![7]]).unwrap();
+        assert_eq!(single.argmin(), Some([0, 0].into()));
+        assert_eq!(single.argmax(), Some([0, 0].into()));
+        assert_eq!(single.row_argmax(0), Some([0, 0].into()));
+        assert_eq!(single.col_argmin(0), Some([0, 0].into()));
+
+        let empty = Matrix::<i32>::from_rows(vec![vec![]; 3]).unwrap();
+        assert_eq!(empty.argmin(), None);
+        assert_eq!(empty.argmax(), None);
+        assert_eq!(empty.row_argmin(1), None);
+        assert_eq!(empty.col_argmax(0), None);
+
+        let with_nan = Matrix::from_rows(vec![vec![1.0, f64::NAN], vec![-1.0, 2.0]]).unwrap();
+        assert_eq!(with_nan.argmin(), Some([0, 1].into()));
+        assert_eq!(with_nan.argmax(), Some([1, 1].into()));
+        assert_eq!(with_nan.col_argmax(1), Some([1, 0].into()));
+    }
+
+    #[test]
+    fn extremes_with_address_test() {
+        let ties = Matrix::from_rows(vec![vec![2, 5, 1], vec![5, 1, 2]]).unwrap();
+        assert_eq!(ties.max_with_address(), Some(([1, 0].into(), &5)));
+        assert_eq!(ties.min_with_address(), Some(([2, 0].into(), &1)));
+        assert_eq!(ties.max_by(|a, b| b.cmp(a)), Some(([2, 0].into(), &1)));
+        assert_eq!(
+            ties.min_by(|a, b| (a % 2).cmp(&(b % 2))),
+            Some(([0, 0].into(), &2))
+        );
+
+        let empty = Matrix::<i32>::from_rows(vec![vec![]; 2]).unwrap();
+        assert_eq!(empty.max_with_address(), None);
+        assert_eq!(empty.min_by(|a, b| a.cmp(b)), None);
+    }
+
+    #[test]
+    fn reduce_columns_single_pass_test() {
+        let (width, height) = (1000, 1000);
+        let matrix = Matrix::new(width, height, |address| (address.x ^ address.y) as i64).unwrap();
+        let mut visited = 0;
+        let mut next_index = 0;
+        let sums = matrix.reduce_columns(0, |sum, value| {
+            // Values arrive in storage order, so the matrix is walked once from front to back.
+            assert!(std::ptr::eq(value, &matrix.data[next_index]));
+            next_index += 1;
+            visited += 1;
+            sum + value
+        });
+        assert_eq!(visited, width * height);
+        assert_eq!(sums.len(), width);
+        for (x, sum) in sums.iter().enumerate() {
+            assert_eq!(*sum, matrix.column(x).unwrap().into_iter().sum::<i64>());
+        }
+        let counts = matrix.reduce_rows(0, |count, _| count + 1);
+        assert_eq!(counts, vec![width; height]);
+
+        let empty_rows = Matrix::<i64>::from_rows(vec![vec![]; 3]).unwrap();
+        assert_eq!(
+            empty_rows.reduce_rows(7, |sum, value| sum + value),
+            vec![7; 3]
+        );
+        assert!(
+            empty_rows
+                .reduce_columns(7, |sum, value| sum + value)
+                .is_empty()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn extremes_match_argmin_argmax_test(values in proptest::collection::vec(-5i32..5, 0..30), width in 1usize..6) {
+            let height = values.len() / width;
+            let matrix = Matrix::from_flat_vec(width, height, values[..width * height].to_vec()).unwrap();
+            assert_eq!(matrix.max_with_address().map(|(address, _)| address), matrix.argmax());
+            assert_eq!(matrix.min_with_address().map(|(address, _)| address), matrix.argmin());
+            if let Some((address, value)) = matrix.max_with_address() {
+                assert_eq!(value, matrix.max().unwrap());
+                assert_eq!(matrix.position_of(value), Some(address));
+            }
+        }
+
+        #[test]
+        fn bucketed_counts_test(values in proptest::collection::vec(0u8..3, 35), cell_size in 1u32..8) {
+            let matrix = Matrix::from_flat_vec(7, 5, values).unwrap();
+            let counts = matrix.bucketed_counts(cell_size, |&value| value == 0);
+            assert_eq!(counts.iter().map(|(_, count)| *count as usize).sum::<usize>(), matrix.iter().filter(|&&value| value == 0).count());
+            for (bucket, count) in counts.iter() {
+                let size = cell_size as i32;
+                let expected = matrix
+                    .address_value_iter()
+                    .filter(|(address, value)| **value == 0 && address.x / size == bucket.x && address.y / size == bucket.y)
+                    .count();
+                assert_eq!(*count as usize, expected);
+            }
+        }
+
+        #[test]
+        fn argmin_argmax_ties_test(rows in proptest::collection::vec(proptest::collection::vec(0u8..3, 1..5), 1..5)) {
+            let width = rows[0].len();
+            let rows = rows.into_iter().map(|mut row| { row.resize(width, 0); row }).collect::<Vec<_>>();
+            let matrix = Matrix::from_rows(rows).unwrap();
+            assert_eq!(&matrix[matrix.argmin().unwrap()], matrix.min().unwrap());
+            assert_eq!(&matrix[matrix.argmax().unwrap()], matrix.max().unwrap());
+            for (y, row) in matrix.rows().enumerate() {
+                let address = matrix.row_argmin(y).unwrap();
+                assert_eq!(address.y, y as i32);
+                assert_eq!(&matrix[address], row.iter().min().unwrap());
+                assert_eq!(&matrix[matrix.row_argmax(y).unwrap()], row.iter().max().unwrap());
+            }
+            for (x, column) in matrix.columns().enumerate() {
+                let column = column.collect::<Vec<_>>();
+                let address = matrix.col_argmax(x).unwrap();
+                assert_eq!(address.x, x as i32);
+                assert_eq!(&matrix[address], *column.iter().max().unwrap());
+                assert_eq!(&matrix[matrix.col_argmin(x).unwrap()], *column.iter().min().unwrap());
+            }
+        }
+
+        #[test]
+        fn aggregates_test(rows in proptest::collection::vec(proptest::collection::vec(-100i32..100, 4), 1..6)) {
+            let matrix = Matrix::from_rows(rows.clone()).unwrap();
+            let values = rows.iter().flatten().copied().collect::<Vec<i32>>();
+            assert_eq!(matrix.sum(), values.iter().sum::<i32>());
+            assert_eq!(matrix.min(), values.iter().min());
+            assert_eq!(matrix.max(), values.iter().max());
+            assert_eq!(matrix.mean(), Some(values.iter().sum::<i32>() as f64 / values.len() as f64));
+            assert_eq!(matrix.row_sums(), rows.iter().map(|row| row.iter().sum()).collect::<Vec<i32>>());
+            assert_eq!(matrix.col_sums().iter().sum::<i32>(), matrix.sum());
+            for (x, sum) in matrix.col_sums().into_iter().enumerate() {
+                assert_eq!(sum, rows.iter().map(|row| row[x]).sum::<i32>());
+                assert_eq!(matrix.col_means()[x], sum as f64 / rows.len() as f64);
+            }
+            let small = matrix.transform(|_, value| (value % 5) as i64);
+            assert_eq!(small.product(), small.iter().product::<i64>());
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::matrix::Matrix;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+/// Matrices are approximately equal if their dimensions are equal and every pair of values at the
+/// same address is approximately equal.
+impl<T: AbsDiffEq> AbsDiffEq for Matrix<T>
+where
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.dimensions() == other.dimensions()
+            && self
+                .data
+                .iter()
+                .zip(&other.data)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<T: RelativeEq> RelativeEq for Matrix<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.dimensions() == other.dimensions()
+            && self
+                .data
+                .iter()
+                .zip(&other.data)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<T: UlpsEq> UlpsEq for Matrix<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.dimensions() == other.dimensions()
+            && self
+                .data
+                .iter()
+                .zip(&other.data)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+    #[test]
+    fn approx_traits_test() {
+        let computed = Matrix::new(3, 3, |address| {
+            (0..10).map(|_| 0.1).sum::<f64>() * (address.x + address.y) as f64
+        })
+        .unwrap();
+        let exact = Matrix::new(3, 3, |address| (address.x + address.y) as f64).unwrap();
+        assert_ne!(computed, exact);
+        assert_abs_diff_eq!(computed, exact, epsilon = 1e-12);
+        assert_relative_eq!(computed, exact);
+        assert_ulps_eq!(computed, exact, max_ulps = 8);
+        assert!(computed.approx_eq(&exact, 1e-12));
+
+        let mut off = exact.clone();
+        off[(2, 1)] += 1e-6;
+        assert_relative_ne!(off, exact);
+        assert_abs_diff_eq!(off, exact, epsilon = 1e-5);
+        assert_relative_ne!(
+            exact,
+            Matrix::new(1, 9, |address| address.y as f64).unwrap(),
+            max_relative = 1.0
+        );
+
+        let singles = Matrix::from_rows(vec![vec![0.1f32 + 0.2, 1.0]]).unwrap();
+        assert_relative_eq!(singles, Matrix::from_rows(vec![vec![0.3f32, 1.0]]).unwrap());
+    }
+}
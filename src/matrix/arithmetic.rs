@@ -0,0 +1,271 @@
+use crate::matrix::Matrix;
+use crate::matrix_error::DimensionMismatchError;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign};
+
+impl<T> Matrix<T> {
+    /// Multiplies two matrices of the same dimensions element by element (the Hadamard product).
+    /// Will return an Err if the dimensions of the two matrices differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The matrix whose elements multiply the elements of this matrix
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`, The element-wise product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(3, 2, |address| address.x).unwrap();
+    /// let b = Matrix::new(3, 2, |address| address.y).unwrap();
+    /// assert_eq!(
+    ///     a.component_mul(&b).unwrap(),
+    ///     Matrix::new(3, 2, |address| address.x * address.y).unwrap()
+    /// );
+    /// ```
+    pub fn component_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionMismatchError>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        self.zip_with(other, |a, b| a.clone() * b.clone())
+    }
+
+    /// Divides two matrices of the same dimensions element by element.
+    /// Will return an Err if the dimensions of the two matrices differ.
+    ///
+    /// Division by a zero element follows the semantics of the element type:
+    /// integer types panic, while floating point types produce infinity or NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The matrix whose elements divide the elements of this matrix
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`, The element-wise quotient.
+    pub fn component_div(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionMismatchError>
+    where
+        T: Div<Output = T> + Clone,
+    {
+        self.zip_with(other, |a, b| a.clone() / b.clone())
+    }
+
+    /// Multiplies this matrix by another matrix of the same dimensions element by element,
+    /// storing the result in this matrix instead of allocating a new one.
+    /// Will return an Err, leaving this matrix untouched, if the dimensions of the two matrices differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The matrix whose elements multiply the elements of this matrix
+    ///
+    /// Returns: `Result<(), DimensionMismatchError>`
+    pub fn component_mul_assign(&mut self, other: &Matrix<T>) -> Result<(), DimensionMismatchError>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        self.zip_with_in_place(other, |a, b| *a = a.clone() * b.clone())
+    }
+
+    /// Divides this matrix by another matrix of the same dimensions element by element,
+    /// storing the result in this matrix instead of allocating a new one.
+    /// Will return an Err, leaving this matrix untouched, if the dimensions of the two matrices differ.
+    ///
+    /// Division by a zero element follows the semantics of the element type, as in `component_div`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The matrix whose elements divide the elements of this matrix
+    ///
+    /// Returns: `Result<(), DimensionMismatchError>`
+    pub fn component_div_assign(&mut self, other: &Matrix<T>) -> Result<(), DimensionMismatchError>
+    where
+        T: Div<Output = T> + Clone,
+    {
+        self.zip_with_in_place(other, |a, b| *a = a.clone() / b.clone())
+    }
+}
+
+impl<T: Mul<Output = T> + Clone> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Multiplies every element of the matrix by a scalar.
+    fn mul(self, rhs: T) -> Self::Output {
+        Matrix::from_parts(
+            self.width,
+            self.height,
+            self.data
+                .into_iter()
+                .map(|value| value * rhs.clone())
+                .collect(),
+        )
+    }
+}
+
+impl<T: Mul<Output = T> + Clone> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Multiplies every element of the matrix by a scalar.
+    fn mul(self, rhs: T) -> Self::Output {
+        Matrix::from_parts(
+            self.width,
+            self.height,
+            self.data
+                .iter()
+                .map(|value| value.clone() * rhs.clone())
+                .collect(),
+        )
+    }
+}
+
+impl<T: Add<Output = T> + Clone> Add<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Adds a scalar to every element of the matrix.
+    fn add(self, rhs: T) -> Self::Output {
+        Matrix::from_parts(
+            self.width,
+            self.height,
+            self.data
+                .into_iter()
+                .map(|value| value + rhs.clone())
+                .collect(),
+        )
+    }
+}
+
+impl<T: Add<Output = T> + Clone> Add<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Adds a scalar to every element of the matrix.
+    fn add(self, rhs: T) -> Self::Output {
+        Matrix::from_parts(
+            self.width,
+            self.height,
+            self.data
+                .iter()
+                .map(|value| value.clone() + rhs.clone())
+                .collect(),
+        )
+    }
+}
+
+impl<T: MulAssign + Clone> MulAssign<T> for Matrix<T> {
+    /// Multiplies every element of the matrix by a scalar in place.
+    fn mul_assign(&mut self, rhs: T) {
+        self.data.iter_mut().for_each(|value| *value *= rhs.clone());
+    }
+}
+
+impl<T: AddAssign + Clone> AddAssign<T> for Matrix<T> {
+    /// Adds a scalar to every element of the matrix in place.
+    fn add_assign(&mut self, rhs: T) {
+        self.data.iter_mut().for_each(|value| *value += rhs.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::tensor::Tensor;
+
+    #[test]
+    fn component_mul_test() {
+        let (width, height) = (20, 10);
+        let a = Matrix::new(width, height, |address| address.x + 1).unwrap();
+        let b = Matrix::new(width, height, |address| address.y - 3).unwrap();
+        let product = a.component_mul(&b).unwrap();
+        assert_eq!(
+            product,
+            Matrix::new(width, height, |address| (address.x + 1) * (address.y - 3)).unwrap()
+        );
+
+        let mut in_place = a.clone();
+        in_place.component_mul_assign(&b).unwrap();
+        assert_eq!(in_place, product);
+
+        let c = Matrix::new(height, width, |_| 1).unwrap();
+        assert!(a.component_mul(&c).is_err());
+        assert!(in_place.component_mul_assign(&c).is_err());
+        assert_eq!(in_place, product);
+    }
+
+    #[test]
+    fn component_div_test() {
+        let a = Matrix::new(4, 3, |address| 12.0 * (address.x + address.y) as f64).unwrap();
+        let b = Matrix::new(4, 3, |address| (address.x + 1) as f64).unwrap();
+        assert_eq!(
+            a.component_div(&b).unwrap(),
+            Matrix::new(4, 3, |address| 12.0 * (address.x + address.y) as f64
+                / (address.x + 1) as f64)
+            .unwrap()
+        );
+        let mut in_place = a.clone();
+        in_place.component_div_assign(&b).unwrap();
+        assert_eq!(in_place, a.component_div(&b).unwrap());
+    }
+
+    #[test]
+    fn component_div_float_zero_test() {
+        let a = Matrix::new(3, 1, |address| [1.0, -1.0, 0.0][address.x as usize]).unwrap();
+        let zeros = Matrix::new(3, 1, |_| 0.0).unwrap();
+        let quotient = a.component_div(&zeros).unwrap();
+        assert_eq!(quotient[(0, 0)], f64::INFINITY);
+        assert_eq!(quotient[(1, 0)], f64::NEG_INFINITY);
+        assert!(quotient[(2, 0)].is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn component_div_integer_zero_test() {
+        let a = Matrix::new(3, 3, |_| 1).unwrap();
+        let zeros = Matrix::new(3, 3, |_| 0).unwrap();
+        let _ = a.component_div(&zeros);
+    }
+
+    #[test]
+    fn scalar_i32_test() {
+        let (width, height) = (9, 5);
+        let matrix = Matrix::new(width, height, |address| address.x - address.y).unwrap();
+        let tripled = Matrix::new(width, height, |address| 3 * (address.x - address.y)).unwrap();
+        let incremented = Matrix::new(width, height, |address| address.x - address.y + 1).unwrap();
+        assert_eq!(&matrix * 3, tripled);
+        assert_eq!(&matrix + 1, incremented);
+        assert_eq!(matrix.clone() * 3, tripled);
+        assert_eq!(matrix.clone() + 1, incremented);
+
+        let owned = matrix.clone();
+        let buffer = owned.as_slice().as_ptr();
+        let owned = owned * 3 + 1;
+        assert_eq!(owned.as_slice().as_ptr(), buffer);
+        assert_eq!(owned, &tripled + 1);
+
+        let mut in_place = matrix.clone();
+        in_place *= 3;
+        assert_eq!(in_place, tripled);
+        let mut in_place = matrix;
+        in_place += 1;
+        assert_eq!(in_place, incremented);
+    }
+
+    #[test]
+    fn scalar_f64_test() {
+        let (width, height) = (9, 5);
+        let matrix = Matrix::new(width, height, |address| address.x as f64 / 4.0).unwrap();
+        let halved = &matrix * 0.5;
+        let shifted = &matrix + -0.25;
+        halved.address_value_iter().for_each(|(address, value)| {
+            assert_eq!(*value, address.x as f64 / 8.0);
+            assert_eq!(shifted[address], address.x as f64 / 4.0 - 0.25);
+        });
+        let mut in_place = matrix.clone();
+        in_place *= 0.5;
+        in_place += 1.0;
+        assert_eq!(in_place, halved + 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scalar_overflow_test() {
+        let matrix = Matrix::new(2, 2, |_| i32::MAX).unwrap();
+        let _ = &matrix + 1;
+    }
+}
@@ -0,0 +1,289 @@
+use crate::binary::{BinaryElement, Endianness};
+use crate::matrix::Matrix;
+use crate::matrix_error::{DimensionMismatchError, MatrixError};
+use std::io::{ErrorKind, Read, Write};
+
+/// The version written at the start of the format of `Matrix::write_binary`.
+const BINARY_FORMAT_VERSION: u8 = 1;
+
+impl<T: BinaryElement> Matrix<T> {
+    /// Reads a matrix from a headerless dump of raw elements in row-major order,
+    /// such as the output of numpy's `tofile`.
+    /// Exactly `width * height` elements are read; any further bytes in the reader are left unread.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader`: The source of the raw element bytes
+    /// * `width`: The width, or number of columns in the matrix
+    /// * `height`: The height, or number of rows in the matrix
+    /// * `endianness`: The byte order of each element
+    ///
+    /// Returns: `Result<Matrix<T>, MatrixError>`, An Err if the width or height is zero, if the
+    /// matrix is too large to address, or if the reader fails or ends before all elements are read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::binary::Endianness;
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let bytes: &[u8] = &[0, 1, 0, 2, 0, 3, 0, 4];
+    /// let matrix = Matrix::<u16>::from_reader_binary(bytes, 2, 2, Endianness::Big).unwrap();
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap());
+    /// ```
+    pub fn from_reader_binary<R: Read>(
+        reader: R,
+        width: usize,
+        height: usize,
+        endianness: Endianness,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if width == 0 || height == 0 {
+            return Err(DimensionMismatchError {
+                message: "Matrix dimensions must be non-zero".into(),
+            }
+            .into());
+        }
+        read_elements(reader, width as u64, height as u64, endianness)
+    }
+
+    /// Writes the elements of the matrix in row-major order without any header,
+    /// the inverse of `Matrix::from_reader_binary`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer`: The destination of the raw element bytes
+    /// * `endianness`: The byte order of each element
+    ///
+    /// Returns: `Result<(), MatrixError>`, An Err if the writer fails.
+    pub fn write_binary_to<W: Write>(
+        &self,
+        mut writer: W,
+        endianness: Endianness,
+    ) -> Result<(), MatrixError> {
+        let mut bytes = Vec::with_capacity(self.data.len() * T::SIZE);
+        self.data
+            .iter()
+            .for_each(|value| value.write_bytes(endianness, &mut bytes));
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Writes the matrix in a self-describing binary format which can be read back with
+    /// `Matrix::read_binary` on any platform.
+    ///
+    /// The format is a version byte, currently 1, followed by the width and the height as little
+    /// endian `u64`s and the little endian elements in row-major order. The element type is not
+    /// recorded, so the matrix must be read back with the same `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer`: The destination of the bytes
+    ///
+    /// Returns: `Result<(), MatrixError>`, An Err if the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1u8, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// let mut bytes = Vec::new();
+    /// matrix.write_binary(&mut bytes).unwrap();
+    /// assert_eq!(bytes.len(), 1 + 8 + 8 + 6);
+    /// assert_eq!(Matrix::<u8>::read_binary(&bytes[..]).unwrap(), matrix);
+    /// ```
+    pub fn write_binary<W: Write>(&self, mut writer: W) -> Result<(), MatrixError> {
+        let mut header = vec![BINARY_FORMAT_VERSION];
+        (self.width as u64).write_bytes(Endianness::Little, &mut header);
+        (self.height as u64).write_bytes(Endianness::Little, &mut header);
+        writer.write_all(&header)?;
+        self.write_binary_to(writer, Endianness::Little)
+    }
+
+    /// Reads a matrix written by `Matrix::write_binary`. Any bytes following the matrix are left
+    /// unread.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader`: The source of the bytes
+    ///
+    /// Returns: `Result<Matrix<T>, MatrixError>`, An Err if the reader fails or ends before the whole
+    /// matrix is read, or if the version is not supported.
+    pub fn read_binary<R: Read>(mut reader: R) -> Result<Matrix<T>, MatrixError> {
+        let mut header = [0u8; 17];
+        reader.read_exact(&mut header)?;
+        if header[0] != BINARY_FORMAT_VERSION {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!("Unsupported binary matrix format version {}", header[0]),
+            )
+            .into());
+        }
+        let width = u64::from_bytes(&header[1..9], Endianness::Little);
+        let height = u64::from_bytes(&header[9..17], Endianness::Little);
+        read_elements(reader, width, height, Endianness::Little)
+    }
+}
+
+/// Reads `width * height` elements in row-major order into a matrix.
+fn read_elements<T: BinaryElement, R: Read>(
+    reader: R,
+    width: u64,
+    height: u64,
+    endianness: Endianness,
+) -> Result<Matrix<T>, MatrixError> {
+    let too_large = || DimensionMismatchError {
+        message: format!("A {}x{} matrix is too large to read", width, height),
+    };
+    let length = width
+        .checked_mul(height)
+        .and_then(|count| count.checked_mul(T::SIZE as u64))
+        .filter(|&length| usize::try_from(length).is_ok())
+        .ok_or_else(too_large)?;
+    // Reading through `take` grows the buffer as bytes arrive, so that a corrupt header cannot
+    // cause a huge allocation up front.
+    let mut bytes = Vec::new();
+    reader.take(length).read_to_end(&mut bytes)?;
+    if (bytes.len() as u64) < length {
+        return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into());
+    }
+    Ok(Matrix::from_parts(
+        width as usize,
+        height as usize,
+        bytes
+            .chunks_exact(T::SIZE)
+            .map(|chunk| T::from_bytes(chunk, endianness))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::binary::Endianness;
+    use crate::matrix::Matrix;
+    use crate::matrix_error::MatrixError;
+    use proptest::proptest;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn golden_fixture_test() {
+        let expected =
+            Matrix::from_rows(vec![vec![1i32, -2, 258], vec![0x01020304, 0, -1]]).unwrap();
+        let little: [u8; 24] = [
+            1, 0, 0, 0, 254, 255, 255, 255, 2, 1, 0, 0, //
+            4, 3, 2, 1, 0, 0, 0, 0, 255, 255, 255, 255,
+        ];
+        let big: [u8; 24] = [
+            0, 0, 0, 1, 255, 255, 255, 254, 0, 0, 1, 2, //
+            1, 2, 3, 4, 0, 0, 0, 0, 255, 255, 255, 255,
+        ];
+        assert_eq!(
+            Matrix::<i32>::from_reader_binary(&little[..], 3, 2, Endianness::Little).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Matrix::<i32>::from_reader_binary(&big[..], 3, 2, Endianness::Big).unwrap(),
+            expected
+        );
+        let mut written = Vec::new();
+        expected
+            .write_binary_to(&mut written, Endianness::Little)
+            .unwrap();
+        assert_eq!(written, little);
+        let mut written = Vec::new();
+        expected
+            .write_binary_to(&mut written, Endianness::Big)
+            .unwrap();
+        assert_eq!(written, big);
+    }
+
+    #[test]
+    fn float_fixture_test() {
+        let bytes: [u8; 8] = [0x3f, 0xf0, 0, 0, 0, 0, 0, 0];
+        let matrix = Matrix::<f64>::from_reader_binary(&bytes[..], 1, 1, Endianness::Big).unwrap();
+        assert_eq!(matrix[(0, 0)], 1.0);
+    }
+
+    #[test]
+    fn short_read_test() {
+        let bytes = [0u8; 15];
+        match Matrix::<u32>::from_reader_binary(&bytes[..], 2, 2, Endianness::Little) {
+            Err(MatrixError::Io { kind, .. }) => assert_eq!(kind, ErrorKind::UnexpectedEof),
+            other => panic!("Expected an unexpected end of file error, got {:?}", other),
+        }
+        assert!(Matrix::<u8>::from_reader_binary(&bytes[..], 0, 2, Endianness::Little).is_err());
+        assert!(matches!(
+            Matrix::<u32>::from_reader_binary(&bytes[..], usize::MAX, 2, Endianness::Little),
+            Err(MatrixError::DimensionMismatch(_))
+        ));
+        match Matrix::<u8>::from_reader_binary(&bytes[..], 1 << 20, 1 << 20, Endianness::Little) {
+            Err(MatrixError::Io { kind, .. }) => assert_eq!(kind, ErrorKind::UnexpectedEof),
+            other => panic!("Expected an unexpected end of file error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn versioned_format_test() {
+        let matrix = Matrix::from_rows(vec![vec![1i32, -2, 258], vec![0x01020304, 0, -1]]).unwrap();
+        let mut bytes = Vec::new();
+        matrix.write_binary(&mut bytes).unwrap();
+        let mut expected = vec![1, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+        matrix
+            .write_binary_to(&mut expected, Endianness::Little)
+            .unwrap();
+        assert_eq!(bytes, expected);
+
+        let mut trailing = bytes.clone();
+        trailing.push(7);
+        assert_eq!(Matrix::<i32>::read_binary(&trailing[..]).unwrap(), matrix);
+        for length in [0, 16, bytes.len() - 1] {
+            match Matrix::<i32>::read_binary(&bytes[..length]) {
+                Err(MatrixError::Io { kind, .. }) => assert_eq!(kind, ErrorKind::UnexpectedEof),
+                other => panic!("Expected an unexpected end of file error, got {:?}", other),
+            }
+        }
+        bytes[0] = 2;
+        match Matrix::<i32>::read_binary(&bytes[..]) {
+            Err(MatrixError::Io { kind, .. }) => assert_eq!(kind, ErrorKind::InvalidData),
+            other => panic!("Expected an invalid data error, got {:?}", other),
+        }
+        let mut huge = vec![1];
+        huge.extend_from_slice(&[0xff; 16]);
+        assert!(matches!(
+            Matrix::<i32>::read_binary(&huge[..]),
+            Err(MatrixError::DimensionMismatch(_))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn versioned_round_trip_test(width in 0usize..12, height in 0usize..12, seed in proptest::num::f32::ANY) {
+            let floats = Matrix::from_parts(width, height, (0..width * height).map(|index| seed * index as f32).collect());
+            let integers = Matrix::from_parts(width, height, (0..width * height).map(|index| index as i32 - 40).collect());
+            let bytes = Matrix::from_parts(width, height, (0..width * height).map(|index| index as u8).collect());
+            let mut written = Vec::new();
+            floats.write_binary(&mut written).unwrap();
+            let decoded = Matrix::<f32>::read_binary(&written[..]).unwrap();
+            assert_eq!(decoded.dimensions(), (width, height));
+            assert!(decoded.data.iter().zip(&floats.data).all(|(a, b)| a.to_bits() == b.to_bits()));
+            let mut written = Vec::new();
+            integers.write_binary(&mut written).unwrap();
+            assert_eq!(Matrix::<i32>::read_binary(&written[..]).unwrap(), integers);
+            let mut written = Vec::new();
+            bytes.write_binary(&mut written).unwrap();
+            assert_eq!(Matrix::<u8>::read_binary(&written[..]).unwrap(), bytes);
+        }
+
+        #[test]
+        fn round_trip_test(width in 1usize..20, height in 1usize..20, seed in proptest::num::f64::ANY) {
+            let matrix = Matrix::new(width, height, |address| seed * address.x as f64 - address.y as f64).unwrap();
+            for endianness in [Endianness::Little, Endianness::Big] {
+                let mut bytes = Vec::new();
+                matrix.write_binary_to(&mut bytes, endianness).unwrap();
+                assert_eq!(bytes.len(), width * height * 8);
+                let decoded = Matrix::<f64>::from_reader_binary(&bytes[..], width, height, endianness).unwrap();
+                assert!(decoded.data.iter().zip(&matrix.data).all(|(a, b)| a.to_bits() == b.to_bits()));
+            }
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::matrix::{EdgeMode, Matrix};
+use crate::matrix_error::DimensionMismatchError;
+
+impl Matrix<f64> {
+    /// Computes the discrete 2D cross-correlation of the matrix with a kernel, treating every cell
+    /// outside the matrix as zero so the result has the same dimensions as the matrix.
+    ///
+    /// The kernel is not flipped, and the cell at `(kernel.width / 2, kernel.height / 2)` of the
+    /// kernel is its anchor, which lies over the output cell being computed.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel`: The weights of the neighbourhood of each cell
+    ///
+    /// Returns: `Matrix<f64>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![0.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]]).unwrap();
+    /// let kernel = Matrix::from_rows(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.convolve(&kernel),
+    ///     Matrix::from_rows(vec![vec![0.0, 0.0, 0.0], vec![3.0, 2.0, 1.0]]).unwrap()
+    /// );
+    /// ```
+    pub fn convolve(&self, kernel: &Matrix<f64>) -> Matrix<f64> {
+        self.correlate_same(kernel, &EdgeMode::Constant(0.0))
+    }
+
+    /// Computes the discrete 2D cross-correlation of the matrix with a kernel centered on each cell,
+    /// taking the values outside the matrix from an edge mode so the result has the same dimensions
+    /// as the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel`: The weights of the neighbourhood of each cell, with an odd width and height so
+    ///   that it has a center
+    /// * `edge_mode`: How the values outside the matrix are chosen
+    ///
+    /// Returns: `Result<Matrix<f64>, DimensionMismatchError>`, An Err if the width or height of the
+    /// kernel is even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::{EdgeMode, Matrix};
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.0, 2.0, 4.0]]).unwrap();
+    /// let kernel = Matrix::from_rows(vec![vec![1.0, 1.0, 1.0]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.convolve_with(&kernel, &EdgeMode::Clamp).unwrap(),
+    ///     Matrix::from_rows(vec![vec![4.0, 7.0, 10.0]]).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     matrix.convolve_with(&kernel, &EdgeMode::Wrap).unwrap(),
+    ///     Matrix::from_rows(vec![vec![7.0, 7.0, 7.0]]).unwrap()
+    /// );
+    /// assert!(matrix.convolve_with(&Matrix::new(2, 1, |_| 1.0).unwrap(), &EdgeMode::Clamp).is_err());
+    /// ```
+    pub fn convolve_with(
+        &self,
+        kernel: &Matrix<f64>,
+        edge_mode: &EdgeMode<f64>,
+    ) -> Result<Matrix<f64>, DimensionMismatchError> {
+        if kernel.width.is_multiple_of(2) || kernel.height.is_multiple_of(2) {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Kernel dimensions must be odd, got {}x{}",
+                    kernel.width, kernel.height
+                ),
+            });
+        }
+        Ok(self.correlate_same(kernel, edge_mode))
+    }
+
+    /// Computes the discrete 2D cross-correlation of the matrix with a kernel at every position where
+    /// the kernel lies entirely inside the matrix, so no padding is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel`: The weights of the neighbourhood of each cell
+    ///
+    /// Returns: `Matrix<f64>`, of size `(width - kernel.width + 1)x(height - kernel.height + 1)`,
+    /// where the value at each address is computed with the kernel's top left corner at that address.
+    /// It has no cells if the kernel is larger than the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+    /// let kernel = Matrix::from_rows(vec![vec![1.0, 1.0], vec![1.0, 1.0]]).unwrap();
+    /// assert_eq!(matrix.convolve_valid(&kernel), Matrix::from_rows(vec![vec![12.0, 16.0]]).unwrap());
+    /// ```
+    pub fn convolve_valid(&self, kernel: &Matrix<f64>) -> Matrix<f64> {
+        let (width, height) = if kernel.width > self.width || kernel.height > self.height {
+            (0, 0)
+        } else {
+            (
+                self.width - kernel.width + 1,
+                self.height - kernel.height + 1,
+            )
+        };
+        self.correlate(kernel, width, height, 0, 0)
+    }
+
+    /// Correlates with the kernel anchored at `(kernel.width / 2, kernel.height / 2)` over every cell,
+    /// padding the matrix with the edge mode so that the kernel never leaves it.
+    fn correlate_same(&self, kernel: &Matrix<f64>, edge_mode: &EdgeMode<f64>) -> Matrix<f64> {
+        if self.is_empty() {
+            return Matrix::from_parts(self.width, self.height, Vec::new());
+        }
+        let (anchor_x, anchor_y) = (kernel.width / 2, kernel.height / 2);
+        let thickness = anchor_x.max(anchor_y);
+        self.pad_with(thickness, edge_mode).correlate(
+            kernel,
+            self.width,
+            self.height,
+            thickness - anchor_x,
+            thickness - anchor_y,
+        )
+    }
+
+    /// Builds a `width`x`height` matrix whose value at each address is the sum of the kernel weights
+    /// times the values under the kernel, with its top left corner placed at that address shifted by
+    /// `(left, top)`. The kernel must lie inside the matrix at every position.
+    fn correlate(
+        &self,
+        kernel: &Matrix<f64>,
+        width: usize,
+        height: usize,
+        left: usize,
+        top: usize,
+    ) -> Matrix<f64> {
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let mut sum = 0.0;
+                for (kernel_y, weights) in kernel.rows().enumerate() {
+                    let start = (top + y + kernel_y) * self.width + left + x;
+                    for (weight, value) in
+                        weights.iter().zip(&self.data[start..start + kernel.width])
+                    {
+                        sum += weight * value;
+                    }
+                }
+                data.push(sum);
+            }
+        }
+        Matrix::from_parts(width, height, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{EdgeMode, Matrix};
+    use crate::matrix_address::MatrixAddress;
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    fn gaussian_kernel() -> Matrix<f64> {
+        Matrix::from_rows(vec![
+            vec![1.0, 2.0, 1.0],
+            vec![2.0, 4.0, 2.0],
+            vec![1.0, 2.0, 1.0],
+        ])
+        .unwrap()
+            * (1.0 / 16.0)
+    }
+
+    fn one_to_nine() -> Matrix<f64> {
+        Matrix::new(3, 3, |address| (address.y * 3 + address.x + 1) as f64).unwrap()
+    }
+
+    #[test]
+    fn gaussian_blur_test() {
+        let expected = Matrix::from_rows(vec![
+            vec![21.0, 36.0, 33.0],
+            vec![52.0, 80.0, 68.0],
+            vec![57.0, 84.0, 69.0],
+        ])
+        .unwrap()
+            * (1.0 / 16.0);
+        assert!(
+            one_to_nine()
+                .convolve(&gaussian_kernel())
+                .approx_eq(&expected, 1e-12)
+        );
+    }
+
+    #[test]
+    fn identity_kernel_test() {
+        let matrix = Matrix::new(5, 4, |address| {
+            ((address.x * 31 + address.y * 17) as f64).sin()
+        })
+        .unwrap();
+        let identity = Matrix::new(
+            3,
+            3,
+            |address| if address == [1, 1].into() { 1.0 } else { 0.0 },
+        )
+        .unwrap();
+        for edge_mode in [EdgeMode::Constant(0.0), EdgeMode::Clamp, EdgeMode::Wrap] {
+            assert_eq!(matrix.convolve_with(&identity, &edge_mode).unwrap(), matrix);
+        }
+    }
+
+    #[test]
+    fn even_kernel_test() {
+        for (width, height) in [(2, 3), (3, 2), (0, 1), (4, 4)] {
+            let kernel = Matrix::from_flat_vec(width, height, vec![1.0; width * height]).unwrap();
+            assert_eq!(
+                one_to_nine()
+                    .convolve_with(&kernel, &EdgeMode::Clamp)
+                    .unwrap_err()
+                    .message,
+                format!("Kernel dimensions must be odd, got {}x{}", width, height)
+            );
+        }
+        let empty = Matrix::<f64>::from_rows(vec![vec![]; 2]).unwrap();
+        assert_eq!(
+            empty
+                .convolve_with(&gaussian_kernel(), &EdgeMode::Wrap)
+                .unwrap(),
+            empty
+        );
+    }
+
+    #[test]
+    fn gaussian_blur_valid_test() {
+        let blurred = one_to_nine().convolve_valid(&gaussian_kernel());
+        assert!(blurred.approx_eq(&Matrix::from_rows(vec![vec![5.0]]).unwrap(), 1e-12));
+        let too_large = Matrix::new(4, 1, |_| 1.0).unwrap();
+        assert_eq!(one_to_nine().convolve_valid(&too_large).iter().count(), 0);
+    }
+
+    #[test]
+    fn convolve_does_not_flip_test() {
+        let shift_right = Matrix::from_rows(vec![vec![1.0, 0.0]]).unwrap();
+        assert_eq!(
+            one_to_nine().convolve(&shift_right),
+            Matrix::from_rows(vec![
+                vec![0.0, 1.0, 2.0],
+                vec![0.0, 4.0, 5.0],
+                vec![0.0, 7.0, 8.0]
+            ])
+            .unwrap()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn convolve_with_edge_modes_test(width in 1usize..6, height in 1usize..6, kernel_width in 0usize..3, kernel_height in 0usize..3) {
+            let (kernel_width, kernel_height) = (kernel_width * 2 + 1, kernel_height * 2 + 1);
+            let matrix = Matrix::new(width, height, |address| (address.x * 5 - address.y * 3) as f64).unwrap();
+            let kernel = Matrix::new(kernel_width, kernel_height, |address| (address.x - address.y) as f64 + 0.25).unwrap();
+            let anchor = MatrixAddress { x: (kernel_width / 2) as i32, y: (kernel_height / 2) as i32 };
+            for edge_mode in [EdgeMode::Constant(-2.0), EdgeMode::Clamp, EdgeMode::Wrap] {
+                let convolved = matrix.convolve_with(&kernel, &edge_mode).unwrap();
+                for (address, value) in convolved.address_value_iter() {
+                    let mut expected = 0.0;
+                    for (offset, weight) in kernel.address_value_iter() {
+                        expected += weight * edge_mode.value_at(&matrix, address + offset - anchor);
+                    }
+                    assert_eq!(*value, expected);
+                }
+            }
+        }
+
+        #[test]
+        fn convolve_valid_matches_windows_test(width in 1usize..7, height in 1usize..7, kernel_width in 1usize..4, kernel_height in 1usize..4) {
+            let matrix = Matrix::new(width, height, |address| (address.x * 7 - address.y * 3) as f64).unwrap();
+            let kernel = Matrix::new(kernel_width, kernel_height, |address| (address.x + 2 * address.y) as f64 - 1.5).unwrap();
+            let convolved = matrix.convolve_valid(&kernel);
+            let same = matrix.convolve(&kernel);
+            let anchor = MatrixAddress { x: (kernel_width / 2) as i32, y: (kernel_height / 2) as i32 };
+            let mut count = 0;
+            for (corner, window) in matrix.windows(kernel_width, kernel_height) {
+                let expected = window.zip_with(&kernel, |a, b| a * b).unwrap().iter().sum::<f64>();
+                assert_eq!(convolved[corner], expected);
+                assert_eq!(same[corner + anchor], expected);
+                count += 1;
+            }
+            assert_eq!(convolved.iter().count(), count);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::tensor::Tensor;
+use std::ops::{Index, IndexMut};
+use std::sync::Arc;
+
+/// A matrix whose values are shared between clones, so cloning is O(1).
+/// The first mutation of a clone whose values are still shared copies the values (copy-on-write),
+/// leaving every other clone unaffected.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CowMatrix<T: Clone> {
+    width: usize,
+    height: usize,
+    data: Arc<Vec<T>>,
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Converts the matrix into a `CowMatrix` without copying its values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let shared = Matrix::new(100, 100, |address| address.x).unwrap().into_shared();
+    /// let mut copy = shared.clone();
+    /// copy[(3, 4)] = -1;
+    /// assert_eq!(shared[(3, 4)], 3);
+    /// assert_eq!(copy[(3, 4)], -1);
+    /// ```
+    pub fn into_shared(self) -> CowMatrix<T> {
+        CowMatrix {
+            width: self.width,
+            height: self.height,
+            data: Arc::new(self.data),
+        }
+    }
+}
+
+impl<T: Clone> CowMatrix<T> {
+    /// Converts back into a `Matrix`. The values are only copied if they are still shared with
+    /// another clone.
+    pub fn into_owned(self) -> Matrix<T> {
+        Matrix::from_parts(self.width, self.height, Arc::unwrap_or_clone(self.data))
+    }
+
+    /// Whether this matrix currently shares its values with at least one other clone.
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.data) > 1
+    }
+
+    fn index_address(&self, address: MatrixAddress) -> usize {
+        if !self.contains_address(address) {
+            panic!(
+                "Address ({}, {}) is out of bounds for a {}x{} matrix",
+                address.x, address.y, self.width, self.height
+            )
+        }
+        address.y as usize * self.width + address.x as usize
+    }
+}
+
+impl<'a, T: Clone + 'a> Tensor<'a, T, i32, MatrixAddress, 2> for CowMatrix<T> {
+    fn smallest_contained_address(&self) -> MatrixAddress {
+        MatrixAddress { x: 0, y: 0 }
+    }
+
+    fn largest_contained_address(&self) -> MatrixAddress {
+        MatrixAddress {
+            x: self.width as i32 - 1,
+            y: self.height as i32 - 1,
+        }
+    }
+}
+
+impl<T: Clone> Index<MatrixAddress> for CowMatrix<T> {
+    type Output = T;
+
+    fn index(&self, index: MatrixAddress) -> &Self::Output {
+        &self.data[self.index_address(index)]
+    }
+}
+
+impl<T: Clone> Index<(i32, i32)> for CowMatrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (i32, i32)) -> &Self::Output {
+        &self[MatrixAddress {
+            x: index.0,
+            y: index.1,
+        }]
+    }
+}
+
+impl<T: Clone> IndexMut<MatrixAddress> for CowMatrix<T> {
+    /// Copies the values first if they are shared with another clone.
+    fn index_mut(&mut self, index: MatrixAddress) -> &mut Self::Output {
+        let index = self.index_address(index);
+        &mut Arc::make_mut(&mut self.data)[index]
+    }
+}
+
+impl<T: Clone> IndexMut<(i32, i32)> for CowMatrix<T> {
+    /// Copies the values first if they are shared with another clone.
+    fn index_mut(&mut self, index: (i32, i32)) -> &mut Self::Output {
+        &mut self[MatrixAddress {
+            x: index.0,
+            y: index.1,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::tensor::Tensor;
+    use std::sync::Arc;
+
+    #[test]
+    fn shared_allocation_test() {
+        let shared = Matrix::new(50, 40, |address| address.x * address.y)
+            .unwrap()
+            .into_shared();
+        let clones = (0..10).map(|_| shared.clone()).collect::<Vec<_>>();
+        assert_eq!(Arc::strong_count(&shared.data), 11);
+        assert!(
+            clones
+                .iter()
+                .all(|clone| Arc::ptr_eq(&clone.data, &shared.data))
+        );
+        drop(clones);
+        assert!(!shared.is_shared());
+    }
+
+    #[test]
+    fn copy_on_write_test() {
+        let original = Matrix::new(50, 40, |address| address.x * address.y).unwrap();
+        let shared = original.clone().into_shared();
+        let mut first = shared.clone();
+        let second = shared.clone();
+
+        first[(10, 10)] = -5;
+        assert!(!Arc::ptr_eq(&first.data, &shared.data));
+        assert_eq!(Arc::strong_count(&shared.data), 2);
+        assert_eq!(first[(10, 10)], -5);
+        assert_eq!(second[(10, 10)], 100);
+        assert_eq!(shared[(10, 10)], 100);
+
+        let pointer = Arc::as_ptr(&first.data);
+        first[(11, 10)] = -6;
+        assert_eq!(Arc::as_ptr(&first.data), pointer);
+
+        *first.get_mut([0, 0].into()).unwrap() = 7;
+        assert_eq!(first.get([0, 0].into()), Some(&7));
+        assert_eq!(second.into_owned(), original);
+    }
+
+    #[test]
+    #[should_panic(expected = "Address (3, 0) is out of bounds for a 3x2 matrix")]
+    fn index_past_row_end_test() {
+        let shared = Matrix::new(3, 2, |_| 0).unwrap().into_shared();
+        let _ = shared[(3, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "Address (-1, 1) is out of bounds for a 3x2 matrix")]
+    fn index_negative_test() {
+        let mut shared = Matrix::new(3, 2, |_| 0).unwrap().into_shared();
+        shared[(-1, 1)] = 1;
+    }
+
+    #[test]
+    fn owned_round_trip_test() {
+        let original = Matrix::new(13, 7, |address| format!("{:?}", address)).unwrap();
+        let shared = original.clone().into_shared();
+        let buffer = shared.data.as_ptr();
+        shared
+            .address_value_iter()
+            .for_each(|(address, value)| assert_eq!(*value, original[address]));
+        let owned = shared.into_owned();
+        assert_eq!(owned.data.as_ptr(), buffer);
+        assert_eq!(owned, original);
+    }
+}
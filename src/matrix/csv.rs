@@ -0,0 +1,221 @@
+use crate::matrix::Matrix;
+use crate::matrix_error::MatrixError;
+use std::io::{Error, ErrorKind, Read, Write};
+
+impl<T> Matrix<T> {
+    /// Reads a matrix from CSV as described by RFC 4180, with one record per row.
+    ///
+    /// Fields are separated by commas and records by CRLF, although a bare LF is accepted too.
+    /// Fields may be enclosed in double quotes, in which case they can contain commas, line breaks
+    /// and doubled double quotes, which stand for a single double quote. A line break at the end of
+    /// the input does not start another record, and an empty input produces a 0x0 matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader`: The source of the CSV text
+    /// * `converter`: The function which converts the unquoted text of a field to a value
+    ///
+    /// Returns: `Result<Matrix<T>, MatrixError>`, An Err if the reader fails, the text is not UTF-8,
+    /// a quoted field is malformed, or the records differ in length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let csv = "name,quote\r\nAda,\"Hello, \"\"world\"\"\"\r\n";
+    /// let matrix = Matrix::from_csv(csv.as_bytes(), |field| field.to_string()).unwrap();
+    /// assert_eq!(matrix[(1, 1)], "Hello, \"world\"");
+    /// ```
+    pub fn from_csv<R: Read, F: Fn(&str) -> T>(
+        mut reader: R,
+        converter: F,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let rows = parse_records(&text)?
+            .iter()
+            .map(|record| record.iter().map(|field| converter(field)).collect())
+            .collect();
+        Ok(Matrix::from_rows(rows)?)
+    }
+
+    /// Writes the matrix as CSV as described by RFC 4180, with one record per row, the inverse of
+    /// `Matrix::from_csv`.
+    ///
+    /// Every record ends with CRLF. Fields containing a comma, a double quote or a line break are
+    /// enclosed in double quotes, with their double quotes doubled. A matrix without rows is written
+    /// as empty text, which reads back as a 0x0 matrix. So is a matrix without columns, losing its
+    /// height, because an empty record cannot be told apart from a record holding one empty field.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer`: The destination of the CSV text
+    /// * `converter`: The function which converts a value to the text of its field
+    ///
+    /// Returns: `Result<(), MatrixError>`, An Err if the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.5, -2.0], vec![0.25, 1e3]]).unwrap();
+    /// let mut csv = Vec::new();
+    /// matrix.to_csv(&mut csv, |value| value.to_string()).unwrap();
+    /// assert_eq!(csv, b"1.5,-2\r\n0.25,1000\r\n");
+    /// ```
+    pub fn to_csv<W: Write, F: Fn(&T) -> String>(
+        &self,
+        mut writer: W,
+        converter: F,
+    ) -> Result<(), MatrixError> {
+        let mut text = String::new();
+        for row in self.data.chunks(self.width.max(1)).take(self.height) {
+            for (x, value) in row.iter().enumerate() {
+                if x > 0 {
+                    text.push(',');
+                }
+                let field = converter(value);
+                if field.contains([',', '"', '\r', '\n']) {
+                    text.push('"');
+                    text.push_str(&field.replace('"', "\"\""));
+                    text.push('"');
+                } else {
+                    text.push_str(&field);
+                }
+            }
+            text.push_str("\r\n");
+        }
+        writer.write_all(text.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Splits CSV text into records of unquoted fields.
+fn parse_records(text: &str) -> Result<Vec<Vec<String>>, Error> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut chars = text.chars().peekable();
+    while chars.peek().is_some() {
+        let mut field = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next() {
+                    Some('"') if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => {
+                        return Err(invalid_data(format!(
+                            "Quoted field of record {} is not closed",
+                            records.len()
+                        )));
+                    }
+                }
+            }
+            if !matches!(chars.peek(), None | Some(',' | '\r' | '\n')) {
+                return Err(invalid_data(format!(
+                    "Quoted field of record {} is followed by text",
+                    records.len()
+                )));
+            }
+        } else {
+            while let Some(c) = chars.next_if(|&c| c != ',' && c != '\n') {
+                field.push(c);
+            }
+            if field.ends_with('\r') && chars.peek() == Some(&'\n') {
+                field.pop();
+            }
+        }
+        record.push(field);
+        chars.next_if_eq(&'\r');
+        match chars.next() {
+            Some(',') if chars.peek().is_none() => record.push(String::new()),
+            Some(',') => {}
+            _ => records.push(std::mem::take(&mut record)),
+        }
+    }
+    Ok(records)
+}
+
+fn invalid_data(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_error::MatrixError;
+    use proptest::proptest;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn rfc_4180_fixture_test() {
+        let csv = "a,\"b,c\",\"\"\"d\"\"\"\r\n\"e\r\nf\",,g\nh,i,\r\n";
+        let matrix = Matrix::from_csv(csv.as_bytes(), |field| field.to_string()).unwrap();
+        assert_eq!(
+            matrix,
+            Matrix::from_rows(vec![
+                vec!["a", "b,c", "\"d\""],
+                vec!["e\r\nf", "", "g"],
+                vec!["h", "i", ""],
+            ])
+            .unwrap()
+            .transform(|_, field| field.to_string())
+        );
+        let mut written = Vec::new();
+        matrix.to_csv(&mut written, |field| field.clone()).unwrap();
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            "a,\"b,c\",\"\"\"d\"\"\"\r\n\"e\r\nf\",,g\r\nh,i,\r\n"
+        );
+        assert_eq!(
+            Matrix::from_csv("".as_bytes(), |field| field.len()).unwrap(),
+            Matrix::from_rows(Vec::new()).unwrap()
+        );
+    }
+
+    #[test]
+    fn no_columns_test() {
+        let matrix = Matrix::<String>::from_rows(vec![vec![]; 3]).unwrap();
+        let mut written = Vec::new();
+        matrix.to_csv(&mut written, |field| field.clone()).unwrap();
+        assert!(written.is_empty());
+        let read = Matrix::from_csv(&written[..], |field| field.to_string()).unwrap();
+        assert_eq!(read.dimensions(), (0, 0));
+
+        let blank = Matrix::new(1, 3, |_| String::new()).unwrap();
+        let mut written = Vec::new();
+        blank.to_csv(&mut written, |field| field.clone()).unwrap();
+        assert_eq!(written, b"\r\n\r\n\r\n");
+        assert_eq!(
+            Matrix::from_csv(&written[..], |field| field.to_string()).unwrap(),
+            blank
+        );
+    }
+
+    #[test]
+    fn malformed_csv_test() {
+        assert!(matches!(
+            Matrix::from_csv("1,2\r\n3\r\n".as_bytes(), |field| field.to_string()),
+            Err(MatrixError::DimensionMismatch(_))
+        ));
+        for csv in ["1,\"2\r\n3,4\r\n", "1,\"2\"x\r\n3,4\r\n"] {
+            match Matrix::from_csv(csv.as_bytes(), |field| field.to_string()) {
+                Err(MatrixError::Io { kind, .. }) => assert_eq!(kind, ErrorKind::InvalidData),
+                other => panic!("Expected an invalid data error, got {:?}", other),
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip_test(fields in proptest::collection::vec("[a-c,\"\r\n ]{1,4}", 4..24), width in 1usize..5) {
+            let height = fields.len() / width;
+            let matrix = Matrix::from_flat_vec(width, height, fields[..width * height].to_vec()).unwrap();
+            let mut written = Vec::new();
+            matrix.to_csv(&mut written, |field| field.clone()).unwrap();
+            assert_eq!(Matrix::from_csv(&written[..], |field| field.to_string()).unwrap(), matrix);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::matrix::Matrix;
+use std::ops::Deref;
+
+/// A matrix which can no longer be mutated, for sharing a grid between threads without the risk of
+/// accidentally writing to it.
+///
+/// A frozen matrix dereferences to a shared `Matrix`, so every read-only method of `Matrix`, its
+/// indexing and its iterators are available, while no mutable access is possible until it is
+/// converted back with `thaw`. It is `Send` and `Sync` whenever `T` is.
+///
+/// ```compile_fail
+/// use rust_tensors::matrix::Matrix;
+///
+/// let mut frozen = Matrix::new(2, 2, |_| 0).unwrap().freeze();
+/// frozen[(0, 0)] = 1;
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct FrozenMatrix<T> {
+    matrix: Matrix<T>,
+}
+
+impl<T> Matrix<T> {
+    /// Converts the matrix into a `FrozenMatrix` without copying its values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let frozen = Matrix::new(3, 3, |address| address.x + address.y).unwrap().freeze();
+    /// assert_eq!(frozen[(2, 1)], 3);
+    /// assert_eq!(frozen.iter().sum::<i32>(), 18);
+    /// let mut thawed = frozen.thaw();
+    /// thawed[(2, 1)] = 0;
+    /// ```
+    pub fn freeze(self) -> FrozenMatrix<T> {
+        FrozenMatrix { matrix: self }
+    }
+}
+
+impl<T> FrozenMatrix<T> {
+    /// Converts the frozen matrix back into a mutable `Matrix` without copying its values.
+    pub fn thaw(self) -> Matrix<T> {
+        self.matrix
+    }
+}
+
+impl<T> Deref for FrozenMatrix<T> {
+    type Target = Matrix<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.matrix
+    }
+}
+
+impl<T> From<Matrix<T>> for FrozenMatrix<T> {
+    fn from(value: Matrix<T>) -> Self {
+        value.freeze()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::axis::Axis;
+    use crate::matrix::{FrozenMatrix, Matrix};
+    use crate::tensor::Tensor;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn frozen_is_send_sync_test() {
+        assert_send_sync::<FrozenMatrix<i64>>();
+    }
+
+    #[test]
+    fn concurrent_reduction_test() {
+        let frozen = Matrix::new(300, 200, |address| (address.x * address.y) as i64)
+            .unwrap()
+            .freeze();
+        let expected_total = frozen.iter().sum::<i64>();
+        let (total, row_sums, maximum) = std::thread::scope(|scope| {
+            let total = scope.spawn(|| frozen.iter().sum::<i64>());
+            let row_sums =
+                scope.spawn(|| frozen.reduce_along(Axis::Rows, |row| row.iter().sum::<i64>()));
+            let maximum = scope.spawn(|| {
+                frozen
+                    .address_value_iter()
+                    .max_by_key(|(_, value)| **value)
+                    .map(|(address, _)| address)
+            });
+            (
+                total.join().unwrap(),
+                row_sums.join().unwrap(),
+                maximum.join().unwrap(),
+            )
+        });
+        assert_eq!(total, expected_total);
+        assert_eq!(row_sums.iter().sum::<i64>(), expected_total);
+        assert_eq!(maximum, Some(frozen.largest_contained_address()));
+        let matrix = frozen.thaw();
+        assert_eq!(matrix[(299, 199)], 299 * 199);
+    }
+}
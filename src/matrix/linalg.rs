@@ -0,0 +1,1487 @@
+use crate::matrix::Matrix;
+use crate::matrix_error::{DimensionMismatchError, InversionError, MatrixError, NotSquareError};
+use std::cmp::Ordering;
+use std::ops::{Add, Mul};
+
+impl<T> Matrix<T> {
+    /// Creates an `n` by `n` identity matrix, with ones on the main diagonal and zeros elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let identity = Matrix::<i32>::identity(2);
+    /// assert_eq!(identity, Matrix::from_rows(vec![vec![1, 0], vec![0, 1]]).unwrap());
+    /// ```
+    pub fn identity(n: usize) -> Matrix<T>
+    where
+        T: From<u8>,
+    {
+        Matrix::new(n, n, |address| T::from((address.x == address.y) as u8))
+            .expect("Identity matrix size must be non-zero")
+    }
+
+    /// Creates a square matrix with the given values on the main diagonal and zeros elsewhere.
+    ///
+    /// # Arguments
+    ///
+    /// * `values`: The values of the diagonal, from the top left to the bottom right
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let diagonal = Matrix::from_diagonal(&[2.0, 3.0]);
+    /// assert_eq!(diagonal, Matrix::from_rows(vec![vec![2.0, 0.0], vec![0.0, 3.0]]).unwrap());
+    /// ```
+    pub fn from_diagonal(values: &[T]) -> Matrix<T>
+    where
+        T: From<u8> + Clone,
+    {
+        Matrix::new(values.len(), values.len(), |address| {
+            if address.x == address.y {
+                values[address.x as usize].clone()
+            } else {
+                T::from(0)
+            }
+        })
+        .expect("Diagonal must contain at least one value")
+    }
+
+    /// Multiplies this matrix by another using the standard row-by-column matrix product.
+    /// Will return an Err if the width of this matrix does not equal the height of `rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs`: The right hand side of the product
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`, A matrix with the height of this matrix
+    /// and the width of `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let a = Matrix::<i32>::parse_matrix("1 2|3 4", " ", "|", |s| s.parse().unwrap()).unwrap();
+    /// let b = Matrix::<i32>::parse_matrix("5 6|7 8", " ", "|", |s| s.parse().unwrap()).unwrap();
+    /// let product = Matrix::<i32>::parse_matrix("19 22|43 50", " ", "|", |s| s.parse().unwrap())
+    ///     .unwrap();
+    /// assert_eq!(a.mat_mul(&b).unwrap(), product);
+    /// ```
+    pub fn mat_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionMismatchError>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Default + Clone,
+    {
+        if self.width != rhs.height {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Cannot multiply a {}x{} matrix by a {}x{} matrix",
+                    self.width, self.height, rhs.width, rhs.height
+                ),
+            });
+        }
+        let mut data = vec![T::default(); self.height * rhs.width];
+        for (row, output_row) in data.chunks_mut(rhs.width.max(1)).enumerate() {
+            let lhs_row = &self.data[row * self.width..(row + 1) * self.width];
+            for (lhs_value, rhs_row) in lhs_row.iter().zip(rhs.data.chunks(rhs.width.max(1))) {
+                for (output, rhs_value) in output_row.iter_mut().zip(rhs_row) {
+                    *output = output.clone() + lhs_value.clone() * rhs_value.clone();
+                }
+            }
+        }
+        Ok(Matrix::from_parts(rhs.width, self.height, data))
+    }
+
+    /// Computes the Kronecker product of this matrix and another, a block matrix where the value at
+    /// each address of this matrix scales a full copy of `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The matrix copied into every block
+    ///
+    /// Returns: `Matrix<T>`, A matrix whose width and height are the products of the widths and
+    /// heights of the two matrices, where the block at `(x, y)` is `self[(x, y)] * other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_rows(vec![vec![1, 2]]).unwrap();
+    /// let b = Matrix::from_rows(vec![vec![1], vec![10]]).unwrap();
+    /// assert_eq!(a.kronecker(&b), Matrix::from_rows(vec![vec![1, 2], vec![10, 20]]).unwrap());
+    /// ```
+    pub fn kronecker(&self, other: &Matrix<T>) -> Matrix<T>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        let (width, height) = (self.width * other.width, self.height * other.height);
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            let (outer_row, inner_row) = (y / other.height, y % other.height);
+            for x in 0..width {
+                let (outer_column, inner_column) = (x / other.width, x % other.width);
+                data.push(
+                    self.data[outer_row * self.width + outer_column].clone()
+                        * other.data[inner_row * other.width + inner_column].clone(),
+                );
+            }
+        }
+        Matrix::from_parts(width, height, data)
+    }
+
+    /// Sums the values on the main diagonal of a square matrix.
+    ///
+    /// Returns: `Result<T, NotSquareError>`, An Err if the matrix is not square. The trace of a 0x0
+    /// matrix is `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(matrix.trace(), Ok(5));
+    /// assert!(Matrix::from_rows(vec![vec![1, 2]]).unwrap().trace().is_err());
+    /// ```
+    pub fn trace(&self) -> Result<T, NotSquareError>
+    where
+        T: Add<Output = T> + Clone + Default,
+    {
+        if self.width != self.height {
+            return Err(NotSquareError {
+                width: self.width,
+                height: self.height,
+            });
+        }
+        Ok(self
+            .diagonal_iter()
+            .fold(T::default(), |sum, value| sum + value.clone()))
+    }
+
+    /// Creates an iterator over the main diagonal of the matrix, from the top left corner towards the
+    /// bottom right. For a matrix which is not square, the diagonal ends at the bottom or right edge,
+    /// whichever comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(matrix.diagonal_iter().collect::<Vec<_>>(), vec![&1, &5]);
+    /// ```
+    pub fn diagonal_iter(&self) -> impl Iterator<Item = &T> {
+        let length = self.width.min(self.height);
+        self.data.iter().step_by(self.width + 1).take(length)
+    }
+
+    /// Creates an iterator over the anti-diagonal of the matrix, from the top right corner towards the
+    /// bottom left. For a matrix which is not square, the diagonal ends at the bottom or left edge,
+    /// whichever comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(matrix.anti_diagonal_iter().collect::<Vec<_>>(), vec![&3, &5]);
+    /// ```
+    pub fn anti_diagonal_iter(&self) -> impl Iterator<Item = &T> {
+        let length = self.width.min(self.height);
+        self.data
+            .iter()
+            .skip(self.width.saturating_sub(1))
+            .step_by(self.width.saturating_sub(1).max(1))
+            .take(length)
+    }
+
+    /// Creates an iterator over mutable references to the main diagonal of the matrix, visiting the
+    /// same values as `diagonal_iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(3, 3, |_| 0).unwrap();
+    /// matrix.diagonal_iter_mut().for_each(|value| *value = 1);
+    /// assert_eq!(matrix, Matrix::identity(3));
+    /// ```
+    pub fn diagonal_iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let length = self.width.min(self.height);
+        self.data.iter_mut().step_by(self.width + 1).take(length)
+    }
+
+    /// Creates an iterator over mutable references to the anti-diagonal of the matrix, visiting the
+    /// same values as `anti_diagonal_iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// matrix.anti_diagonal_iter_mut().for_each(|value| *value = 0);
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 2, 0], vec![4, 0, 6]]).unwrap());
+    /// ```
+    pub fn anti_diagonal_iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let length = self.width.min(self.height);
+        self.data
+            .iter_mut()
+            .skip(self.width.saturating_sub(1))
+            .step_by(self.width.saturating_sub(1).max(1))
+            .take(length)
+    }
+
+    /// Whether the matrix has as many rows as columns. A 0x0 matrix is square.
+    ///
+    /// Returns: `bool`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// assert!(Matrix::<i32>::identity(3).is_square());
+    /// assert!(!Matrix::from_rows(vec![vec![1, 2]]).unwrap().is_square());
+    /// ```
+    pub fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+
+    /// Whether the matrix is square and equal to its transpose, so that the value at `(x, y)` equals
+    /// the value at `(y, x)` for every address.
+    ///
+    /// Returns: `bool`, false for a matrix which is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// assert!(Matrix::from_rows(vec![vec![1, 7], vec![7, 2]]).unwrap().is_symmetric());
+    /// assert!(!Matrix::from_rows(vec![vec![1, 7], vec![6, 2]]).unwrap().is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.is_symmetric_by(|a, b| a == b)
+    }
+
+    /// Whether the matrix is square and the value at `(x, y)` matches the value at `(y, x)` for every
+    /// address, according to a comparison function. Only the values below the main diagonal are
+    /// compared against those above it, without building the transpose.
+    ///
+    /// # Arguments
+    ///
+    /// * `eq`: Whether two values mirrored across the main diagonal match
+    ///
+    /// Returns: `bool`, false for a matrix which is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.0f64, 0.1 + 0.2], vec![0.3, 2.0]]).unwrap();
+    /// assert!(!matrix.is_symmetric());
+    /// assert!(matrix.is_symmetric_by(|a, b| (a - b).abs() < 1e-9));
+    /// ```
+    pub fn is_symmetric_by<F: Fn(&T, &T) -> bool>(&self, eq: F) -> bool {
+        self.is_square()
+            && (0..self.height).all(|y| {
+                (0..y).all(|x| {
+                    eq(
+                        &self.data[y * self.width + x],
+                        &self.data[x * self.width + y],
+                    )
+                })
+            })
+    }
+
+    /// Whether the matrix is square and every value off the main diagonal is `T::default()`.
+    ///
+    /// Returns: `bool`, false for a matrix which is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// assert!(Matrix::from_diagonal(&[3, 0, 5]).is_diagonal());
+    /// assert!(!Matrix::from_rows(vec![vec![1, 0], vec![2, 1]]).unwrap().is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool
+    where
+        T: PartialEq + Default,
+    {
+        let zero = T::default();
+        self.is_square()
+            && self
+                .data
+                .iter()
+                .enumerate()
+                .all(|(index, value)| index % (self.width + 1) == 0 || *value == zero)
+    }
+
+    /// Whether the matrix is square with ones on the main diagonal and `T::default()` everywhere
+    /// else.
+    ///
+    /// Returns: `bool`, false for a matrix which is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// assert!(Matrix::<f64>::identity(4).is_identity());
+    /// assert!(!Matrix::from_diagonal(&[1, 2]).is_identity());
+    /// ```
+    pub fn is_identity(&self) -> bool
+    where
+        T: PartialEq + Default + From<u8>,
+    {
+        let one = T::from(1);
+        self.is_diagonal() && self.diagonal_iter().all(|value| *value == one)
+    }
+}
+
+impl<T> Matrix<T> {
+    fn check_square(&self) -> Result<(), NotSquareError> {
+        if !self.is_square() {
+            return Err(NotSquareError {
+                width: self.width,
+                height: self.height,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The lower triangular factor, upper triangular factor and row permutation
+/// returned by `Matrix::lu_decompose`.
+pub type LuDecomposition = (Matrix<f64>, Matrix<f64>, Vec<usize>);
+
+/// The LU decomposition of a square matrix with partial pivoting, such that `PA = LU`.
+struct LuFactors {
+    size: usize,
+    /// U on and above the diagonal, and the multipliers of L below it.
+    /// The diagonal of L is implicitly all ones.
+    data: Vec<f64>,
+    /// `permutation[i]` is the row of the original matrix which became row `i`.
+    permutation: Vec<usize>,
+    odd_swap_count: bool,
+}
+
+impl LuFactors {
+    fn pivot(&self, index: usize) -> f64 {
+        self.data[index * self.size + index]
+    }
+
+    fn determinant(&self) -> f64 {
+        let product = (0..self.size).map(|i| self.pivot(i)).product::<f64>();
+        if self.odd_swap_count {
+            -product
+        } else {
+            product
+        }
+    }
+
+    /// Whether any pivot is negligible relative to the largest magnitude of the original matrix.
+    fn is_singular(&self, largest_magnitude: f64) -> bool {
+        let tolerance = self.size as f64 * f64::EPSILON * largest_magnitude;
+        (0..self.size).any(|i| self.pivot(i).abs() <= tolerance)
+    }
+
+    /// Solves `Ax = b` given `b`, by forward substitution through L and back substitution through U.
+    fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let size = self.size;
+        let mut x = self
+            .permutation
+            .iter()
+            .map(|&row| b[row])
+            .collect::<Vec<f64>>();
+        for row in 0..size {
+            let row_data = &self.data[row * size..(row + 1) * size];
+            x[row] -= (0..row).map(|k| row_data[k] * x[k]).sum::<f64>();
+        }
+        for row in (0..size).rev() {
+            let row_data = &self.data[row * size..(row + 1) * size];
+            x[row] -= (row + 1..size).map(|k| row_data[k] * x[k]).sum::<f64>();
+            x[row] /= row_data[row];
+        }
+        x
+    }
+}
+
+impl Matrix<f64> {
+    fn lu_factors(&self) -> Result<LuFactors, NotSquareError> {
+        self.check_square()?;
+        let size = self.width;
+        let mut factors = LuFactors {
+            size,
+            data: self.data.clone(),
+            permutation: (0..size).collect(),
+            odd_swap_count: false,
+        };
+        let data = &mut factors.data;
+        for column in 0..size {
+            let pivot_row = (column..size)
+                .max_by(|&a, &b| {
+                    data[a * size + column]
+                        .abs()
+                        .total_cmp(&data[b * size + column].abs())
+                })
+                .unwrap();
+            if pivot_row != column {
+                swap_flat_rows(data, size, pivot_row, column);
+                factors.permutation.swap(pivot_row, column);
+                factors.odd_swap_count = !factors.odd_swap_count;
+            }
+            let pivot = data[column * size + column];
+            if pivot == 0.0 {
+                continue;
+            }
+            for row in column + 1..size {
+                let factor = data[row * size + column] / pivot;
+                data[row * size + column] = factor;
+                if factor != 0.0 {
+                    for x in column + 1..size {
+                        data[row * size + x] -= factor * data[column * size + x];
+                    }
+                }
+            }
+        }
+        Ok(factors)
+    }
+
+    /// Computes the determinant of a square matrix using LU decomposition with partial pivoting,
+    /// which takes O(n³) time.
+    ///
+    /// Returns: `Result<f64, NotSquareError>`, An Err if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![4.0, 3.0], vec![6.0, 3.0]]).unwrap();
+    /// assert_eq!(matrix.determinant().unwrap(), -6.0);
+    /// ```
+    pub fn determinant(&self) -> Result<f64, NotSquareError> {
+        Ok(self.lu_factors()?.determinant())
+    }
+
+    /// Computes the inverse of a square matrix using LU decomposition with partial pivoting.
+    ///
+    /// A matrix is treated as singular when a pivot is no larger than `n * f64::EPSILON` times the
+    /// largest magnitude in the matrix. For a well conditioned matrix, the product of the matrix and
+    /// its inverse differs from the identity by roughly `n * f64::EPSILON` times the condition number,
+    /// so comparing against the identity with `approx_eq` and an epsilon of `1e-9` is comfortable for
+    /// small, well conditioned matrices.
+    ///
+    /// Returns: `Result<Matrix<f64>, InversionError>`, An Err if the matrix is not square or is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![4.0, 7.0], vec![2.0, 6.0]]).unwrap();
+    /// let inverse = matrix.invert().unwrap();
+    /// let expected = Matrix::from_rows(vec![vec![0.6, -0.7], vec![-0.2, 0.4]]).unwrap();
+    /// assert!(inverse.approx_eq(&expected, 1e-12));
+    /// ```
+    #[doc(alias = "inverse")]
+    pub fn invert(&self) -> Result<Matrix<f64>, InversionError> {
+        let factors = self.lu_factors()?;
+        let largest_magnitude = self.data.iter().fold(0.0f64, |a, b| a.max(b.abs()));
+        if factors.is_singular(largest_magnitude) {
+            return Err(InversionError::Singular);
+        }
+        let size = self.width;
+        let mut data = vec![0.0; size * size];
+        let mut unit = vec![0.0; size];
+        for column in 0..size {
+            unit[column] = 1.0;
+            for (row, value) in factors.solve(&unit).into_iter().enumerate() {
+                data[row * size + column] = value;
+            }
+            unit[column] = 0.0;
+        }
+        Ok(Matrix::from_parts(size, size, data))
+    }
+
+    /// Computes the LU decomposition of a square matrix with partial pivoting.
+    ///
+    /// The result `(L, U, permutation)` satisfies `PA = LU`, where `L` is unit lower triangular,
+    /// `U` is upper triangular, and `P` is the permutation matrix which moves row `permutation[i]`
+    /// of `A` to row `i`. Uses the same singularity tolerance as `invert`.
+    ///
+    /// Returns: `Result<LuDecomposition, MatrixError>`,
+    /// An Err if the matrix is not square or is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.0, 2.0], vec![4.0, 2.0]]).unwrap();
+    /// let (l, u, permutation) = matrix.lu_decompose().unwrap();
+    /// assert_eq!(l, Matrix::from_rows(vec![vec![1.0, 0.0], vec![0.25, 1.0]]).unwrap());
+    /// assert_eq!(u, Matrix::from_rows(vec![vec![4.0, 2.0], vec![0.0, 1.5]]).unwrap());
+    /// assert_eq!(permutation, vec![1, 0]);
+    /// ```
+    pub fn lu_decompose(&self) -> Result<LuDecomposition, MatrixError> {
+        let factors = self.lu_factors()?;
+        let largest_magnitude = self.data.iter().fold(0.0f64, |a, b| a.max(b.abs()));
+        if factors.is_singular(largest_magnitude) {
+            return Err(MatrixError::Singular);
+        }
+        let size = factors.size;
+        let value = |x: usize, y: usize| factors.data[y * size + x];
+        // Built from parts rather than with `Matrix::new`, which rejects the factors of a 0x0 matrix.
+        let factor = |element: &dyn Fn(usize, usize) -> f64| {
+            let data = (0..size * size)
+                .map(|index| element(index % size, index / size))
+                .collect();
+            Matrix::from_parts(size, size, data)
+        };
+        let lower = factor(&|x, y| match x.cmp(&y) {
+            Ordering::Less => value(x, y),
+            Ordering::Equal => 1.0,
+            Ordering::Greater => 0.0,
+        });
+        let upper = factor(&|x, y| if x >= y { value(x, y) } else { 0.0 });
+        Ok((lower, upper, factors.permutation))
+    }
+
+    /// Reduces the matrix in place to row echelon form by Gaussian elimination with partial pivoting.
+    /// For a square matrix the result is upper triangular.
+    ///
+    /// Each column is pivoted on the remaining row with the largest magnitude in that column, and
+    /// columns which are entirely zero below the current row are skipped. Values eliminated below a
+    /// pivot are set to exactly zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 2.0]]).unwrap();
+    /// matrix.gaussian_elimination();
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![2.0, 2.0], vec![0.0, 1.0]]).unwrap());
+    /// ```
+    pub fn gaussian_elimination(&mut self) {
+        let (width, height) = self.dimensions();
+        let data = &mut self.data;
+        let mut pivot_row = 0;
+        for column in 0..width {
+            if pivot_row == height {
+                break;
+            }
+            let best_row = (pivot_row..height)
+                .max_by(|&a, &b| {
+                    data[a * width + column]
+                        .abs()
+                        .total_cmp(&data[b * width + column].abs())
+                })
+                .unwrap();
+            let pivot = data[best_row * width + column];
+            if pivot == 0.0 {
+                continue;
+            }
+            swap_flat_rows(data, width, best_row, pivot_row);
+            for row in pivot_row + 1..height {
+                let factor = data[row * width + column] / pivot;
+                data[row * width + column] = 0.0;
+                if factor != 0.0 {
+                    for x in column + 1..width {
+                        data[row * width + x] -= factor * data[pivot_row * width + x];
+                    }
+                }
+            }
+            pivot_row += 1;
+        }
+    }
+
+    /// Solves the linear system `Ax = b`, where `A` is this matrix,
+    /// using LU decomposition with partial pivoting.
+    /// Uses the same singularity tolerance as `invert`.
+    ///
+    /// # Arguments
+    ///
+    /// * `b`: A column vector with the same height as this matrix
+    ///
+    /// Returns: `Result<Vec<f64>, MatrixError>`, `x` in order from top to bottom.
+    /// An Err if the matrix is not square or is singular, or if `b` is not a column vector of matching height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_rows(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+    /// let b = Matrix::from_rows(vec![vec![3.0], vec![5.0]]).unwrap();
+    /// let x = a.solve(&b).unwrap();
+    /// assert!((x[0] - 0.8).abs() < 1e-12 && (x[1] - 1.4).abs() < 1e-12);
+    /// ```
+    pub fn solve(&self, b: &Matrix<f64>) -> Result<Vec<f64>, MatrixError> {
+        let factors = self.lu_factors()?;
+        if b.width != 1 || b.height != self.height {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Expected a column vector of height {}, found a {}x{} matrix",
+                    self.height, b.width, b.height
+                ),
+            }
+            .into());
+        }
+        let largest_magnitude = self.data.iter().fold(0.0f64, |a, b| a.max(b.abs()));
+        if factors.is_singular(largest_magnitude) {
+            return Err(MatrixError::Singular);
+        }
+        Ok(factors.solve(&b.data))
+    }
+
+    /// Compares two matrices, allowing each pair of values to differ by a small amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The matrix to compare against
+    /// * `epsilon`: The largest allowed absolute difference between two values at the same address
+    ///
+    /// Returns: `bool`, True if and only if the dimensions are equal and every pair of values differs
+    /// by at most `epsilon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let computed = Matrix::from_rows(vec![vec![0.1 + 0.2, 1.0]]).unwrap();
+    /// let expected = Matrix::from_rows(vec![vec![0.3, 1.0]]).unwrap();
+    /// assert_ne!(computed, expected);
+    /// assert!(computed.approx_eq(&expected, 1e-12));
+    /// assert!(!computed.approx_eq(&Matrix::from_rows(vec![vec![0.3, 1.1]]).unwrap(), 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix<f64>, epsilon: f64) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self
+                .data
+                .iter()
+                .zip(&other.data)
+                .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+
+    /// Computes the Frobenius norm, the square root of the sum of the squares of all values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![3.0, 0.0], vec![0.0, -4.0]]).unwrap();
+    /// assert_eq!(matrix.frobenius_norm(), 5.0);
+    /// ```
+    pub fn frobenius_norm(&self) -> f64 {
+        self.data
+            .iter()
+            .map(|value| value * value)
+            // Folded from 0.0, since summing nothing gives -0.0.
+            .fold(0.0, |sum, value| sum + value)
+            .sqrt()
+    }
+
+    /// Computes the L1 operator norm, the largest sum of absolute values of any column.
+    /// A matrix without any values has a norm of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.0, -2.0], vec![-3.0, 4.0]]).unwrap();
+    /// assert_eq!(matrix.l1_norm(), 6.0);
+    /// ```
+    pub fn l1_norm(&self) -> f64 {
+        self.columns()
+            .map(|column| column.fold(0.0, |sum, value| sum + value.abs()))
+            .fold(0.0, f64::max)
+    }
+
+    /// Computes the L-infinity operator norm, the largest sum of absolute values of any row.
+    /// A matrix without any values has a norm of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.0, -2.0], vec![-3.0, 4.0]]).unwrap();
+    /// assert_eq!(matrix.l_inf_norm(), 7.0);
+    /// ```
+    pub fn l_inf_norm(&self) -> f64 {
+        self.rows()
+            .map(|row| row.iter().fold(0.0, |sum, value| sum + value.abs()))
+            .fold(0.0, f64::max)
+    }
+
+    /// Computes the element-wise L1 norm, the sum of the absolute values of all values.
+    /// Unlike `l1_norm`, this treats the matrix as one long vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.0, -2.0], vec![-3.0, 4.0]]).unwrap();
+    /// assert_eq!(matrix.element_sum_abs(), 10.0);
+    /// ```
+    pub fn element_sum_abs(&self) -> f64 {
+        self.data.iter().fold(0.0, |sum, value| sum + value.abs())
+    }
+}
+
+impl Matrix<i32> {
+    /// Computes the determinant of a square integer matrix exactly,
+    /// using fraction-free (Bareiss) elimination with row pivoting.
+    /// Intended for small matrices such as 4x4 transforms, where the result fits in an `i64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an intermediate value or the result overflows.
+    ///
+    /// Returns: `Result<i64, NotSquareError>`, An Err if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![2, 0, 1], vec![1, 3, 2], vec![1, 1, 2]]).unwrap();
+    /// assert_eq!(matrix.determinant().unwrap(), 6);
+    /// ```
+    pub fn determinant(&self) -> Result<i64, NotSquareError> {
+        self.check_square()?;
+        let size = self.width;
+        if size == 0 {
+            return Ok(1);
+        }
+        let mut data = self.data.iter().map(|&v| v as i128).collect::<Vec<i128>>();
+        let mut sign = 1;
+        let mut previous_pivot = 1i128;
+        for column in 0..size {
+            let Some(pivot_row) = (column..size).find(|&row| data[row * size + column] != 0) else {
+                return Ok(0);
+            };
+            if pivot_row != column {
+                swap_flat_rows(&mut data, size, pivot_row, column);
+                sign = -sign;
+            }
+            let pivot = data[column * size + column];
+            for row in column + 1..size {
+                for x in column + 1..size {
+                    let value = data[row * size + x]
+                        .checked_mul(pivot)
+                        .and_then(|a| {
+                            data[row * size + column]
+                                .checked_mul(data[column * size + x])
+                                .and_then(|b| a.checked_sub(b))
+                        })
+                        .expect("Determinant computation overflowed");
+                    data[row * size + x] = value / previous_pivot;
+                }
+                data[row * size + column] = 0;
+            }
+            previous_pivot = pivot;
+        }
+        Ok(
+            i64::try_from(sign * data[size * size - 1])
+                .expect("Determinant does not fit in an i64"),
+        )
+    }
+}
+
+/// Swaps two rows of a matrix stored as flat row-major data, where `size` is the row length.
+fn swap_flat_rows<T>(data: &mut [T], size: usize, a: usize, b: usize) {
+    if a == b {
+        return;
+    }
+    let (low, high) = (a.min(b), a.max(b));
+    let (head, tail) = data.split_at_mut(high * size);
+    head[low * size..(low + 1) * size].swap_with_slice(&mut tail[..size]);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_error::{InversionError, MatrixError, NotSquareError};
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    fn parse(data_str: &str) -> Matrix<i64> {
+        Matrix::parse_matrix(data_str, " ", "|", |s| s.parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn kronecker_known_product_test() {
+        assert_eq!(
+            parse("1 2|3 4").kronecker(&parse("0 5|6 7")),
+            parse("0 5 0 10|6 7 12 14|0 15 0 20|18 21 24 28")
+        );
+        assert_eq!(
+            parse("1 2 3").kronecker(&parse("2|-1")),
+            parse("2 4 6|-1 -2 -3")
+        );
+        let empty = Matrix::<i64>::from_rows(vec![vec![]; 2]).unwrap();
+        assert_eq!(parse("1 2|3 4").kronecker(&empty).dimensions(), (0, 4));
+    }
+
+    #[test]
+    fn structural_predicates_test() {
+        let empty = Matrix::<i64>::from_rows(vec![]).unwrap();
+        assert!(empty.is_square() && empty.is_symmetric() && empty.is_identity());
+
+        let identity = Matrix::<i64>::identity(3);
+        assert!(identity.is_square());
+        assert!(identity.is_symmetric());
+        assert!(identity.is_diagonal());
+        assert!(identity.is_identity());
+
+        let symmetric = parse("2 -1 0|-1 2 -1|0 -1 2");
+        assert!(symmetric.is_symmetric());
+        assert!(!symmetric.is_diagonal());
+        assert!(!symmetric.is_identity());
+
+        let diagonal = parse("1 0 0|0 2 0|0 0 1");
+        assert!(diagonal.is_symmetric() && diagonal.is_diagonal());
+        assert!(!diagonal.is_identity());
+
+        let general = parse("1 2|3 4");
+        assert!(general.is_square());
+        assert!(!general.is_symmetric() && !general.is_diagonal() && !general.is_identity());
+
+        let wide = parse("1 0 0|0 1 0");
+        assert!(!wide.is_square());
+        assert!(!wide.is_symmetric() && !wide.is_diagonal() && !wide.is_identity());
+        assert!(!parse("1 1|1 1|1 1").is_symmetric_by(|_, _| true));
+
+        let nearly = Matrix::from_rows(vec![vec![1.0, 2.0 + 1e-12], vec![2.0, 1.0]]).unwrap();
+        assert!(!nearly.is_symmetric());
+        assert!(nearly.is_symmetric_by(|a: &f64, b| (a - b).abs() < 1e-9));
+        assert!(!nearly.is_symmetric_by(|a: &f64, b| (a - b).abs() < 1e-15));
+        let compared = std::cell::Cell::new(0);
+        parse("1 2 3|2 1 4|3 4 1").is_symmetric_by(|_, _| {
+            compared.set(compared.get() + 1);
+            true
+        });
+        assert_eq!(compared.get(), 3);
+    }
+
+    #[test]
+    fn mat_mul_known_product_test() {
+        assert_eq!(
+            parse("1 2 3|4 5 6|7 8 9")
+                .mat_mul(&parse("9 8 7|6 5 4|3 2 1"))
+                .unwrap(),
+            parse("30 24 18|84 69 54|138 114 90")
+        );
+    }
+
+    #[test]
+    fn mat_mul_non_square_test() {
+        let a = parse("1 2 3|4 5 6");
+        let b = parse("7 8|9 10|11 12");
+        let ab = a.mat_mul(&b).unwrap();
+        assert_eq!((ab.width, ab.height), (2, 2));
+        assert_eq!(ab, parse("58 64|139 154"));
+        let ba = b.mat_mul(&a).unwrap();
+        assert_eq!((ba.width, ba.height), (3, 3));
+        assert_eq!(ba, parse("39 54 69|49 68 87|59 82 105"));
+
+        let column = parse("1|2|3");
+        assert_eq!(a.mat_mul(&column).unwrap(), parse("14|32"));
+        assert!(column.mat_mul(&a).is_err());
+        assert!(a.mat_mul(&a).is_err());
+    }
+
+    #[test]
+    fn determinant_known_values_test() {
+        let two = Matrix::from_rows(vec![vec![3.0, 8.0], vec![4.0, 6.0]]).unwrap();
+        assert_eq!(two.determinant().unwrap(), -14.0);
+        let three = Matrix::from_rows(vec![
+            vec![6.0, 1.0, 1.0],
+            vec![4.0, -2.0, 5.0],
+            vec![2.0, 8.0, 7.0],
+        ])
+        .unwrap();
+        assert_eq!(three.determinant().unwrap(), -306.0);
+        assert_eq!(
+            Matrix::new(1, 1, |_| -2.5).unwrap().determinant().unwrap(),
+            -2.5
+        );
+
+        let two = Matrix::from_rows(vec![vec![3, 8], vec![4, 6]]).unwrap();
+        assert_eq!(two.determinant().unwrap(), -14);
+        let three = Matrix::from_rows(vec![vec![6, 1, 1], vec![4, -2, 5], vec![2, 8, 7]]).unwrap();
+        assert_eq!(three.determinant().unwrap(), -306);
+        let four = Matrix::from_rows(vec![
+            vec![0, 2, 0, 1],
+            vec![3, 0, 1, 0],
+            vec![0, 1, 4, 0],
+            vec![2, 0, 0, 5],
+        ])
+        .unwrap();
+        assert_eq!(four.determinant().unwrap(), -118);
+        assert_eq!(
+            Matrix::new(4, 4, |address| address.x * 1000 + 7 * address.y * address.y)
+                .unwrap()
+                .determinant()
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn determinant_empty_test() {
+        assert_eq!(
+            Matrix::<f64>::from_rows(vec![]).unwrap().determinant(),
+            Ok(1.0)
+        );
+        assert_eq!(
+            Matrix::<i32>::from_rows(vec![]).unwrap().determinant(),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn determinant_singular_test() {
+        let singular = Matrix::from_rows(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![0.0, 1.0, 1.0],
+        ])
+        .unwrap();
+        assert_eq!(singular.determinant().unwrap(), 0.0);
+        assert_eq!(
+            Matrix::new(5, 5, |_| 0.0).unwrap().determinant().unwrap(),
+            0.0
+        );
+        let singular =
+            Matrix::from_rows(vec![vec![1, 2, 3], vec![2, 4, 6], vec![0, 1, 1]]).unwrap();
+        assert_eq!(singular.determinant().unwrap(), 0);
+    }
+
+    #[test]
+    fn determinant_not_square_test() {
+        assert!(Matrix::new(3, 2, |_| 1.0).unwrap().determinant().is_err());
+        assert!(Matrix::new(2, 3, |_| 1).unwrap().determinant().is_err());
+    }
+
+    #[test]
+    fn determinant_large_test() {
+        let size = 100;
+        let triangular = Matrix::new(size, size, |address| {
+            if address.x == address.y {
+                if address.x % 2 == 0 { 1.25 } else { 0.8 }
+            } else if address.x > address.y {
+                (address.x * 31 % 7) as f64 - 3.0
+            } else {
+                0.0
+            }
+        })
+        .unwrap();
+        assert!((triangular.determinant().unwrap() - 1.0).abs() < 1e-9);
+        let mut permuted = triangular.clone();
+        permuted
+            .apply_op(&crate::matrix::MatrixOp::SwapRows { a: 0, b: 99 })
+            .unwrap();
+        assert!((permuted.determinant().unwrap() + 1.0).abs() < 1e-9);
+    }
+
+    fn identity(size: usize) -> Matrix<f64> {
+        Matrix::identity(size)
+    }
+
+    #[test]
+    fn identity_test() {
+        for size in [1, 2, 5, 17] {
+            let identity = Matrix::<u64>::identity(size);
+            assert_eq!(identity.address_iter().count(), size * size);
+            identity.address_value_iter().for_each(|(address, value)| {
+                assert_eq!(*value, if address.x == address.y { 1 } else { 0 });
+            });
+        }
+        assert_eq!(Matrix::<f32>::identity(1)[(0, 0)], 1.0);
+    }
+
+    #[test]
+    fn from_diagonal_test() {
+        for size in [1, 2, 5, 17] {
+            let values = (1..=size as i64).collect::<Vec<i64>>();
+            let diagonal = Matrix::from_diagonal(&values);
+            assert_eq!(diagonal.address_iter().count(), size * size);
+            diagonal.address_value_iter().for_each(|(address, value)| {
+                if address.x == address.y {
+                    assert_eq!(*value, address.x as i64 + 1);
+                } else {
+                    assert_eq!(*value, 0);
+                }
+            });
+        }
+        let diagonal = Matrix::from_diagonal(&[2.0, -3.0, 0.5]);
+        assert_eq!(diagonal.determinant().unwrap(), -3.0);
+    }
+
+    #[test]
+    fn invert_test() {
+        let matrix = Matrix::from_rows(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ])
+        .unwrap();
+        let expected = Matrix::from_rows(vec![
+            vec![0.75, 0.5, 0.25],
+            vec![0.5, 1.0, 0.5],
+            vec![0.25, 0.5, 0.75],
+        ])
+        .unwrap();
+        let inverse = matrix.invert().unwrap();
+        assert!(inverse.approx_eq(&expected, 1e-12));
+        assert!(
+            matrix
+                .mat_mul(&inverse)
+                .unwrap()
+                .approx_eq(&identity(3), 1e-12)
+        );
+        assert!(inverse.invert().unwrap().approx_eq(&matrix, 1e-12));
+    }
+
+    #[test]
+    fn invert_pascal_test() {
+        // The symmetric Pascal matrix has determinant one and an inverse with integer values.
+        for size in 1..=7 {
+            let mut pascal = Matrix::new(size, size, |_| 1.0).unwrap();
+            for y in 1..size as i32 {
+                for x in 1..size as i32 {
+                    pascal[(x, y)] = pascal[(x - 1, y)] + pascal[(x, y - 1)];
+                }
+            }
+            let inverse = pascal.invert().unwrap();
+            assert!(
+                pascal
+                    .mat_mul(&inverse)
+                    .unwrap()
+                    .approx_eq(&identity(size), 1e-9)
+            );
+            assert!(
+                inverse
+                    .mat_mul(&pascal)
+                    .unwrap()
+                    .approx_eq(&identity(size), 1e-9)
+            );
+            assert!(
+                inverse
+                    .iter()
+                    .all(|value| (value - value.round()).abs() < 1e-6)
+            );
+        }
+    }
+
+    #[test]
+    fn invert_error_test() {
+        assert_eq!(
+            Matrix::new(3, 2, |_| 1.0).unwrap().invert(),
+            Err(InversionError::NotSquare(NotSquareError {
+                width: 3,
+                height: 2
+            }))
+        );
+        let singular = Matrix::from_rows(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ])
+        .unwrap();
+        assert_eq!(singular.invert(), Err(InversionError::Singular));
+        assert_eq!(
+            Matrix::new(4, 4, |_| 0.0).unwrap().invert(),
+            Err(InversionError::Singular)
+        );
+        let repeated_column = Matrix::new(5, 5, |address| {
+            let x = if address.x == 4 { 1 } else { address.x };
+            ((x + 2) * (address.y + 3) % 7) as f64 + (x == address.y) as u8 as f64
+        })
+        .unwrap();
+        assert_eq!(repeated_column.invert(), Err(InversionError::Singular));
+    }
+
+    #[test]
+    fn approx_eq_test() {
+        let a = Matrix::new(3, 3, |address| address.x as f64 * 0.1).unwrap();
+        let b = Matrix::new(3, 3, |address| address.x as f64 / 10.0 + 1e-10).unwrap();
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&b, 1e-11));
+        assert!(!a.approx_eq(&Matrix::new(3, 2, |_| 0.0).unwrap(), 1.0));
+    }
+
+    #[test]
+    fn gaussian_elimination_test() {
+        let mut matrix = Matrix::from_rows(vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ])
+        .unwrap();
+        let determinant = matrix.determinant().unwrap();
+        matrix.gaussian_elimination();
+        assert!(
+            matrix
+                .address_value_iter()
+                .all(|(address, &value)| address.x >= address.y || value == 0.0)
+        );
+        assert_eq!(matrix[(0, 0)], -3.0);
+        let diagonal_product = (0..3).map(|i| matrix[(i, i)]).product::<f64>();
+        assert!((diagonal_product.abs() - determinant.abs()).abs() < 1e-12);
+
+        let mut wide =
+            Matrix::from_rows(vec![vec![0.0, 1.0, 2.0, 3.0], vec![0.0, 2.0, 4.0, 7.0]]).unwrap();
+        wide.gaussian_elimination();
+        assert_eq!(
+            wide,
+            Matrix::from_rows(vec![vec![0.0, 2.0, 4.0, 7.0], vec![0.0, 0.0, 0.0, -0.5]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn solve_test() {
+        let a = Matrix::from_rows(vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ])
+        .unwrap();
+        let b = Matrix::from_rows(vec![vec![8.0], vec![-11.0], vec![-3.0]]).unwrap();
+        let x = a.solve(&b).unwrap();
+        for (value, expected) in x.iter().zip([2.0, 3.0, -1.0]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        let x = Matrix::from_cols(vec![x]).unwrap();
+        assert!(a.mat_mul(&x).unwrap().approx_eq(&b, 1e-12));
+    }
+
+    #[test]
+    fn solve_error_test() {
+        let singular = Matrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        let b = Matrix::from_rows(vec![vec![1.0], vec![2.0]]).unwrap();
+        assert_eq!(singular.solve(&b), Err(MatrixError::Singular));
+        let wide = Matrix::new(3, 2, |_| 1.0).unwrap();
+        assert_eq!(
+            wide.solve(&b),
+            Err(MatrixError::NotSquare(NotSquareError {
+                width: 3,
+                height: 2
+            }))
+        );
+        let square = Matrix::<f64>::identity(2);
+        assert!(matches!(
+            square.solve(&Matrix::new(2, 1, |_| 1.0).unwrap()),
+            Err(MatrixError::DimensionMismatch(_))
+        ));
+        assert!(matches!(
+            square.solve(&Matrix::new(1, 3, |_| 1.0).unwrap()),
+            Err(MatrixError::DimensionMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn lu_decompose_test() {
+        let a = Matrix::from_rows(vec![
+            vec![1.0, 3.0, 5.0, 2.0],
+            vec![2.0, 4.0, 7.0, 1.0],
+            vec![1.0, 1.0, 0.0, 6.0],
+            vec![7.0, 2.0, 3.0, 3.0],
+        ])
+        .unwrap();
+        let (l, u, permutation) = a.lu_decompose().unwrap();
+        for (address, &value) in l.address_value_iter() {
+            match address.x.cmp(&address.y) {
+                std::cmp::Ordering::Less => assert!(value.abs() <= 1.0),
+                std::cmp::Ordering::Equal => assert_eq!(value, 1.0),
+                std::cmp::Ordering::Greater => assert_eq!(value, 0.0),
+            }
+        }
+        assert!(
+            u.address_value_iter()
+                .all(|(address, &value)| address.x >= address.y || value == 0.0)
+        );
+        let p = Matrix::new(4, 4, |address| {
+            (permutation[address.y as usize] == address.x as usize) as u8 as f64
+        })
+        .unwrap();
+        assert!(
+            p.mat_mul(&a)
+                .unwrap()
+                .approx_eq(&l.mat_mul(&u).unwrap(), 1e-12)
+        );
+
+        // Solve Ax = b as Ly = Pb followed by Ux = y.
+        let b = [4.0, -2.0, 7.0, 1.0];
+        let mut x = permutation.iter().map(|&row| b[row]).collect::<Vec<f64>>();
+        for row in 0..4 {
+            x[row] -= (0..row)
+                .map(|k| l[(k as i32, row as i32)] * x[k])
+                .sum::<f64>();
+        }
+        for row in (0..4).rev() {
+            x[row] -= (row + 1..4)
+                .map(|k| u[(k as i32, row as i32)] * x[k])
+                .sum::<f64>();
+            x[row] /= u[(row as i32, row as i32)];
+        }
+        let expected = a
+            .solve(&Matrix::from_cols(vec![b.to_vec()]).unwrap())
+            .unwrap();
+        for (value, expected) in x.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        let x = Matrix::from_cols(vec![x]).unwrap();
+        assert!(
+            a.mat_mul(&x)
+                .unwrap()
+                .approx_eq(&Matrix::from_cols(vec![b.to_vec()]).unwrap(), 1e-12)
+        );
+    }
+
+    #[test]
+    fn lu_decompose_empty_test() {
+        let empty = Matrix::<f64>::from_rows(vec![]).unwrap();
+        let (l, u, permutation) = empty.lu_decompose().unwrap();
+        assert_eq!((l.dimensions(), u.dimensions()), ((0, 0), (0, 0)));
+        assert!(permutation.is_empty());
+        assert_eq!(empty.invert(), Ok(empty.clone()));
+    }
+
+    #[test]
+    fn lu_decompose_error_test() {
+        let singular = Matrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(singular.lu_decompose(), Err(MatrixError::Singular));
+        assert!(matches!(
+            Matrix::new(2, 3, |_| 1.0).unwrap().lu_decompose(),
+            Err(MatrixError::NotSquare(_))
+        ));
+    }
+
+    #[test]
+    fn trace_test() {
+        for size in 1..6 {
+            assert_eq!(Matrix::<i64>::identity(size).trace(), Ok(size as i64));
+        }
+        assert_eq!(parse("1 2 3|4 5 6|7 8 9").trace(), Ok(15));
+        assert_eq!(
+            parse("1 2 3|4 5 6").trace(),
+            Err(NotSquareError {
+                width: 3,
+                height: 2
+            })
+        );
+        assert_eq!(Matrix::<i64>::from_rows(vec![]).unwrap().trace(), Ok(0));
+    }
+
+    #[test]
+    fn diagonal_iter_test() {
+        let wide = parse("1 2 3 4|5 6 7 8");
+        assert_eq!(
+            wide.diagonal_iter().copied().collect::<Vec<_>>(),
+            vec![1, 6]
+        );
+        assert_eq!(
+            wide.anti_diagonal_iter().copied().collect::<Vec<_>>(),
+            vec![4, 7]
+        );
+        let tall = parse("1 2|3 4|5 6");
+        assert_eq!(
+            tall.diagonal_iter().copied().collect::<Vec<_>>(),
+            vec![1, 4]
+        );
+        assert_eq!(
+            tall.anti_diagonal_iter().copied().collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        let column = parse("1|2|3");
+        assert_eq!(column.diagonal_iter().copied().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(
+            column.anti_diagonal_iter().copied().collect::<Vec<_>>(),
+            vec![1]
+        );
+        let empty = Matrix::<i64>::from_rows(vec![vec![]; 2]).unwrap();
+        assert_eq!(empty.diagonal_iter().count(), 0);
+        assert_eq!(empty.anti_diagonal_iter().count(), 0);
+    }
+
+    #[test]
+    fn norms_test() {
+        let square = Matrix::from_rows(vec![vec![1.0, -2.0], vec![-3.0, 4.0]]).unwrap();
+        assert_eq!(square.frobenius_norm(), 30f64.sqrt());
+        assert_eq!(square.l1_norm(), 6.0);
+        assert_eq!(square.l_inf_norm(), 7.0);
+        assert_eq!(square.element_sum_abs(), 10.0);
+
+        let wide = Matrix::from_rows(vec![vec![1.0, -2.0, 3.0], vec![0.5, 0.0, -0.5]]).unwrap();
+        assert_eq!(wide.frobenius_norm(), 14.5f64.sqrt());
+        assert_eq!(wide.l1_norm(), 3.5);
+        assert_eq!(wide.l_inf_norm(), 6.0);
+        assert_eq!(wide.element_sum_abs(), 7.0);
+
+        for (width, height) in [(0, 0), (3, 0), (0, 3)] {
+            let empty = Matrix::<f64>::from_flat_vec(width, height, vec![]).unwrap();
+            for norm in [
+                empty.frobenius_norm(),
+                empty.l1_norm(),
+                empty.l_inf_norm(),
+                empty.element_sum_abs(),
+            ] {
+                assert_eq!(norm, 0.0);
+                assert!(norm.is_sign_positive());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn diagonal_iter_mut_test(width in 0usize..7, height in 1usize..7) {
+            let mut matrix = Matrix::new(width, height, |_| 0).unwrap_or(Matrix::from_rows(vec![vec![]; height]).unwrap());
+            matrix.diagonal_iter_mut().for_each(|value| *value += 1);
+            matrix.anti_diagonal_iter_mut().for_each(|value| *value += 2);
+            for (address, value) in matrix.address_value_iter() {
+                let (x, y) = (address.x as usize, address.y as usize);
+                let expected = if x == y { 1 } else { 0 } + if x + y + 1 == width { 2 } else { 0 };
+                assert_eq!(*value, expected, "at {:?}", address);
+            }
+            assert!(matrix.diagonal_iter().copied().eq(matrix.clone().diagonal_iter_mut().map(|value| *value)));
+        }
+
+        #[test]
+        fn kronecker_identity_block_diagonal_test(
+            n in 1usize..4,
+            width in 1usize..4,
+            height in 1usize..4,
+            values in proptest::collection::vec(-9i64..10, 9),
+        ) {
+            let a = Matrix::new(width, height, |address| values[address.y as usize * 3 + address.x as usize]).unwrap();
+            let product = Matrix::<i64>::identity(n).kronecker(&a);
+            assert_eq!(product.dimensions(), (n * width, n * height));
+            for (address, value) in product.address_value_iter() {
+                let (block_x, block_y) = (address.x / width as i32, address.y / height as i32);
+                let expected = if block_x == block_y {
+                    a[(address.x % width as i32, address.y % height as i32)]
+                } else {
+                    0
+                };
+                assert_eq!(*value, expected);
+            }
+        }
+
+        #[test]
+        fn symmetric_sum_test(values in proptest::collection::vec(-9i64..10, 16)) {
+            let matrix = Matrix::from_flat_vec(4, 4, values).unwrap();
+            let symmetric = Matrix::new(4, 4, |address| matrix[address] + matrix[(address.y, address.x)]).unwrap();
+            assert!(symmetric.is_symmetric());
+            assert_eq!(matrix.is_symmetric(), matrix == Matrix::new(4, 4, |address| matrix[(address.y, address.x)]).unwrap());
+        }
+
+        #[test]
+        fn trace_cyclic_test(
+            width in 1usize..6,
+            height in 1usize..6,
+            depth in 1usize..6,
+            values in proptest::collection::vec(-20i64..20, 75),
+        ) {
+            let a = Matrix::new(width, height, |address| values[address.y as usize * 5 + address.x as usize]).unwrap();
+            let b = Matrix::new(depth, width, |address| values[25 + address.y as usize * 5 + address.x as usize]).unwrap();
+            let c = Matrix::new(height, depth, |address| values[50 + address.y as usize * 5 + address.x as usize]).unwrap();
+            let abc = a.mat_mul(&b).unwrap().mat_mul(&c).unwrap().trace().unwrap();
+            assert_eq!(b.mat_mul(&c).unwrap().mat_mul(&a).unwrap().trace().unwrap(), abc);
+            assert_eq!(c.mat_mul(&a).unwrap().mat_mul(&b).unwrap().trace().unwrap(), abc);
+        }
+
+        #[test]
+        fn determinant_product_test(
+            size in 1usize..6,
+            a_values in proptest::collection::vec(-10.0f64..10.0, 25),
+            b_values in proptest::collection::vec(-10.0f64..10.0, 25),
+        ) {
+            let a = Matrix::new(size, size, |address| a_values[address.y as usize * 5 + address.x as usize]).unwrap();
+            let b = Matrix::new(size, size, |address| b_values[address.y as usize * 5 + address.x as usize]).unwrap();
+            let product = a.mat_mul(&b).unwrap().determinant().unwrap();
+            let expected = a.determinant().unwrap() * b.determinant().unwrap();
+            // Hadamard's bound on the magnitude of each determinant sets the scale of rounding errors.
+            let bound = |matrix: &Matrix<f64>| {
+                (0..size)
+                    .map(|y| matrix.row_slice(y).iter().map(|v| v * v).sum::<f64>().sqrt())
+                    .product::<f64>()
+            };
+            assert!((product - expected).abs() <= 1e-10 * (bound(&a) * bound(&b)).max(1.0));
+        }
+
+        #[test]
+        fn solve_diagonally_dominant_test(size in 1usize..12, seed in -50i32..50) {
+            let matrix = Matrix::new(size, size, |address| {
+                if address.x == address.y {
+                    4.0 * size as f64
+                } else {
+                    ((address.x * 5 + address.y * 11 + seed) % 7) as f64
+                }
+            })
+            .unwrap();
+            let b = Matrix::new(1, size, |address| (address.y - seed) as f64).unwrap();
+            let x = Matrix::from_cols(vec![matrix.solve(&b).unwrap()]).unwrap();
+            assert!(matrix.mat_mul(&x).unwrap().approx_eq(&b, 1e-9));
+        }
+
+        #[test]
+        fn invert_diagonally_dominant_test(size in 1usize..12, seed in -50i32..50) {
+            let matrix = Matrix::new(size, size, |address| {
+                if address.x == address.y {
+                    4.0 * size as f64
+                } else {
+                    ((address.x * 7 + address.y * 3 + seed) % 5) as f64
+                }
+            })
+            .unwrap();
+            let inverse = matrix.invert().unwrap();
+            assert!(matrix.mat_mul(&inverse).unwrap().approx_eq(&identity(size), 1e-9));
+            assert!(inverse.mat_mul(&matrix).unwrap().approx_eq(&identity(size), 1e-9));
+        }
+
+
+        #[test]
+        fn mat_mul_identity_test(width in 1usize..20, height in 1usize..20, seed in -1000i64..1000) {
+            let matrix = Matrix::new(width, height, |address| {
+                seed * address.x as i64 - 3 * address.y as i64
+            })
+            .unwrap();
+            let left_identity = Matrix::new(height, height, |address| (address.x == address.y) as i64).unwrap();
+            let right_identity = Matrix::new(width, width, |address| (address.x == address.y) as i64).unwrap();
+            assert_eq!(left_identity.mat_mul(&matrix).unwrap(), matrix.clone());
+            assert_eq!(matrix.mat_mul(&right_identity).unwrap(), matrix);
+        }
+    }
+}
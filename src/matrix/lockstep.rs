@@ -0,0 +1,251 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::matrix_error::DimensionMismatchError;
+
+/// Iterates over several matrices of the same dimensions at once, yielding each address together
+/// with a tuple of references to the value at that address in every matrix.
+///
+/// Sources are added with `with`, and their dimensions are checked against the first source as they
+/// are added. The first mismatch is reported by `iter` or `iter_mut`, before anything is iterated.
+/// A lockstep started with `new_mut` holds its first source mutably, so `iter_mut` can write to it
+/// while reading the others. Up to five matrices can be iterated together.
+///
+/// # Examples
+///
+/// ```
+/// use rust_tensors::matrix::{Lockstep, Matrix};
+///
+/// let mut position = Matrix::new(2, 2, |address| address.x as f64).unwrap();
+/// let velocity = Matrix::new(2, 2, |_| 3.0).unwrap();
+/// let mass = Matrix::new(2, 2, |address| 1.0 + address.y as f64).unwrap();
+/// for (_, (position, velocity, mass)) in Lockstep::new_mut(&mut position).with(&velocity).with(&mass).iter_mut().unwrap() {
+///     *position += velocity / mass;
+/// }
+/// assert_eq!(position, Matrix::from_rows(vec![vec![3.0, 4.0], vec![1.5, 2.5]]).unwrap());
+///
+/// let wrong = Matrix::new(3, 2, |_| 0.0).unwrap();
+/// assert!(Lockstep::new(&position).with(&velocity).with(&wrong).iter().is_err());
+/// ```
+pub struct Lockstep<S> {
+    sources: S,
+    width: usize,
+    height: usize,
+    source_count: usize,
+    mismatch: Option<DimensionMismatchError>,
+}
+
+impl<'a, T> Lockstep<(&'a Matrix<T>,)> {
+    /// Starts a lockstep iteration over the matrix, which sets the dimensions every other source
+    /// must have.
+    pub fn new(first: &'a Matrix<T>) -> Self {
+        Lockstep::start((first,), first.width, first.height)
+    }
+}
+
+impl<'a, T> Lockstep<(&'a mut Matrix<T>,)> {
+    /// Starts a lockstep iteration whose values from this matrix are mutable, which sets the
+    /// dimensions every other source must have.
+    pub fn new_mut(first: &'a mut Matrix<T>) -> Self {
+        let (width, height) = (first.width, first.height);
+        Lockstep::start((first,), width, height)
+    }
+}
+
+impl<S> Lockstep<S> {
+    fn start(sources: S, width: usize, height: usize) -> Self {
+        Lockstep {
+            sources,
+            width,
+            height,
+            source_count: 1,
+            mismatch: None,
+        }
+    }
+
+    /// Records a mismatch for the next source unless an earlier one was already recorded.
+    fn checked<T>(&self, next: &Matrix<T>) -> Option<DimensionMismatchError> {
+        self.mismatch.clone().or_else(|| {
+            (next.width != self.width || next.height != self.height).then(|| {
+                DimensionMismatchError {
+                    message: format!(
+                        "Source {} of the lockstep is {}x{}, expected {}x{}",
+                        self.source_count, next.width, next.height, self.width, self.height
+                    ),
+                }
+            })
+        })
+    }
+
+    fn into_sources(self) -> Result<(S, usize), DimensionMismatchError> {
+        match self.mismatch {
+            Some(mismatch) => Err(mismatch),
+            None => Ok((self.sources, self.width)),
+        }
+    }
+}
+
+macro_rules! impl_lockstep_with {
+    ($(($source:ident, $value:ident)),*) => {
+        impl<'a, First, $($value),*> Lockstep<(First, $(&'a Matrix<$value>,)*)> {
+            /// Adds a matrix to the sources, whose values come after those of the current sources in
+            /// the yielded tuples.
+            pub fn with<Next>(
+                self,
+                next: &'a Matrix<Next>,
+            ) -> Lockstep<(First, $(&'a Matrix<$value>,)* &'a Matrix<Next>)> {
+                let mismatch = self.checked(next);
+                let (first, $($source,)*) = self.sources;
+                Lockstep {
+                    sources: (first, $($source,)* next),
+                    width: self.width,
+                    height: self.height,
+                    source_count: self.source_count + 1,
+                    mismatch,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_lockstep_iter {
+    ($(($source:ident, $value:ident)),*) => {
+        impl<'a, First, $($value),*> Lockstep<(&'a Matrix<First>, $(&'a Matrix<$value>,)*)> {
+            /// Creates an iterator over the addresses of the sources in row-major order, with the
+            /// values of every source at each address.
+            ///
+            /// Returns: An Err describing the first source whose dimensions differ from the first's.
+            #[allow(clippy::type_complexity)]
+            pub fn iter(
+                self,
+            ) -> Result<
+                impl Iterator<Item = (MatrixAddress, (&'a First, $(&'a $value,)*))>,
+                DimensionMismatchError,
+            > {
+                let ((first, $($source,)*), width) = self.into_sources()?;
+                Ok(first.data.iter().enumerate().map(move |(index, first)| {
+                    (Matrix::<First>::row_major_address(width, index), (first, $(&$source.data[index],)*))
+                }))
+            }
+        }
+
+        impl<'a, First, $($value),*> Lockstep<(&'a mut Matrix<First>, $(&'a Matrix<$value>,)*)> {
+            /// Creates an iterator over the addresses of the sources in row-major order, with a
+            /// mutable reference to the value of the first source and the values of the others at
+            /// each address.
+            ///
+            /// Returns: An Err describing the first source whose dimensions differ from the first's.
+            #[allow(clippy::type_complexity)]
+            pub fn iter_mut(
+                self,
+            ) -> Result<
+                impl Iterator<Item = (MatrixAddress, (&'a mut First, $(&'a $value,)*))>,
+                DimensionMismatchError,
+            > {
+                let ((first, $($source,)*), width) = self.into_sources()?;
+                Ok(first.data.iter_mut().enumerate().map(move |(index, first)| {
+                    (Matrix::<First>::row_major_address(width, index), (first, $(&$source.data[index],)*))
+                }))
+            }
+        }
+    };
+}
+
+impl_lockstep_with!();
+impl_lockstep_with!((second, Second));
+impl_lockstep_with!((second, Second), (third, Third));
+impl_lockstep_with!((second, Second), (third, Third), (fourth, Fourth));
+
+impl_lockstep_iter!();
+impl_lockstep_iter!((second, Second));
+impl_lockstep_iter!((second, Second), (third, Third));
+impl_lockstep_iter!((second, Second), (third, Third), (fourth, Fourth));
+impl_lockstep_iter!(
+    (second, Second),
+    (third, Third),
+    (fourth, Fourth),
+    (fifth, Fifth)
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{Lockstep, Matrix};
+    use crate::matrix_address::MatrixAddress;
+    use crate::tensor::Tensor;
+
+    #[test]
+    fn lockstep_pairing_test() {
+        let a = Matrix::new(3, 2, |address| address.x).unwrap();
+        let b = Matrix::new(3, 2, |address| address.y as u8).unwrap();
+        let c = Matrix::new(3, 2, |address| format!("{},{}", address.x, address.y)).unwrap();
+        let visited = Lockstep::new(&a)
+            .with(&b)
+            .with(&c)
+            .iter()
+            .unwrap()
+            .map(|(address, (a, b, c))| {
+                assert_eq!(*a, address.x);
+                assert_eq!(*b as i32, address.y);
+                assert_eq!(*c, format!("{},{}", address.x, address.y));
+                address
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(visited, a.address_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn lockstep_mismatch_test() {
+        let a = Matrix::new(3, 2, |_| 0).unwrap();
+        let b = Matrix::new(2, 3, |_| 0).unwrap();
+        let c = Matrix::new(3, 3, |_| 0).unwrap();
+        let error = Lockstep::new(&a)
+            .with(&a)
+            .with(&b)
+            .with(&c)
+            .iter()
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.message,
+            "Source 2 of the lockstep is 2x3, expected 3x2"
+        );
+        let mut d = Matrix::new(3, 3, |_| 0).unwrap();
+        let error = Lockstep::new_mut(&mut d)
+            .with(&c)
+            .with(&a)
+            .iter_mut()
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.message,
+            "Source 2 of the lockstep is 3x2, expected 3x3"
+        );
+    }
+
+    #[test]
+    fn lockstep_mut_test() {
+        let mut position = Matrix::new(4, 3, |address| address.x * 10).unwrap();
+        let velocity = Matrix::new(4, 3, |address| address.y).unwrap();
+        let mass = Matrix::new(4, 3, |_| 2).unwrap();
+        let fixed = Matrix::new(4, 3, |address| address == MatrixAddress { x: 1, y: 1 }).unwrap();
+        let steps = Lockstep::new_mut(&mut position)
+            .with(&velocity)
+            .with(&mass)
+            .with(&fixed)
+            .iter_mut()
+            .unwrap();
+        for (_, (position, velocity, mass, fixed)) in steps {
+            if !fixed {
+                *position += velocity * mass;
+            }
+        }
+        let expected = Matrix::new(4, 3, |address| {
+            if address == (MatrixAddress { x: 1, y: 1 }) {
+                10
+            } else {
+                address.x * 10 + address.y * 2
+            }
+        })
+        .unwrap();
+        assert_eq!(position, expected);
+    }
+}
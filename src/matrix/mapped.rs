@@ -0,0 +1,164 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+
+/// A read-only view of a matrix which converts each value with a function when it is read, instead
+/// of building a converted matrix up front.
+///
+/// Converted values are computed again on every read and returned by value, so the view cannot be
+/// indexed by reference like a `Matrix`. It suits values which are consumed once, such as by a
+/// reduction or a renderer.
+#[derive(Clone, Copy)]
+pub struct MappedView<'a, T, U, F: Fn(&T) -> U> {
+    matrix: &'a Matrix<T>,
+    mapper: F,
+}
+
+impl<T> Matrix<T> {
+    /// Creates a view of the matrix whose values are converted with a function on access.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapper`: Converts a value of the matrix to the value seen through the view
+    ///
+    /// Returns: `MappedView<T, U, F>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let pixels = Matrix::new(1000, 1000, |address| (address.x % 256) as u8).unwrap();
+    /// let brightness = pixels.map_view(|&pixel| f64::from(pixel) / 255.0);
+    /// assert_eq!(brightness.get([255, 0].into()), Some(1.0));
+    /// assert!((brightness.iter().sum::<f64>() - 489_082.35).abs() < 0.01);
+    /// ```
+    pub fn map_view<U, F>(&self, mapper: F) -> MappedView<'_, T, U, F>
+    where
+        F: Fn(&T) -> U,
+    {
+        MappedView {
+            matrix: self,
+            mapper,
+        }
+    }
+}
+
+impl<'a, T, U, F: Fn(&T) -> U> MappedView<'a, T, U, F> {
+    /// Gets the converted value at an address.
+    ///
+    /// # Arguments
+    ///
+    /// * `address`: The address of the value
+    ///
+    /// Returns: `Option<U>`, None if the address is not contained in the matrix.
+    pub fn get(&self, address: MatrixAddress) -> Option<U> {
+        let index = self.matrix.index_of(address)?;
+        Some((self.mapper)(&self.matrix.data[index]))
+    }
+
+    /// Whether the address has a value in the underlying matrix.
+    ///
+    /// Returns: `bool`
+    pub fn contains_address(&self, address: MatrixAddress) -> bool {
+        self.matrix.index_of(address).is_some()
+    }
+
+    /// The width and height of the underlying matrix.
+    ///
+    /// Returns: `(usize, usize)`, as `(width, height)`
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.matrix.dimensions()
+    }
+
+    /// The underlying matrix, whose values are not converted.
+    ///
+    /// Returns: `&Matrix<T>`
+    pub fn matrix(&self) -> &'a Matrix<T> {
+        self.matrix
+    }
+
+    /// Creates an iterator converting every value in row-major order.
+    ///
+    /// Returns: `impl Iterator<Item = U>`
+    pub fn iter(&self) -> impl Iterator<Item = U> {
+        self.matrix.data.iter().map(&self.mapper)
+    }
+
+    /// Creates an iterator converting every value in row-major order, together with its address.
+    ///
+    /// Returns: `impl Iterator<Item = (MatrixAddress, U)>`
+    pub fn address_value_iter(&self) -> impl Iterator<Item = (MatrixAddress, U)> {
+        self.matrix
+            .data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (self.matrix.address_of_index(index), (self.mapper)(value)))
+    }
+
+    /// Converts every value into a new matrix.
+    ///
+    /// Returns: `Matrix<U>`
+    pub fn to_matrix(&self) -> Matrix<U> {
+        Matrix::from_parts(self.matrix.width, self.matrix.height, self.iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_address::MatrixAddress;
+    use proptest::proptest;
+    use std::cell::Cell;
+
+    /// A value which counts how many of its kind exist at once, and cannot be cloned.
+    struct Tracked<'a> {
+        value: i64,
+        live: &'a Cell<usize>,
+    }
+
+    impl<'a> Tracked<'a> {
+        fn new(value: i64, live: &'a Cell<usize>, peak: &'a Cell<usize>) -> Self {
+            live.set(live.get() + 1);
+            peak.set(peak.get().max(live.get()));
+            Tracked { value, live }
+        }
+    }
+
+    impl Drop for Tracked<'_> {
+        fn drop(&mut self) {
+            self.live.set(self.live.get() - 1);
+        }
+    }
+
+    #[test]
+    fn values_are_not_materialized_test() {
+        let (live, peak) = (Cell::new(0), Cell::new(0));
+        let matrix = Matrix::new(300, 200, |address| (address.x - address.y) as i64).unwrap();
+        let view = matrix.map_view(|&value| Tracked::new(value * 3, &live, &peak));
+        let sum = view.iter().map(|tracked| tracked.value).sum::<i64>();
+        assert_eq!(sum, matrix.iter().map(|value| value * 3).sum::<i64>());
+        assert_eq!(view.get(MatrixAddress { x: 5, y: 1 }).unwrap().value, 12);
+        assert_eq!(peak.get(), 1);
+        assert_eq!(live.get(), 0);
+        assert_eq!(view.to_matrix().iter().count(), 60000);
+        assert_eq!(peak.get(), 60000);
+    }
+
+    proptest! {
+        #[test]
+        fn reductions_match_eager_map_test(values in proptest::collection::vec(0u8..=255, 0..40), width in 1usize..5) {
+            let height = values.len() / width;
+            let matrix = Matrix::from_flat_vec(width, height, values[..width * height].to_vec()).unwrap();
+            let view = matrix.map_view(|&value| f64::from(value).sqrt());
+            let eager = matrix.clone().transform(|_, &value| f64::from(value).sqrt());
+            assert_eq!(view.iter().sum::<f64>(), eager.iter().sum::<f64>());
+            assert_eq!(view.to_matrix(), eager);
+            for (address, value) in view.address_value_iter() {
+                assert_eq!(value, eager[address]);
+                assert_eq!(view.get(address), Some(value));
+            }
+            assert_eq!(view.get(MatrixAddress { x: width as i32, y: 0 }), None);
+            assert_eq!(view.dimensions(), matrix.dimensions());
+        }
+    }
+}
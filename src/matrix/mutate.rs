@@ -0,0 +1,407 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::matrix_error::{MatrixError, OutOfBoundsError};
+use crate::tensor::Tensor;
+
+impl<T> Matrix<T> {
+    /// Sets every value of the matrix to a clone of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(3, 2, |address| address.x).unwrap();
+    /// matrix.fill(7);
+    /// assert_eq!(matrix, Matrix::new(3, 2, |_| 7).unwrap());
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+    }
+
+    /// Sets every value of the matrix to a value created from its address, reusing the existing
+    /// allocation. The result equals `Matrix::new` with the same dimensions and converter.
+    ///
+    /// # Arguments
+    ///
+    /// * `address_value_converter`: Creates the new value of a cell from its address
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(3, 2, |_| 0).unwrap();
+    /// matrix.fill_with(|address| address.x * 10 + address.y);
+    /// assert_eq!(matrix, Matrix::new(3, 2, |address| address.x * 10 + address.y).unwrap());
+    /// ```
+    pub fn fill_with<F>(&mut self, address_value_converter: F)
+    where
+        F: Fn(MatrixAddress) -> T,
+    {
+        let width = self.width;
+        for (index, value) in self.data.iter_mut().enumerate() {
+            *value = address_value_converter(Self::row_major_address(width, index));
+        }
+    }
+
+    /// Sets every value of the matrix to `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(2, 2, |address| address.x + address.y).unwrap();
+    /// matrix.clear_to_default();
+    /// assert_eq!(matrix, Matrix::new(2, 2, |_| 0).unwrap());
+    /// ```
+    pub fn clear_to_default(&mut self)
+    where
+        T: Default,
+    {
+        self.data.fill_with(T::default);
+    }
+
+    /// Changes every value of the matrix in place, in row-major order, without allocating.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: Changes a value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(3, 2, |address| address.x as f64).unwrap();
+    /// matrix.apply(|value| *value = value.powi(2));
+    /// assert_eq!(matrix, Matrix::new(3, 2, |address| (address.x * address.x) as f64).unwrap());
+    /// ```
+    pub fn apply<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.data.iter_mut().for_each(f);
+    }
+
+    /// Changes every value of the matrix in place, in row-major order, given its address.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: Changes the value at an address
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(3, 3, |_| 1).unwrap();
+    /// matrix.apply_with_address(|address, value| *value *= address.x - address.y);
+    /// assert_eq!(matrix.diagonal_iter().sum::<i32>(), 0);
+    /// assert_eq!(matrix[(2, 0)], 2);
+    /// ```
+    pub fn apply_with_address<F: FnMut(MatrixAddress, &mut T)>(&mut self, mut f: F) {
+        let width = self.width;
+        for (index, value) in self.data.iter_mut().enumerate() {
+            f(Self::row_major_address(width, index), value);
+        }
+    }
+
+    /// Swaps the values at two addresses, without cloning either. Swapping an address with itself
+    /// leaves the matrix unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `a`: The address of the first value
+    /// * `b`: The address of the second value
+    ///
+    /// Returns: `Result<(), OutOfBoundsError>`, An Err naming the first address outside the matrix,
+    /// in which case nothing is swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec!["a", "b"], vec!["c", "d"]]).unwrap();
+    /// matrix.swap(MatrixAddress { x: 0, y: 0 }, MatrixAddress { x: 1, y: 1 }).unwrap();
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec!["d", "b"], vec!["c", "a"]]).unwrap());
+    /// assert!(matrix.swap(MatrixAddress { x: 0, y: 0 }, MatrixAddress { x: 2, y: 0 }).is_err());
+    /// ```
+    pub fn swap(&mut self, a: MatrixAddress, b: MatrixAddress) -> Result<(), OutOfBoundsError> {
+        let first = self.index_of(a).ok_or(OutOfBoundsError { address: a })?;
+        let second = self.index_of(b).ok_or(OutOfBoundsError { address: b })?;
+        self.data.swap(first, second);
+        Ok(())
+    }
+
+    /// Sets every value in the rectangle between two corners, including both, to a clone of the value.
+    /// Nothing is written if the region is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `top_left`: The address of the top left cell of the region
+    /// * `bottom_right`: The address of the bottom right cell of the region
+    /// * `value`: The new value of every cell in the region
+    ///
+    /// Returns: `Result<(), MatrixError>`, An Err if a corner lies outside the matrix
+    /// or the region is inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let mut matrix = Matrix::new(3, 2, |_| 0).unwrap();
+    /// matrix.fill_region(MatrixAddress { x: 1, y: 0 }, MatrixAddress { x: 2, y: 1 }, 1).unwrap();
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![0, 1, 1], vec![0, 1, 1]]).unwrap());
+    /// assert!(matrix.fill_region(MatrixAddress { x: 2, y: 0 }, MatrixAddress { x: 1, y: 1 }, 1).is_err());
+    /// ```
+    pub fn fill_region(
+        &mut self,
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+        value: T,
+    ) -> Result<(), MatrixError>
+    where
+        T: Clone,
+    {
+        self.check_region(top_left, bottom_right)?;
+        for y in top_left.y..=bottom_right.y {
+            let row_start = self.index_address(MatrixAddress { x: 0, y });
+            self.data[row_start + top_left.x as usize..=row_start + bottom_right.x as usize]
+                .fill(value.clone());
+        }
+        Ok(())
+    }
+
+    /// Sets every value in the rectangle between two corners, including both, to a value created from
+    /// its address. Nothing is written if the region is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `top_left`: The address of the top left cell of the region
+    /// * `bottom_right`: The address of the bottom right cell of the region
+    /// * `address_value_converter`: Creates the new value of a cell from its address in the matrix
+    ///
+    /// Returns: `Result<(), MatrixError>`, An Err if a corner lies outside the matrix
+    /// or the region is inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let mut matrix = Matrix::new(3, 2, |_| String::new()).unwrap();
+    /// let corner = MatrixAddress { x: 2, y: 1 };
+    /// matrix.fill_region_with(corner, corner, |address| format!("{}{}", address.x, address.y)).unwrap();
+    /// assert_eq!(matrix[corner], "21");
+    /// ```
+    pub fn fill_region_with<F>(
+        &mut self,
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+        address_value_converter: F,
+    ) -> Result<(), MatrixError>
+    where
+        F: Fn(MatrixAddress) -> T,
+    {
+        self.check_region(top_left, bottom_right)?;
+        for y in top_left.y..=bottom_right.y {
+            for x in top_left.x..=bottom_right.x {
+                let address = MatrixAddress { x, y };
+                self[address] = address_value_converter(address);
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn check_contains(&self, address: MatrixAddress) -> Result<(), OutOfBoundsError> {
+        if self.contains_address(address) {
+            Ok(())
+        } else {
+            Err(OutOfBoundsError { address })
+        }
+    }
+
+    pub(super) fn check_region(
+        &self,
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+    ) -> Result<(), MatrixError> {
+        if top_left.x > bottom_right.x || top_left.y > bottom_right.y {
+            return Err(MatrixError::InvalidRegion {
+                top_left,
+                bottom_right,
+            });
+        }
+        self.check_contains(top_left)?;
+        self.check_contains(bottom_right)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_address::MatrixAddress;
+    use crate::matrix_error::{MatrixError, OutOfBoundsError};
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    #[test]
+    fn apply_round_trip_test() {
+        let original =
+            Matrix::new(2000, 1500, |address| (address.x * 3 - address.y) as f64).unwrap();
+        let mut matrix = original.clone();
+        let buffer = matrix.as_slice().as_ptr();
+        matrix.apply(|value| *value += 1.0);
+        assert!(
+            matrix
+                .iter()
+                .zip(original.iter())
+                .all(|(a, b)| *a == b + 1.0)
+        );
+        matrix.apply(|value| *value -= 1.0);
+        assert_eq!(matrix, original);
+        assert_eq!(matrix.as_slice().as_ptr(), buffer);
+
+        let mut visited = Vec::new();
+        let mut small = Matrix::new(3, 2, |_| 0).unwrap();
+        small.apply_with_address(|address, value| {
+            visited.push(address);
+            *value = address.x * 10 + address.y;
+        });
+        assert!(visited.into_iter().eq(small.address_iter()));
+        assert_eq!(
+            small,
+            Matrix::new(3, 2, |address| address.x * 10 + address.y).unwrap()
+        );
+    }
+
+    #[test]
+    fn fill_with_test() {
+        let mut matrix = Matrix::new(5, 3, |_| String::from("old")).unwrap();
+        matrix.fill_with(|address| format!("{},{}", address.x, address.y));
+        assert_eq!(
+            matrix,
+            Matrix::new(5, 3, |address| format!("{},{}", address.x, address.y)).unwrap()
+        );
+        matrix.clear_to_default();
+        assert!(matrix.iter().all(String::is_empty));
+
+        let mut empty = Matrix::<String>::from_rows(vec![vec![]; 4]).unwrap();
+        empty.fill_with(|_| unreachable!());
+        assert_eq!(empty.dimensions(), (0, 4));
+    }
+
+    #[test]
+    fn fill_region_test() {
+        let original = Matrix::new(5, 4, |address| address.y * 10 + address.x).unwrap();
+        let mut matrix = original.clone();
+        matrix
+            .fill_region([1, 1].into(), [3, 2].into(), -1)
+            .unwrap();
+        let single = MatrixAddress { x: 4, y: 3 };
+        matrix.fill_region(single, single, -2).unwrap();
+        for (address, value) in original.address_value_iter() {
+            let expected = if address == single {
+                -2
+            } else if (1..=3).contains(&address.x) && (1..=2).contains(&address.y) {
+                -1
+            } else {
+                *value
+            };
+            assert_eq!(matrix[address], expected);
+        }
+        matrix.fill(0);
+        assert!(matrix.iter().all(|value| *value == 0));
+    }
+
+    #[test]
+    fn fill_region_errors_test() {
+        let original = Matrix::new(5, 4, |address| address.y * 10 + address.x).unwrap();
+        let mut matrix = original.clone();
+        assert_eq!(
+            matrix.fill_region([3, 1].into(), [2, 2].into(), 0),
+            Err(MatrixError::InvalidRegion {
+                top_left: MatrixAddress { x: 3, y: 1 },
+                bottom_right: MatrixAddress { x: 2, y: 2 },
+            })
+        );
+        assert_eq!(
+            matrix.fill_region_with([0, 0].into(), [5, 2].into(), |_| 0),
+            Err(MatrixError::OutOfBounds(OutOfBoundsError {
+                address: MatrixAddress { x: 5, y: 2 }
+            }))
+        );
+        assert_eq!(matrix, original);
+    }
+
+    #[test]
+    fn fill_region_with_test() {
+        let mut matrix = Matrix::new(3, 3, |_| vec![]).unwrap();
+        matrix
+            .fill_region_with([1, 0].into(), [2, 1].into(), |address| {
+                vec![address.x, address.y]
+            })
+            .unwrap();
+        assert_eq!(matrix[(2, 1)], vec![2, 1]);
+        assert_eq!(matrix[(1, 0)], vec![1, 0]);
+        assert!(matrix[(0, 0)].is_empty());
+        assert!(matrix[(1, 2)].is_empty());
+    }
+
+    #[test]
+    fn swap_test() {
+        let mut matrix = Matrix::new(3, 2, |address| Box::new(address.y * 3 + address.x)).unwrap();
+        matrix.swap([2, 1].into(), [0, 0].into()).unwrap();
+        assert_eq!((*matrix[(0, 0)], *matrix[(2, 1)]), (5, 0));
+        let before = matrix.clone();
+        matrix.swap([2, 1].into(), [2, 1].into()).unwrap();
+        matrix.swap([0, 1].into(), [0, 1].into()).unwrap();
+        assert_eq!(matrix, before);
+        assert_eq!(
+            matrix.swap([1, 1].into(), [3, 1].into()),
+            Err(OutOfBoundsError {
+                address: MatrixAddress { x: 3, y: 1 }
+            })
+        );
+        assert_eq!(
+            matrix.swap([0, 2].into(), [0, 2].into()),
+            Err(OutOfBoundsError {
+                address: MatrixAddress { x: 0, y: 2 }
+            })
+        );
+        assert_eq!(
+            matrix.swap([-1, 0].into(), [4, 0].into()),
+            Err(OutOfBoundsError {
+                address: MatrixAddress { x: -1, y: 0 }
+            })
+        );
+        assert_eq!(matrix, before);
+    }
+
+    proptest! {
+        #[test]
+        fn swap_matches_clone_test(width in 1usize..6, height in 1usize..6, a in (-1i32..7, -1i32..7), b in (-1i32..7, -1i32..7)) {
+            let (a, b) = (MatrixAddress { x: a.0, y: a.1 }, MatrixAddress { x: b.0, y: b.1 });
+            let original = Matrix::new(width, height, |address| address.y * 10 + address.x).unwrap();
+            let mut matrix = original.clone();
+            match (original.get(a), original.get(b)) {
+                (Some(&first), Some(&second)) => {
+                    matrix.swap(a, b).unwrap();
+                    assert_eq!(matrix[a], second);
+                    assert_eq!(matrix[b], first);
+                    matrix.swap(b, a).unwrap();
+                }
+                _ => assert!(matrix.swap(a, b).is_err()),
+            }
+            assert_eq!(matrix, original);
+        }
+    }
+}
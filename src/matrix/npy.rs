@@ -0,0 +1,327 @@
+use crate::binary::{BinaryElement, Endianness};
+use crate::matrix::Matrix;
+use crate::matrix_error::{DimensionMismatchError, MatrixError};
+use std::io::{ErrorKind, Read, Write};
+
+/// The bytes every `.npy` file starts with.
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The element types which can be read from a `.npy` file.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum DType {
+    Float32,
+    Float64,
+}
+
+/// The parsed header of a `.npy` file.
+struct Header {
+    dtype: DType,
+    endianness: Endianness,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+impl Matrix<f64> {
+    /// Reads a matrix from a 2D NumPy array in the `.npy` format, such as the output of numpy's
+    /// `save`. The first dimension of the array is the height of the matrix and the second its width.
+    ///
+    /// Little and big endian `float32` and `float64` arrays are supported, in either C or Fortran
+    /// order. Any bytes following the array are left unread.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader`: The source of the `.npy` file
+    ///
+    /// Returns: `Result<Matrix<f64>, MatrixError>`, An Err if the reader fails or ends early, the
+    /// header is malformed or has an unsupported dtype, or the array is not 2D.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+    /// let mut npy = Vec::new();
+    /// matrix.to_npy(&mut npy).unwrap();
+    /// assert_eq!(Matrix::from_npy(&npy[..]).unwrap(), matrix);
+    /// ```
+    pub fn from_npy<R: Read>(mut reader: R) -> Result<Matrix<f64>, MatrixError> {
+        let header = read_header(&mut reader)?;
+        let &[height, width] = header.shape.as_slice() else {
+            return Err(DimensionMismatchError {
+                message: format!("Expected a 2D array, got shape {:?}", header.shape),
+            }
+            .into());
+        };
+        let count = width
+            .checked_mul(height)
+            .ok_or_else(|| too_large(width, height))?;
+        let values = match header.dtype {
+            DType::Float32 => read_values::<f32, R>(reader, width, height, header.endianness)?
+                .into_iter()
+                .map(f64::from)
+                .collect(),
+            DType::Float64 => read_values::<f64, R>(reader, width, height, header.endianness)?,
+        };
+        let data = if header.fortran_order {
+            (0..count)
+                .map(|index| values[index % width * height + index / width])
+                .collect()
+        } else {
+            values
+        };
+        Ok(Matrix::from_parts(width, height, data))
+    }
+
+    /// Writes the matrix as a 2D little endian `float64` NumPy array in C order, in the `.npy`
+    /// version 1.0 format, the inverse of `Matrix::from_npy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer`: The destination of the `.npy` file
+    ///
+    /// Returns: `Result<(), MatrixError>`, An Err if the writer fails.
+    pub fn to_npy<W: Write>(&self, mut writer: W) -> Result<(), MatrixError> {
+        let mut header = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}",
+            self.height, self.width
+        );
+        // numpy pads the header with spaces and a newline so that the data is 64 byte aligned.
+        let unpadded = MAGIC.len() + 4 + header.len() + 1;
+        header.push_str(&" ".repeat(unpadded.next_multiple_of(64) - unpadded));
+        header.push('\n');
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header.len() + self.data.len() * 8);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        self.data
+            .iter()
+            .for_each(|value| value.write_bytes(Endianness::Little, &mut bytes));
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+}
+
+fn read_values<T: BinaryElement, R: Read>(
+    reader: R,
+    width: usize,
+    height: usize,
+    endianness: Endianness,
+) -> Result<Vec<T>, MatrixError> {
+    let length = width
+        .checked_mul(height)
+        .and_then(|count| count.checked_mul(T::SIZE))
+        .ok_or_else(|| too_large(width, height))?;
+    // Reading through `take` grows the buffer as bytes arrive, so that a corrupt header cannot
+    // cause a huge allocation up front.
+    let mut bytes = Vec::new();
+    reader.take(length as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < length {
+        return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into());
+    }
+    Ok(bytes
+        .chunks_exact(T::SIZE)
+        .map(|chunk| T::from_bytes(chunk, endianness))
+        .collect())
+}
+
+/// Reads the magic bytes, version and header dictionary of a `.npy` file.
+fn read_header<R: Read>(reader: &mut R) -> Result<Header, MatrixError> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[..6] != MAGIC {
+        return Err(malformed("Missing the magic bytes of a .npy file".into()));
+    }
+    let header_length = match preamble[6] {
+        1 => {
+            let mut length = [0u8; 2];
+            reader.read_exact(&mut length)?;
+            u16::from_le_bytes(length) as usize
+        }
+        2 | 3 => {
+            let mut length = [0u8; 4];
+            reader.read_exact(&mut length)?;
+            u32::from_le_bytes(length) as usize
+        }
+        major => return Err(malformed(format!("Unsupported .npy version {}", major))),
+    };
+    // Read through `take`, as the payload is, so that a corrupt length cannot cause a huge
+    // allocation up front.
+    let mut header = Vec::new();
+    reader
+        .by_ref()
+        .take(header_length as u64)
+        .read_to_end(&mut header)?;
+    if header.len() < header_length {
+        return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into());
+    }
+    let header = String::from_utf8(header)
+        .map_err(|_| malformed("The .npy header is not valid text".into()))?;
+
+    let descr = header_value(&header, "descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .or_else(|| descr.strip_prefix('"'))
+        .and_then(|descr| descr.get(..3))
+        .ok_or_else(|| malformed(format!("Malformed dtype in .npy header {}", header)))?;
+    let (endianness, dtype) = match descr {
+        "<f4" => (Endianness::Little, DType::Float32),
+        ">f4" => (Endianness::Big, DType::Float32),
+        "<f8" => (Endianness::Little, DType::Float64),
+        ">f8" => (Endianness::Big, DType::Float64),
+        _ => return Err(malformed(format!("Unsupported dtype {}", descr))),
+    };
+    let fortran_order = match header_value(&header, "fortran_order")? {
+        value if value.starts_with("True") => true,
+        value if value.starts_with("False") => false,
+        value => return Err(malformed(format!("Malformed fortran_order {}", value))),
+    };
+    let shape = header_value(&header, "shape")?
+        .strip_prefix('(')
+        .and_then(|shape| shape.split_once(')'))
+        .ok_or_else(|| malformed(format!("Malformed shape in .npy header {}", header)))?
+        .0
+        .split(',')
+        .map(str::trim)
+        .filter(|length| !length.is_empty())
+        .map(|length| {
+            length
+                .parse()
+                .map_err(|_| malformed(format!("Malformed shape length {}", length)))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Header {
+        dtype,
+        endianness,
+        fortran_order,
+        shape,
+    })
+}
+
+/// Finds the text following a key of the header dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, MatrixError> {
+    [format!("'{}':", key), format!("\"{}\":", key)]
+        .iter()
+        .find_map(|pattern| header.split_once(pattern.as_str()))
+        .map(|(_, value)| value.trim_start())
+        .ok_or_else(|| malformed(format!("The .npy header has no {}", key)))
+}
+
+fn too_large(width: usize, height: usize) -> MatrixError {
+    DimensionMismatchError {
+        message: format!("A {}x{} matrix is too large to read", width, height),
+    }
+    .into()
+}
+
+fn malformed(message: String) -> MatrixError {
+    MatrixError::Io {
+        kind: std::io::ErrorKind::InvalidData,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_error::MatrixError;
+    use proptest::proptest;
+    use std::io::ErrorKind;
+
+    fn npy(header: &str, body: &[u8]) -> Vec<u8> {
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(body);
+        bytes
+    }
+
+    #[test]
+    fn numpy_fixture_test() {
+        let matrix = Matrix::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let mut written = Vec::new();
+        matrix.to_npy(&mut written).unwrap();
+        let header = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }}{}\n",
+            " ".repeat(58)
+        );
+        let body = [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect::<Vec<_>>();
+        assert_eq!(written, npy(&header, &body));
+        assert_eq!(written.len(), 128 + body.len());
+
+        let header = "{'descr': '>f4', 'fortran_order': True, 'shape': (2, 3), }\n";
+        let body = [1.0f32, 4.0, 2.0, 5.0, 3.0, 6.0]
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect::<Vec<_>>();
+        assert_eq!(Matrix::from_npy(&npy(header, &body)[..]).unwrap(), matrix);
+    }
+
+    #[test]
+    fn unsupported_npy_test() {
+        let expect_invalid = |bytes: Vec<u8>| match Matrix::from_npy(&bytes[..]) {
+            Err(MatrixError::Io { kind, .. }) => assert_eq!(kind, ErrorKind::InvalidData),
+            other => panic!("Expected an invalid data error, got {:?}", other),
+        };
+        expect_invalid(npy(
+            "{'descr': '<i8', 'fortran_order': False, 'shape': (1, 1), }\n",
+            &[0; 8],
+        ));
+        expect_invalid(npy("{'descr': '<f8', 'shape': (1, 1), }\n", &[0; 8]));
+        expect_invalid(b"\x93NUMPX\x01\x00\x00\x00".to_vec());
+        for shape in ["()", "(4,)", "(1, 2, 2)"] {
+            let header = format!(
+                "{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}\n",
+                shape
+            );
+            assert!(matches!(
+                Matrix::from_npy(&npy(&header, &[0; 32])[..]),
+                Err(MatrixError::DimensionMismatch(_))
+            ));
+        }
+        let header = "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2), }\n";
+        match Matrix::from_npy(&npy(header, &[0; 31])[..]) {
+            Err(MatrixError::Io { kind, .. }) => assert_eq!(kind, ErrorKind::UnexpectedEof),
+            other => panic!("Expected an unexpected end of file error, got {:?}", other),
+        }
+        for shape in ["(4294967296, 4294967296)", "(4000000000000, 1000000)"] {
+            let header = format!(
+                "{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}\n",
+                shape
+            );
+            assert!(matches!(
+                Matrix::from_npy(&npy(&header, &[0; 32])[..]),
+                Err(MatrixError::DimensionMismatch(_))
+            ));
+        }
+        let header =
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (100000000, 100000000), }\n";
+        match Matrix::from_npy(&npy(header, &[0; 32])[..]) {
+            Err(MatrixError::Io { kind, .. }) => assert_eq!(kind, ErrorKind::UnexpectedEof),
+            other => panic!("Expected an unexpected end of file error, got {:?}", other),
+        }
+        let mut bogus_length = b"\x93NUMPY\x02\x00".to_vec();
+        bogus_length.extend_from_slice(&u32::MAX.to_le_bytes());
+        bogus_length.extend_from_slice(b"{'descr': '<f8', ");
+        match Matrix::from_npy(&bogus_length[..]) {
+            Err(MatrixError::Io { kind, .. }) => assert_eq!(kind, ErrorKind::UnexpectedEof),
+            other => panic!("Expected an unexpected end of file error, got {:?}", other),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip_test(width in 0usize..9, height in 0usize..9, seed in proptest::num::f64::NORMAL) {
+            let data = (0..width * height).map(|index| seed * index as f64).collect();
+            let matrix = Matrix::from_parts(width, height, data);
+            let mut written = Vec::new();
+            matrix.to_npy(&mut written).unwrap();
+            assert_eq!(written.len(), 128 + width * height * 8);
+            assert_eq!(Matrix::from_npy(&written[..]).unwrap(), matrix);
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::matrix_error::MatrixError;
+use crate::tensor::Tensor;
+
+/// A single recorded mutation of a matrix.
+/// A sequence of operations can be stored or sent elsewhere and replayed with `Matrix::apply_ops`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatrixOp<T> {
+    /// Sets the value at a single address.
+    Set { address: MatrixAddress, value: T },
+    /// Sets every value in the inclusive rectangle between the two corners.
+    FillRegion {
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+        value: T,
+    },
+    /// Swaps two entire rows.
+    SwapRows { a: usize, b: usize },
+    /// Copies the whole source matrix into the matrix, with the source's origin placed at `top_left`.
+    Blit {
+        top_left: MatrixAddress,
+        source: Matrix<T>,
+    },
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Applies a single operation to the matrix.
+    /// The operation is validated before anything is written, so on an Err the matrix is unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `op`: The operation to apply
+    ///
+    /// Returns: `Result<(), MatrixError>`, An Err if the operation touches addresses outside the matrix
+    /// or describes an inverted region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::{Matrix, MatrixOp};
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let mut matrix = Matrix::new(3, 3, |_| 0).unwrap();
+    /// matrix.apply_op(&MatrixOp::Set { address: MatrixAddress { x: 1, y: 2 }, value: 5 }).unwrap();
+    /// assert_eq!(matrix[(1, 2)], 5);
+    /// ```
+    pub fn apply_op(&mut self, op: &MatrixOp<T>) -> Result<(), MatrixError> {
+        match op {
+            MatrixOp::Set { address, value } => {
+                self.check_contains(*address)?;
+                self[*address] = value.clone();
+            }
+            MatrixOp::FillRegion {
+                top_left,
+                bottom_right,
+                value,
+            } => self.fill_region(*top_left, *bottom_right, value.clone())?,
+            MatrixOp::SwapRows { a, b } => {
+                for row in [*a, *b] {
+                    if row >= self.height {
+                        return Err(MatrixError::RowOutOfBounds {
+                            row,
+                            height: self.height,
+                        });
+                    }
+                }
+                self.swap_rows(*a, *b);
+            }
+            MatrixOp::Blit { source, .. } if source.data.is_empty() => {}
+            MatrixOp::Blit { top_left, source } => {
+                let bottom_right = *top_left + source.largest_contained_address();
+                self.check_region(*top_left, bottom_right)?;
+                source.address_value_iter().for_each(|(address, value)| {
+                    self[*top_left + address] = value.clone();
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies a sequence of operations to the matrix in order.
+    ///
+    /// Application stops at the first operation which fails and reports it: every operation before
+    /// the failed one stays applied, the failed operation changes nothing, and the remaining
+    /// operations are not attempted.
+    ///
+    /// # Arguments
+    ///
+    /// * `ops`: The operations to apply
+    ///
+    /// Returns: `Result<(), MatrixError>`, `MatrixError::OpFailed` holding the index of the failed
+    /// operation and the reason it failed.
+    pub fn apply_ops(&mut self, ops: &[MatrixOp<T>]) -> Result<(), MatrixError> {
+        ops.iter().enumerate().try_for_each(|(index, op)| {
+            self.apply_op(op).map_err(|error| MatrixError::OpFailed {
+                index,
+                error: Box::new(error),
+            })
+        })
+    }
+
+    /// Computes a list of operations which transforms this matrix into `newer`, suitable for sending
+    /// a small update instead of a whole matrix. Applying the result with `apply_ops` to a copy of this
+    /// matrix reproduces `newer` exactly.
+    ///
+    /// Changed cells are emitted row by row as `Set` operations, with horizontal runs of the same new
+    /// value coalesced into a single `FillRegion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `newer`: The matrix to transform this matrix into
+    ///
+    /// Returns: `Result<Vec<MatrixOp<T>>, MatrixError>`, An Err if the dimensions of the matrices differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let older = Matrix::new(10, 10, |_| 0).unwrap();
+    /// let mut newer = older.clone();
+    /// (2..8).for_each(|x| newer[(x, 3)] = 1);
+    /// let ops = older.diff_ops(&newer).unwrap();
+    /// assert_eq!(ops.len(), 1);
+    ///
+    /// let mut replayed = older.clone();
+    /// replayed.apply_ops(&ops).unwrap();
+    /// assert_eq!(replayed, newer);
+    /// ```
+    pub fn diff_ops(&self, newer: &Matrix<T>) -> Result<Vec<MatrixOp<T>>, MatrixError>
+    where
+        T: PartialEq,
+    {
+        self.check_same_dimensions(newer)?;
+        let mut ops = Vec::new();
+        for (y, (old_row, new_row)) in self
+            .data
+            .chunks(self.width.max(1))
+            .zip(newer.data.chunks(self.width.max(1)))
+            .enumerate()
+        {
+            let mut x = 0;
+            while x < self.width {
+                if old_row[x] == new_row[x] {
+                    x += 1;
+                    continue;
+                }
+                let value = &new_row[x];
+                let mut end = x;
+                while end + 1 < self.width && new_row[end + 1] == *value {
+                    end += 1;
+                }
+                while old_row[end] == new_row[end] {
+                    end -= 1;
+                }
+                let top_left = MatrixAddress {
+                    x: x as i32,
+                    y: y as i32,
+                };
+                if end == x {
+                    ops.push(MatrixOp::Set {
+                        address: top_left,
+                        value: value.clone(),
+                    });
+                } else {
+                    ops.push(MatrixOp::FillRegion {
+                        top_left,
+                        bottom_right: MatrixAddress {
+                            x: end as i32,
+                            y: y as i32,
+                        },
+                        value: value.clone(),
+                    });
+                }
+                x = end + 1;
+            }
+        }
+        Ok(ops)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{Matrix, MatrixOp};
+    use crate::matrix_address::MatrixAddress;
+    use crate::matrix_error::{MatrixError, OutOfBoundsError};
+    use proptest::proptest;
+
+    fn recorded_ops() -> Vec<MatrixOp<i32>> {
+        vec![
+            MatrixOp::Set {
+                address: MatrixAddress { x: 0, y: 0 },
+                value: 7,
+            },
+            MatrixOp::FillRegion {
+                top_left: MatrixAddress { x: 1, y: 1 },
+                bottom_right: MatrixAddress { x: 3, y: 2 },
+                value: -1,
+            },
+            MatrixOp::SwapRows { a: 0, b: 4 },
+            MatrixOp::Blit {
+                top_left: MatrixAddress { x: 2, y: 3 },
+                source: Matrix::new(3, 2, |address| 100 + address.x + 10 * address.y).unwrap(),
+            },
+            MatrixOp::SwapRows { a: 2, b: 2 },
+        ]
+    }
+
+    #[test]
+    fn replay_test() {
+        let (width, height) = (5, 5);
+        let fresh = Matrix::new(width, height, |address| {
+            address.y * width as i32 + address.x
+        })
+        .unwrap();
+
+        let mut direct = fresh.clone();
+        direct[(0, 0)] = 7;
+        for y in 1..=2 {
+            for x in 1..=3 {
+                direct[(x, y)] = -1;
+            }
+        }
+        for x in 0..width as i32 {
+            let temp = direct[(x, 0)];
+            direct[(x, 0)] = direct[(x, 4)];
+            direct[(x, 4)] = temp;
+        }
+        for y in 0..2 {
+            for x in 0..3 {
+                direct[(2 + x, 3 + y)] = 100 + x + 10 * y;
+            }
+        }
+
+        let mut replayed = fresh.clone();
+        replayed.apply_ops(&recorded_ops()).unwrap();
+        assert_eq!(replayed, direct);
+    }
+
+    #[test]
+    fn replay_failure_test() {
+        let fresh = Matrix::new(5, 5, |_| 0).unwrap();
+        let mut ops = recorded_ops();
+        ops.insert(
+            2,
+            MatrixOp::Set {
+                address: MatrixAddress { x: 5, y: 0 },
+                value: 1,
+            },
+        );
+
+        let mut replayed = fresh.clone();
+        let mut expected = fresh.clone();
+        expected.apply_ops(&ops[..2]).unwrap();
+        assert_eq!(
+            replayed.apply_ops(&ops),
+            Err(MatrixError::OpFailed {
+                index: 2,
+                error: Box::new(MatrixError::OutOfBounds(OutOfBoundsError {
+                    address: MatrixAddress { x: 5, y: 0 }
+                })),
+            })
+        );
+        assert_eq!(replayed, expected);
+    }
+
+    #[test]
+    fn invalid_op_test() {
+        let mut matrix = Matrix::new(4, 3, |address| address.x).unwrap();
+        let before = matrix.clone();
+        assert_eq!(
+            matrix.apply_op(&MatrixOp::SwapRows { a: 0, b: 3 }),
+            Err(MatrixError::RowOutOfBounds { row: 3, height: 3 })
+        );
+        assert!(matches!(
+            matrix.apply_op(&MatrixOp::FillRegion {
+                top_left: MatrixAddress { x: 2, y: 0 },
+                bottom_right: MatrixAddress { x: 1, y: 2 },
+                value: 0,
+            }),
+            Err(MatrixError::InvalidRegion { .. })
+        ));
+        assert!(matches!(
+            matrix.apply_op(&MatrixOp::Blit {
+                top_left: MatrixAddress { x: 2, y: 2 },
+                source: Matrix::new(2, 2, |_| 9).unwrap(),
+            }),
+            Err(MatrixError::OutOfBounds(_))
+        ));
+        assert_eq!(matrix, before);
+    }
+
+    #[test]
+    fn diff_ops_small_change_test() {
+        let (width, height) = (100, 100);
+        let older = Matrix::new(width, height, |address| (address.x * address.y) % 5).unwrap();
+        let mut newer = older.clone();
+        (10..60).for_each(|x| newer[(x, 20)] = 9);
+        newer[(3, 3)] = -1;
+        newer[(99, 99)] = -2;
+        let ops = older.diff_ops(&newer).unwrap();
+        assert!(ops.len() < 10);
+        assert!(ops.len() * 100 < width * height);
+        let mut replayed = older.clone();
+        replayed.apply_ops(&ops).unwrap();
+        assert_eq!(replayed, newer);
+
+        assert!(older.diff_ops(&older).unwrap().is_empty());
+        assert!(
+            older
+                .diff_ops(&Matrix::new(width, height + 1, |_| 0).unwrap())
+                .is_err()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn diff_ops_round_trip_test(
+            width in 1usize..12,
+            height in 1usize..12,
+            old_values in proptest::collection::vec(0u8..3, 144),
+            new_values in proptest::collection::vec(0u8..3, 144),
+        ) {
+            let older = Matrix::new(width, height, |address| old_values[(address.y * 12 + address.x) as usize]).unwrap();
+            let newer = Matrix::new(width, height, |address| new_values[(address.y * 12 + address.x) as usize]).unwrap();
+            let ops = older.diff_ops(&newer).unwrap();
+            let changed = older.data.iter().zip(&newer.data).filter(|(a, b)| a != b).count();
+            assert!(ops.len() <= changed);
+            let mut replayed = older.clone();
+            replayed.apply_ops(&ops).unwrap();
+            assert_eq!(replayed, newer);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_test() {
+        let ops = recorded_ops();
+        let json = serde_json::to_string(&ops).unwrap();
+        let decoded: Vec<MatrixOp<i32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, ops);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::matrix::Matrix;
+
+impl<T: Copy> Matrix<T> {
+    /// Creates a copy of the matrix rotated a quarter turn clockwise, so the value at `(x, y)` moves
+    /// to `(height - 1 - y, x)` and the width and height trade places.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.rotate_90_cw(),
+    ///     Matrix::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]).unwrap()
+    /// );
+    /// ```
+    pub fn rotate_90_cw(&self) -> Matrix<T> {
+        let height = self.height;
+        self.rearranged(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Creates a copy of the matrix rotated a quarter turn counterclockwise, so the value at `(x, y)`
+    /// moves to `(y, width - 1 - x)` and the width and height trade places.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.rotate_90_ccw(),
+    ///     Matrix::from_rows(vec![vec![3, 6], vec![2, 5], vec![1, 4]]).unwrap()
+    /// );
+    /// ```
+    pub fn rotate_90_ccw(&self) -> Matrix<T> {
+        let width = self.width;
+        self.rearranged(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Creates a copy of the matrix rotated a half turn, so the value at `(x, y)` moves to
+    /// `(width - 1 - x, height - 1 - y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(matrix.rotate_180(), Matrix::from_rows(vec![vec![6, 5, 4], vec![3, 2, 1]]).unwrap());
+    /// ```
+    pub fn rotate_180(&self) -> Matrix<T> {
+        let (width, height) = self.dimensions();
+        self.rearranged(width, height, |x, y| (width - 1 - x, height - 1 - y))
+    }
+
+    /// Creates a copy of the matrix mirrored left to right, so the order of the columns is reversed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(matrix.flip_horizontal(), Matrix::from_rows(vec![vec![3, 2, 1], vec![6, 5, 4]]).unwrap());
+    /// ```
+    pub fn flip_horizontal(&self) -> Matrix<T> {
+        let width = self.width;
+        self.rearranged(width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Creates a copy of the matrix mirrored top to bottom, so the order of the rows is reversed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(matrix.flip_vertical(), Matrix::from_rows(vec![vec![4, 5, 6], vec![1, 2, 3]]).unwrap());
+    /// ```
+    pub fn flip_vertical(&self) -> Matrix<T> {
+        let height = self.height;
+        self.rearranged(self.width, height, |x, y| (x, height - 1 - y))
+    }
+
+    /// Builds a `width`x`height` matrix whose value at each `(x, y)` is copied from the cell at
+    /// `source(x, y)` of this matrix.
+    fn rearranged<F>(&self, width: usize, height: usize, source: F) -> Matrix<T>
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let (source_x, source_y) = source(x, y);
+                data.push(self.data[source_y * self.width + source_x]);
+            }
+        }
+        Matrix::from_parts(width, height, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use proptest::proptest;
+
+    fn numbered(width: usize, height: usize) -> Matrix<usize> {
+        Matrix::new(width, height, |address| {
+            address.y as usize * width + address.x as usize
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn corners_test() {
+        let matrix = numbered(4, 3);
+        let (top_left, top_right, bottom_left, bottom_right) = (0, 3, 8, 11);
+
+        let rotated = matrix.rotate_90_cw();
+        assert_eq!((rotated.width, rotated.height), (3, 4));
+        assert_eq!(rotated[(2, 0)], top_left);
+        assert_eq!(rotated[(2, 3)], top_right);
+        assert_eq!(rotated[(0, 0)], bottom_left);
+        assert_eq!(rotated[(0, 3)], bottom_right);
+
+        let rotated = matrix.rotate_90_ccw();
+        assert_eq!((rotated.width, rotated.height), (3, 4));
+        assert_eq!(rotated[(0, 3)], top_left);
+        assert_eq!(rotated[(0, 0)], top_right);
+        assert_eq!(rotated[(2, 3)], bottom_left);
+        assert_eq!(rotated[(2, 0)], bottom_right);
+
+        let rotated = matrix.rotate_180();
+        assert_eq!(rotated[(3, 2)], top_left);
+        assert_eq!(rotated[(0, 0)], bottom_right);
+
+        assert_eq!(matrix.flip_horizontal()[(3, 0)], top_left);
+        assert_eq!(matrix.flip_horizontal()[(0, 2)], bottom_right);
+        assert_eq!(matrix.flip_vertical()[(0, 2)], top_left);
+        assert_eq!(matrix.flip_vertical()[(3, 0)], bottom_right);
+    }
+
+    #[test]
+    fn empty_test() {
+        let matrix = Matrix::<usize>::from_rows(vec![vec![]; 3]).unwrap();
+        let rotated = matrix.rotate_90_cw();
+        assert_eq!((rotated.width, rotated.height), (3, 0));
+        assert_eq!(matrix.flip_vertical(), matrix);
+    }
+
+    proptest! {
+        #[test]
+        fn identities_test(width in 1usize..8, height in 1usize..8) {
+            let matrix = numbered(width, height);
+            assert_eq!(matrix.rotate_90_cw().rotate_90_cw().rotate_90_cw().rotate_90_cw(), matrix);
+            assert_eq!(matrix.rotate_90_ccw().rotate_90_cw(), matrix);
+            assert_eq!(matrix.rotate_90_cw().rotate_90_cw(), matrix.rotate_180());
+            assert_eq!(matrix.flip_horizontal().flip_horizontal(), matrix);
+            assert_eq!(matrix.flip_vertical().flip_vertical(), matrix);
+            assert_eq!(matrix.flip_horizontal().flip_vertical(), matrix.rotate_180());
+        }
+    }
+}
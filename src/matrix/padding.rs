@@ -0,0 +1,187 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+
+/// How values are chosen for addresses outside a matrix, such as the border added by `pad_with`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum EdgeMode<T> {
+    /// Every address outside the matrix has the given value.
+    Constant(T),
+    /// An address outside the matrix has the value of the nearest cell on its edge.
+    Clamp,
+    /// The matrix repeats in every direction, so an address past one edge continues from the
+    /// opposite edge.
+    Wrap,
+}
+
+impl<T> EdgeMode<T> {
+    /// Returns the value this edge mode gives an address, which may be outside the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the address is outside a matrix without any cells and the mode is not `Constant`.
+    pub(crate) fn value_at<'a>(&'a self, matrix: &'a Matrix<T>, address: MatrixAddress) -> &'a T {
+        if let Some(index) = matrix.index_of(address) {
+            return &matrix.data[index];
+        }
+        let (width, height) = (matrix.width as i32, matrix.height as i32);
+        let address = match self {
+            EdgeMode::Constant(value) => return value,
+            _ if width == 0 || height == 0 => {
+                panic!(
+                    "Cannot take edge values from a {}x{} matrix",
+                    matrix.width, matrix.height
+                )
+            }
+            EdgeMode::Clamp => MatrixAddress {
+                x: address.x.clamp(0, width - 1),
+                y: address.y.clamp(0, height - 1),
+            },
+            EdgeMode::Wrap => MatrixAddress {
+                x: address.x.rem_euclid(width),
+                y: address.y.rem_euclid(height),
+            },
+        };
+        &matrix[address]
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Creates a copy of the matrix surrounded by a border of `fill` values.
+    ///
+    /// # Arguments
+    ///
+    /// * `thickness`: The number of cells the border adds to each side
+    /// * `fill`: The value of every border cell
+    ///
+    /// Returns: `Matrix<T>`, of size `(width + 2 * thickness)x(height + 2 * thickness)`,
+    /// with the original values starting at `(thickness, thickness)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.pad(1, 0),
+    ///     Matrix::from_rows(vec![vec![0, 0, 0, 0], vec![0, 1, 2, 0], vec![0, 0, 0, 0]]).unwrap()
+    /// );
+    /// ```
+    pub fn pad(&self, thickness: usize, fill: T) -> Matrix<T> {
+        self.pad_with(thickness, &EdgeMode::Constant(fill))
+    }
+
+    /// Creates a copy of the matrix surrounded by a border whose values are chosen by an edge mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `thickness`: The number of cells the border adds to each side
+    /// * `edge_mode`: How the values of the border are chosen
+    ///
+    /// Returns: `Matrix<T>`, of size `(width + 2 * thickness)x(height + 2 * thickness)`,
+    /// with the original values starting at `(thickness, thickness)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix has no cells, the thickness is not zero and the edge mode is not `Constant`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::{EdgeMode, Matrix};
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.pad_with(1, &EdgeMode::Clamp),
+    ///     Matrix::from_rows(vec![vec![1, 1, 2, 3, 3]; 3]).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     matrix.pad_with(1, &EdgeMode::Wrap),
+    ///     Matrix::from_rows(vec![vec![3, 1, 2, 3, 1]; 3]).unwrap()
+    /// );
+    /// ```
+    pub fn pad_with(&self, thickness: usize, edge_mode: &EdgeMode<T>) -> Matrix<T> {
+        let (width, height) = (self.width + 2 * thickness, self.height + 2 * thickness);
+        let offset = thickness as i32;
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                let source = MatrixAddress {
+                    x: x - offset,
+                    y: y - offset,
+                };
+                data.push(edge_mode.value_at(self, source).clone());
+            }
+        }
+        Matrix::from_parts(width, height, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{EdgeMode, Matrix};
+
+    fn asymmetric() -> Matrix<i32> {
+        Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn pad_constant_test() {
+        let padded = asymmetric().pad(2, 0);
+        let mut expected = vec![vec![0; 7]; 6];
+        expected[2][2..5].copy_from_slice(&[1, 2, 3]);
+        expected[3][2..5].copy_from_slice(&[4, 5, 6]);
+        assert_eq!(padded, Matrix::from_rows(expected).unwrap());
+    }
+
+    #[test]
+    fn pad_clamp_test() {
+        let padded = asymmetric().pad_with(2, &EdgeMode::Clamp);
+        let top = vec![1, 1, 1, 2, 3, 3, 3];
+        let bottom = vec![4, 4, 4, 5, 6, 6, 6];
+        let expected = vec![
+            top.clone(),
+            top.clone(),
+            top,
+            bottom.clone(),
+            bottom.clone(),
+            bottom,
+        ];
+        assert_eq!(padded, Matrix::from_rows(expected).unwrap());
+    }
+
+    #[test]
+    fn pad_wrap_test() {
+        let padded = asymmetric().pad_with(2, &EdgeMode::Wrap);
+        let top = vec![2, 3, 1, 2, 3, 1, 2];
+        let bottom = vec![5, 6, 4, 5, 6, 4, 5];
+        let expected = vec![
+            top.clone(),
+            bottom.clone(),
+            top.clone(),
+            bottom.clone(),
+            top,
+            bottom,
+        ];
+        assert_eq!(padded, Matrix::from_rows(expected).unwrap());
+    }
+
+    #[test]
+    fn pad_zero_thickness_test() {
+        assert_eq!(asymmetric().pad_with(0, &EdgeMode::Wrap), asymmetric());
+        let empty = Matrix::<i32>::from_rows(vec![]).unwrap();
+        assert_eq!(empty.pad_with(0, &EdgeMode::Clamp), empty);
+        assert_eq!(
+            empty.pad(1, 7),
+            Matrix::from_rows(vec![vec![7, 7]; 2]).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot take edge values from a 0x0 matrix")]
+    fn pad_empty_clamp_test() {
+        Matrix::<i32>::from_rows(vec![])
+            .unwrap()
+            .pad_with(1, &EdgeMode::Clamp);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use rayon::iter::Either;
+use rayon::prelude::*;
+
+/// The number of values each parallel task reduces sequentially before the partial results are
+/// combined.
+const CHUNK_SIZE: usize = 1024;
+
+impl<T: Sync> Matrix<T> {
+    /// Converts every value into a new matrix of the same dimensions, spreading the work over the
+    /// rayon thread pool. The result is the same as converting the values one after another.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: Converts a value
+    ///
+    /// Returns: `Matrix<U>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::new(300, 200, |address| address.x - address.y).unwrap();
+    /// let squares = matrix.par_map(|value| value * value);
+    /// assert_eq!(squares[(10, 4)], 36);
+    /// ```
+    pub fn par_map<U: Send, F>(&self, f: F) -> Matrix<U>
+    where
+        F: Fn(&T) -> U + Send + Sync,
+    {
+        Matrix::from_parts(
+            self.width,
+            self.height,
+            self.data.par_iter().map(f).collect(),
+        )
+    }
+
+    /// Creates a parallel iterator over every value of the matrix together with its address. As an
+    /// indexed parallel iterator, collecting it keeps the row-major order of `address_value_iter`.
+    ///
+    /// Returns: `impl IndexedParallelIterator<Item = (MatrixAddress, &T)>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::new(50, 40, |address| address.x * address.y).unwrap();
+    /// let diagonal_sum = matrix
+    ///     .par_address_value_iter()
+    ///     .filter(|(address, _)| address.x == address.y)
+    ///     .map(|(_, value)| *value)
+    ///     .sum::<i32>();
+    /// assert_eq!(diagonal_sum, (0..40).map(|i| i * i).sum());
+    /// ```
+    pub fn par_address_value_iter(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = (MatrixAddress, &T)> {
+        self.data
+            .par_iter()
+            .enumerate()
+            .map(|(index, value)| (self.address_of_index(index), value))
+    }
+}
+
+impl<T: Send> Matrix<T> {
+    /// Changes every value of the matrix in place, spreading the work over the rayon thread pool.
+    /// The result is the same as `apply` with the same function.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: Changes a value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut grid = Matrix::new(800, 600, |address| address.x as f64).unwrap();
+    /// grid.par_apply(|value| *value = value.sqrt());
+    /// assert_eq!(grid[(49, 7)], 7.0);
+    /// ```
+    pub fn par_apply<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Send + Sync,
+    {
+        self.data.par_iter_mut().for_each(f);
+    }
+
+    /// Creates a parallel iterator over the rows of the matrix as disjoint mutable slices, from top
+    /// to bottom, so that each row can be worked on by a different thread.
+    ///
+    /// Returns: `impl IndexedParallelIterator<Item = &mut [T]>`, with one item per row, even for a
+    /// matrix of zero width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(4, 3, |_| 1u64).unwrap();
+    /// matrix.par_rows_mut().for_each(|row| {
+    ///     for x in 1..row.len() {
+    ///         row[x] += row[x - 1];
+    ///     }
+    /// });
+    /// assert_eq!(matrix.row(2), Some(&[1, 2, 3, 4][..]));
+    /// ```
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]> {
+        if self.width == 0 {
+            // Chunks cannot be empty, so the rows of a matrix without columns are made up.
+            Either::Left(
+                (0..self.height)
+                    .into_par_iter()
+                    .map(|_| <&mut [T]>::default()),
+            )
+        } else {
+            Either::Right(self.data.par_chunks_mut(self.width))
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync> Matrix<T> {
+    /// Reduces every value of the matrix in parallel, with a result which is bit-identical across
+    /// runs and thread pool sizes even when `op` is not associative, such as floating-point addition.
+    ///
+    /// The values are split into fixed chunks of 1024 in row-major order, and each chunk is folded
+    /// from `identity` from left to right. The partial results are then combined pairwise, first
+    /// with second and third with fourth, one tree level at a time until one value remains. None of
+    /// these steps depends on how the work is scheduled.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: The starting value of each chunk, which should not change a value it is combined with
+    /// * `op`: Combines two values
+    ///
+    /// Returns: `T`, `identity` if the matrix has no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::new(100, 100, |address| (address.x * address.y) as u64).unwrap();
+    /// assert_eq!(matrix.par_reduce_deterministic(0, |a, b| a + b), 24502500);
+    /// ```
+    pub fn par_reduce_deterministic<F>(&self, identity: T, op: F) -> T
+    where
+        F: Fn(T, T) -> T + Sync,
+    {
+        let mut partials = self
+            .data
+            .par_chunks(CHUNK_SIZE)
+            .map(|chunk| chunk.iter().cloned().fold(identity.clone(), &op))
+            .collect::<Vec<T>>();
+        while partials.len() > 1 {
+            partials = partials
+                .into_par_iter()
+                .chunks(2)
+                .map(|pair| pair.into_iter().reduce(&op).unwrap())
+                .collect();
+        }
+        partials.pop().unwrap_or(identity)
+    }
+}
+
+impl Matrix<f64> {
+    /// Sums every value of the matrix in parallel with `par_reduce_deterministic`, so the result is
+    /// bit-identical regardless of the number of threads.
+    ///
+    /// Returns: `f64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::new(3, 2, |address| address.x as f64 + 0.5).unwrap();
+    /// assert_eq!(matrix.par_sum_f64_deterministic(), 9.0);
+    /// ```
+    pub fn par_sum_f64_deterministic(&self) -> f64 {
+        self.par_reduce_deterministic(0.0, |a, b| a + b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::tensor::Tensor;
+    use rayon::ThreadPoolBuilder;
+    use rayon::prelude::*;
+
+    fn kahan_sum(values: impl Iterator<Item = f64>) -> f64 {
+        let (mut sum, mut compensation) = (0.0, 0.0);
+        for value in values {
+            let corrected = value - compensation;
+            let next = sum + corrected;
+            compensation = (next - sum) - corrected;
+            sum = next;
+        }
+        sum
+    }
+
+    #[test]
+    fn par_sum_thread_count_test() {
+        let matrix = Matrix::new(317, 211, |address| {
+            let seed = (address.x * 7919 + address.y * 104729) as f64;
+            seed.sin() * 10f64.powi(address.x % 13 - 6)
+        })
+        .unwrap();
+        let sums = [1, 2, 3, 4, 8, 16].map(|threads| {
+            ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap()
+                .install(|| matrix.par_sum_f64_deterministic())
+        });
+        assert!(sums.iter().all(|sum| sum.to_bits() == sums[0].to_bits()));
+        let reference = kahan_sum(matrix.iter().copied());
+        assert!((sums[0] - reference).abs() <= 1e-9 * reference.abs().max(1.0));
+    }
+
+    #[test]
+    fn par_map_matches_sequential_test() {
+        let matrix = Matrix::new(517, 389, |address| {
+            (address.x as f64 * 0.37 - address.y as f64).sin()
+        })
+        .unwrap();
+        let f = |value: &f64| (value * 1e3).round() as i64 ^ 0x55;
+        let sequential = matrix.clone().transform(|_, value| f(value));
+        for threads in [1, 3, 8] {
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let (mapped, pairs) = pool.install(|| {
+                (
+                    matrix.par_map(f),
+                    matrix.par_address_value_iter().collect::<Vec<_>>(),
+                )
+            });
+            assert_eq!(mapped, sequential);
+            assert!(pairs.into_iter().eq(matrix.address_value_iter()));
+        }
+        let empty = Matrix::<u8>::from_rows(vec![vec![]; 4]).unwrap();
+        assert_eq!(empty.par_map(|value| *value as u32).dimensions(), (0, 4));
+        assert_eq!(empty.par_address_value_iter().count(), 0);
+    }
+
+    #[test]
+    fn par_apply_matches_sequential_test() {
+        let original = Matrix::new(613, 257, |address| {
+            (address.x * 7919 + address.y * 104729) as f64 / 3.0
+        })
+        .unwrap();
+        let f = |value: &mut f64| *value = (*value).sqrt().mul_add(1.5, -0.25);
+        let mut sequential = original.clone();
+        sequential.apply(f);
+        for threads in [1, 3, 8] {
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let mut parallel = original.clone();
+            let mut by_rows = original.clone();
+            pool.install(|| {
+                parallel.par_apply(f);
+                by_rows
+                    .par_rows_mut()
+                    .for_each(|row| row.iter_mut().for_each(f));
+            });
+            assert_eq!(parallel, sequential);
+            assert_eq!(by_rows, sequential);
+        }
+
+        let mut matrix = Matrix::new(5, 4, |address| address.y).unwrap();
+        let mut rows = Vec::new();
+        matrix
+            .par_rows_mut()
+            .map(|row| row.to_vec())
+            .collect_into_vec(&mut rows);
+        assert_eq!(rows, matrix.rows().map(<[i32]>::to_vec).collect::<Vec<_>>());
+        let mut empty = Matrix::<i32>::from_rows(vec![vec![]; 3]).unwrap();
+        assert_eq!(empty.par_rows_mut().len(), 3);
+        assert!(empty.par_rows_mut().all(|row| row.is_empty()));
+    }
+
+    #[test]
+    fn par_reduce_small_test() {
+        let empty = Matrix::<f64>::from_rows(vec![]).unwrap();
+        assert_eq!(empty.par_sum_f64_deterministic(), 0.0);
+        let single = Matrix::from_rows(vec![vec![2.5]]).unwrap();
+        assert_eq!(single.par_sum_f64_deterministic(), 2.5);
+        let matrix = Matrix::new(1500, 3, |address| address.x + address.y).unwrap();
+        assert_eq!(
+            matrix.par_reduce_deterministic(i32::MIN, |a, b| a.max(b)),
+            1501
+        );
+    }
+}
@@ -0,0 +1,502 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use std::mem::size_of;
+
+/// A matrix stored as a tree of blocks, where each block is halved into four quadrants until it can
+/// be represented by a single value.
+///
+/// Large regions of a mostly uniform map collapse into single leaves, which saves memory and lets
+/// region queries report whole uniform blocks instead of every cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuadTree<T> {
+    width: usize,
+    height: usize,
+    root: QuadNode<T>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum QuadNode<T> {
+    /// A quadrant without any cells, produced when halving a block of width or height one.
+    Empty,
+    Leaf(T),
+    /// The top left, top right, bottom left and bottom right quadrants.
+    Split(Box<[QuadNode<T>; 4]>),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Block {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Block {
+    /// The top left, top right, bottom left and bottom right quadrants. The left and top quadrants
+    /// take the extra column and row of an odd width or height.
+    fn quadrants(self) -> [Block; 4] {
+        let (left, top) = (self.width.div_ceil(2), self.height.div_ceil(2));
+        let (right, bottom) = (self.width - left, self.height - top);
+        [
+            Block {
+                width: left,
+                height: top,
+                ..self
+            },
+            Block {
+                x: self.x + left,
+                width: right,
+                height: top,
+                ..self
+            },
+            Block {
+                y: self.y + top,
+                width: left,
+                height: bottom,
+                ..self
+            },
+            Block {
+                x: self.x + left,
+                y: self.y + top,
+                width: right,
+                height: bottom,
+            },
+        ]
+    }
+
+    /// The index of the quadrant containing the cell, which must be contained in the block.
+    fn quadrant_of(self, x: usize, y: usize) -> usize {
+        let (left, top) = (self.width.div_ceil(2), self.height.div_ceil(2));
+        usize::from(x >= self.x + left) + 2 * usize::from(y >= self.y + top)
+    }
+
+    fn intersection(self, other: Block) -> Option<Block> {
+        let (x, y) = (self.x.max(other.x), self.y.max(other.y));
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        (x < right && y < bottom).then(|| Block {
+            x,
+            y,
+            width: right - x,
+            height: bottom - y,
+        })
+    }
+
+    fn corners(self) -> (MatrixAddress, MatrixAddress) {
+        (
+            MatrixAddress {
+                x: self.x as i32,
+                y: self.y as i32,
+            },
+            MatrixAddress {
+                x: (self.x + self.width - 1) as i32,
+                y: (self.y + self.height - 1) as i32,
+            },
+        )
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Builds a quadtree of the matrix. Each block is halved into four quadrants, and once every
+    /// quadrant of a block is a single value the quadrant values are passed to `merge`, which decides
+    /// whether the whole block can be represented by one value.
+    ///
+    /// # Arguments
+    ///
+    /// * `merge`: Given the values of the two to four quadrants of a block, returns the value
+    ///   representing the whole block, or None if the block must stay split
+    ///
+    /// Returns: `QuadTree<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let map = Matrix::new(64, 64, |address| address.x >= 32).unwrap();
+    /// let tree = map.to_quadtree(|values| {
+    ///     values.iter().all(|value| value == &values[0]).then(|| *values[0])
+    /// });
+    /// assert_eq!(tree.leaf_count(), 4);
+    /// assert_eq!(tree.to_matrix(), map);
+    /// ```
+    pub fn to_quadtree<F>(&self, merge: F) -> QuadTree<T>
+    where
+        F: Fn(&[&T]) -> Option<T>,
+    {
+        let block = Block {
+            x: 0,
+            y: 0,
+            width: self.width,
+            height: self.height,
+        };
+        QuadTree {
+            width: self.width,
+            height: self.height,
+            root: self.quadtree_node(block, &merge),
+        }
+    }
+
+    fn quadtree_node<F>(&self, block: Block, merge: &F) -> QuadNode<T>
+    where
+        F: Fn(&[&T]) -> Option<T>,
+    {
+        match block.width * block.height {
+            0 => return QuadNode::Empty,
+            1 => return QuadNode::Leaf(self.data[block.y * self.width + block.x].clone()),
+            _ => {}
+        }
+        let children = block
+            .quadrants()
+            .map(|quadrant| self.quadtree_node(quadrant, merge));
+        let mut values = Vec::with_capacity(4);
+        for child in &children {
+            match child {
+                QuadNode::Empty => {}
+                QuadNode::Leaf(value) => values.push(value),
+                QuadNode::Split(_) => return QuadNode::Split(Box::new(children)),
+            }
+        }
+        match merge(&values) {
+            Some(value) => QuadNode::Leaf(value),
+            None => QuadNode::Split(Box::new(children)),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Matrix<T> {
+    /// Builds a quadtree of the matrix which only collapses blocks whose values are all equal, so
+    /// that no information is lost.
+    ///
+    /// Returns: `QuadTree<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let map = Matrix::new(5, 3, |address| address.y == 2).unwrap();
+    /// assert_eq!(map.to_uniform_quadtree().to_matrix(), map);
+    /// ```
+    pub fn to_uniform_quadtree(&self) -> QuadTree<T> {
+        self.to_quadtree(|values| {
+            values
+                .iter()
+                .all(|value| value == &values[0])
+                .then(|| values[0].clone())
+        })
+    }
+}
+
+impl<T> QuadTree<T> {
+    /// The width and height of the matrix the tree was built from.
+    ///
+    /// Returns: `(usize, usize)`, as `(width, height)`
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Gets the value of a cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `address`: The address of the cell
+    ///
+    /// Returns: `Option<&T>`, None if the address is not contained in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let tree = Matrix::new(4, 4, |address| address.y / 2).unwrap().to_uniform_quadtree();
+    /// assert_eq!(tree.get(MatrixAddress { x: 3, y: 2 }), Some(&1));
+    /// assert_eq!(tree.get(MatrixAddress { x: 4, y: 2 }), None);
+    /// ```
+    pub fn get(&self, address: MatrixAddress) -> Option<&T> {
+        if address.x < 0 || address.y < 0 {
+            return None;
+        }
+        let (x, y) = (address.x as usize, address.y as usize);
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let mut block = self.block();
+        let mut node = &self.root;
+        loop {
+            match node {
+                QuadNode::Empty => return None,
+                QuadNode::Leaf(value) => return Some(value),
+                QuadNode::Split(children) => {
+                    let quadrant = block.quadrant_of(x, y);
+                    block = block.quadrants()[quadrant];
+                    node = &children[quadrant];
+                }
+            }
+        }
+    }
+
+    /// Finds the uniform blocks overlapping a region. Each block is clipped to the region, and the
+    /// parts of the region outside of the tree are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `top_left`: The top left corner of the region, inclusive
+    /// * `bottom_right`: The bottom right corner of the region, inclusive
+    ///
+    /// Returns: `impl Iterator<Item = (MatrixAddress, MatrixAddress, &T)>`, The top left and bottom
+    /// right corners of each clipped block together with its value. The blocks cover every cell of
+    /// the region exactly once, and no blocks are yielded if the corners are inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let tree = Matrix::new(8, 8, |address| address.x < 4).unwrap().to_uniform_quadtree();
+    /// let blocks = tree
+    ///     .region_query(MatrixAddress { x: 2, y: 0 }, MatrixAddress { x: 5, y: 1 })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     blocks,
+    ///     vec![
+    ///         (MatrixAddress { x: 2, y: 0 }, MatrixAddress { x: 3, y: 1 }, &true),
+    ///         (MatrixAddress { x: 4, y: 0 }, MatrixAddress { x: 5, y: 1 }, &false),
+    ///     ]
+    /// );
+    /// ```
+    pub fn region_query(
+        &self,
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+    ) -> impl Iterator<Item = (MatrixAddress, MatrixAddress, &T)> {
+        let query = (top_left.x <= bottom_right.x && top_left.y <= bottom_right.y)
+            .then(|| {
+                let x = top_left.x.max(0) as usize;
+                let y = top_left.y.max(0) as usize;
+                let right = (i64::from(bottom_right.x.max(-1)) + 1) as usize;
+                let bottom = (i64::from(bottom_right.y.max(-1)) + 1) as usize;
+                Block {
+                    x,
+                    y,
+                    width: right.saturating_sub(x),
+                    height: bottom.saturating_sub(y),
+                }
+            })
+            .and_then(|query| self.block().intersection(query));
+        let mut stack = query
+            .map(|query| vec![(&self.root, self.block(), query)])
+            .unwrap_or_default();
+        std::iter::from_fn(move || {
+            while let Some((node, block, query)) = stack.pop() {
+                match node {
+                    QuadNode::Empty => {}
+                    QuadNode::Leaf(value) => {
+                        let (top_left, bottom_right) = query.corners();
+                        return Some((top_left, bottom_right, value));
+                    }
+                    QuadNode::Split(children) => {
+                        for (child, quadrant) in children.iter().zip(block.quadrants()).rev() {
+                            if let Some(clipped) = quadrant.intersection(query) {
+                                stack.push((child, quadrant, clipped));
+                            }
+                        }
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    /// The number of single value blocks in the tree.
+    ///
+    /// Returns: `usize`, between 1 and `width * height` for a tree with any cells.
+    pub fn leaf_count(&self) -> usize {
+        let mut count = 0;
+        let mut stack = vec![&self.root];
+        while let Some(node) = stack.pop() {
+            match node {
+                QuadNode::Empty => {}
+                QuadNode::Leaf(_) => count += 1,
+                QuadNode::Split(children) => stack.extend(children.iter()),
+            }
+        }
+        count
+    }
+
+    /// The number of bytes taken by the tree and its nodes. Memory owned by the values themselves,
+    /// such as the buffer of a `String`, is not included.
+    ///
+    /// Returns: `usize`, comparable to `size_of::<Matrix<T>>() + matrix.len() * size_of::<T>()`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let map = Matrix::new(256, 256, |address| address.x < 128 && address.y < 64).unwrap();
+    /// assert!(map.to_uniform_quadtree().memory_usage() < map.len() / 4);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        let mut bytes = size_of::<Self>();
+        let mut stack = vec![&self.root];
+        while let Some(node) = stack.pop() {
+            if let QuadNode::Split(children) = node {
+                bytes += size_of::<[QuadNode<T>; 4]>();
+                stack.extend(children.iter());
+            }
+        }
+        bytes
+    }
+
+    /// Expands the tree back into a matrix, copying the value of each block into all of its cells.
+    ///
+    /// Returns: `Matrix<T>`
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.width * self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let address = MatrixAddress {
+                    x: x as i32,
+                    y: y as i32,
+                };
+                data.push(self.get(address).unwrap().clone());
+            }
+        }
+        Matrix::from_parts(self.width, self.height, data)
+    }
+
+    fn block(&self) -> Block {
+        Block {
+            x: 0,
+            y: 0,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_address::MatrixAddress;
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    #[test]
+    fn checkerboard_test() {
+        let board = Matrix::new(7, 5, |address| (address.x + address.y) % 2).unwrap();
+        let tree = board.to_uniform_quadtree();
+        assert_eq!(tree.leaf_count(), board.len());
+        assert_eq!(tree.to_matrix(), board);
+
+        let coarse = Matrix::new(16, 16, |address| (address.x / 4 + address.y / 4) % 2).unwrap();
+        let tree = coarse.to_uniform_quadtree();
+        assert_eq!(tree.leaf_count(), 16);
+        assert_eq!(tree.to_matrix(), coarse);
+    }
+
+    #[test]
+    fn degenerate_dimensions_test() {
+        for (width, height) in [(0, 0), (3, 0), (0, 3)] {
+            let empty = Matrix::<u8>::from_flat_vec(width, height, vec![]).unwrap();
+            let tree = empty.to_uniform_quadtree();
+            assert_eq!(tree.leaf_count(), 0);
+            assert_eq!(tree.get([0, 0].into()), None);
+            assert_eq!(tree.region_query([0, 0].into(), [5, 5].into()).count(), 0);
+            assert_eq!(tree.to_matrix(), empty);
+        }
+        let column = Matrix::from_rows(vec![vec![1], vec![1], vec![2]]).unwrap();
+        let tree = column.to_uniform_quadtree();
+        assert_eq!(tree.leaf_count(), 2);
+        assert_eq!(tree.to_matrix(), column);
+    }
+
+    #[test]
+    fn region_query_edge_of_coordinates_test() {
+        let tree = Matrix::new(4, 3, |address| address.x < 2)
+            .unwrap()
+            .to_uniform_quadtree();
+        let corner = MatrixAddress {
+            x: i32::MAX,
+            y: i32::MAX,
+        };
+        let covered = |top_left: MatrixAddress| {
+            tree.region_query(top_left, corner)
+                .map(|(top_left, bottom_right, _)| {
+                    (bottom_right.x - top_left.x + 1) * (bottom_right.y - top_left.y + 1)
+                })
+                .sum::<i32>()
+        };
+        assert_eq!(covered([1, 1].into()), 3 * 2);
+        assert_eq!(covered([i32::MIN, i32::MIN].into()), 4 * 3);
+    }
+
+    #[test]
+    fn lossy_merge_test() {
+        let gradient = Matrix::new(8, 8, |address| address.x as f64 * 0.01).unwrap();
+        let tree = gradient.to_quadtree(|values| {
+            let low = values.iter().copied().fold(f64::INFINITY, |a, &b| a.min(b));
+            let high = values
+                .iter()
+                .copied()
+                .fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+            (high - low <= 0.02).then_some((high + low) / 2.0)
+        });
+        assert!(tree.leaf_count() < gradient.len());
+        for (address, value) in gradient.address_value_iter() {
+            assert!((tree.get(address).unwrap() - value).abs() <= 0.02);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn filled_rectangles_test(
+            width in 0usize..20,
+            height in 0usize..20,
+            rectangles in proptest::collection::vec((0i32..20, 0i32..20, 0i32..20, 0i32..20, 0u8..4), 0..5),
+            query in (-3i32..23, -3i32..23, -3i32..23, -3i32..23),
+        ) {
+            let mut map = Matrix::from_flat_vec(width, height, vec![0u8; width * height]).unwrap();
+            for (x0, y0, x1, y1, value) in rectangles {
+                for address in map.address_iter().collect::<Vec<_>>() {
+                    if (x0.min(x1)..=x0.max(x1)).contains(&address.x)
+                        && (y0.min(y1)..=y0.max(y1)).contains(&address.y) {
+                        map[address] = value;
+                    }
+                }
+            }
+            let tree = map.to_uniform_quadtree();
+            assert!(tree.leaf_count() <= map.len());
+            assert_eq!(tree.to_matrix(), map);
+
+            let (top_left, bottom_right) = (
+                MatrixAddress { x: query.0, y: query.1 },
+                MatrixAddress { x: query.2, y: query.3 },
+            );
+            let mut covered = Vec::new();
+            for (block_top_left, block_bottom_right, value) in tree.region_query(top_left, bottom_right) {
+                for y in block_top_left.y..=block_bottom_right.y {
+                    for x in block_top_left.x..=block_bottom_right.x {
+                        assert_eq!(&map[(x, y)], value);
+                        covered.push(MatrixAddress { x, y });
+                    }
+                }
+            }
+            covered.sort_by_key(|address| (address.y, address.x));
+            let expected = map
+                .address_iter()
+                .filter(|address| {
+                    (top_left.x..=bottom_right.x).contains(&address.x)
+                        && (top_left.y..=bottom_right.y).contains(&address.y)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(covered, expected);
+        }
+    }
+}
@@ -0,0 +1,559 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::matrix_error::OutOfBoundsError;
+use crate::tensor::Tensor;
+use std::fmt::{Display, Write};
+
+/// A palette for `Matrix::to_ascii_art`, from the lowest intensity to the highest.
+pub const DEFAULT_PALETTE: &[char] = &[' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+impl<T> Matrix<T> {
+    /// Renders the matrix as the body of a LaTeX matrix environment, such as `pmatrix` or `bmatrix`.
+    /// Values are separated by ` & ` and every row is ended by ` \\` and a newline.
+    ///
+    /// # Arguments
+    ///
+    /// * `env`: The name of the environment enclosing the rows
+    ///
+    /// Returns: `String`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 0], vec![-2, 3]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.to_latex("bmatrix"),
+    ///     "\\begin{bmatrix}\n1 & 0 \\\\\n-2 & 3 \\\\\n\\end{bmatrix}"
+    /// );
+    /// ```
+    pub fn to_latex(&self, env: &str) -> String
+    where
+        T: Display,
+    {
+        let mut latex = format!("\\begin{{{}}}\n", env);
+        for row in self.rows() {
+            for (x, value) in row.iter().enumerate() {
+                if x > 0 {
+                    latex.push_str(" & ");
+                }
+                write!(latex, "{}", value).unwrap();
+            }
+            latex.push_str(" \\\\\n");
+        }
+        write!(latex, "\\end{{{}}}", env).unwrap();
+        latex
+    }
+
+    /// Renders the matrix as a GitHub flavored Markdown table, whose header is the first row of the
+    /// matrix. Every column is right aligned and padded to the width of its widest value, and pipes
+    /// within values are escaped. A matrix without any values renders as an empty string.
+    ///
+    /// # Arguments
+    ///
+    /// * `display_func`: Converts a value to the text of its cell
+    ///
+    /// Returns: `String`, The rendered table, with rows separated by newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let table = Matrix::from_rows(vec![vec!["x", "x²"], vec!["3", "9"], vec!["12", "144"]]).unwrap();
+    /// assert_eq!(
+    ///     table.to_markdown_table(|cell| cell.to_string()),
+    ///     "|   x |  x² |\n| --: | --: |\n|   3 |   9 |\n|  12 | 144 |"
+    /// );
+    /// ```
+    pub fn to_markdown_table<F: Fn(&T) -> String>(&self, display_func: F) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+        let cells = self
+            .data
+            .iter()
+            .map(|value| display_func(value).replace('|', "\\|"))
+            .collect::<Vec<_>>();
+        let widths = self
+            .column_widths(&cells)
+            .into_iter()
+            .map(|width| width.max(3))
+            .collect::<Vec<_>>();
+        let mut lines = cells
+            .chunks(self.width)
+            .map(|row| {
+                row.iter()
+                    .zip(&widths)
+                    .fold(String::from("|"), |mut line, (cell, &width)| {
+                        write!(line, " {:>width$} |", cell, width = width).unwrap();
+                        line
+                    })
+            })
+            .collect::<Vec<_>>();
+        let separator = widths.iter().fold(String::from("|"), |line, &width| {
+            line + " " + &"-".repeat(width - 1) + ": |"
+        });
+        lines.insert(1, separator);
+        lines.join("\n")
+    }
+
+    /// Renders the matrix with one line per row, right aligning every value to the width of the widest
+    /// value in its column, so that the digits of numbers line up. Values are separated by a space.
+    ///
+    /// # Arguments
+    ///
+    /// * `display_func`: Converts a value to a string
+    ///
+    /// Returns: `String`, The rendered matrix, with rows separated by newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, -200, 3], vec![40, 5, 6]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.to_aligned_string(|value| value.to_string()),
+    ///     " 1 -200 3\n40    5 6"
+    /// );
+    /// ```
+    pub fn to_aligned_string<F: Fn(&T) -> String>(&self, display_func: F) -> String {
+        let cells = self.data.iter().map(display_func).collect::<Vec<_>>();
+        let widths = self.column_widths(&cells);
+        cells
+            .chunks(self.width.max(1))
+            .map(|row| {
+                row.iter()
+                    .zip(&widths)
+                    .map(|(cell, &width)| format!("{:>width$}", cell, width = width))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Finds the number of characters in the longest of the rendered values of each column.
+    fn column_widths(&self, cells: &[String]) -> Vec<usize> {
+        (0..self.width)
+            .map(|x| {
+                cells[x..]
+                    .iter()
+                    .step_by(self.width)
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    /// Renders the matrix as a grid of characters, one line per row, with some addresses replaced
+    /// by overlay characters. When an address appears in several overlays, the last one wins.
+    /// Overlays at addresses outside the matrix are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `base`: Converts a value to the character drawn when no overlay covers it
+    /// * `overlays`: The addresses to draw over and the characters to draw there
+    ///
+    /// Returns: `String`, The rendered grid, with rows separated by newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let matrix = Matrix::new(3, 2, |_| false).unwrap();
+    /// let rendered = matrix.render_with_overlay(
+    ///     |wall| if *wall { '#' } else { '.' },
+    ///     &[(MatrixAddress { x: 1, y: 1 }, '@'), (MatrixAddress { x: 9, y: 9 }, '!')],
+    /// );
+    /// assert_eq!(rendered, "...\n.@.");
+    /// ```
+    pub fn render_with_overlay<F: Fn(&T) -> char>(
+        &self,
+        base: F,
+        overlays: &[(MatrixAddress, char)],
+    ) -> String {
+        self.render_grid(base, overlays).0
+    }
+
+    /// Renders the matrix as `render_with_overlay` does, but fails instead of ignoring overlays at
+    /// addresses outside the matrix.
+    ///
+    /// Returns: `Result<String, OutOfBoundsError>`, An Err holding the first overlay address which
+    /// lies outside the matrix.
+    pub fn try_render_with_overlay<F: Fn(&T) -> char>(
+        &self,
+        base: F,
+        overlays: &[(MatrixAddress, char)],
+    ) -> Result<String, OutOfBoundsError> {
+        match self.render_grid(base, overlays) {
+            (_, Some(address)) => Err(OutOfBoundsError { address }),
+            (rendered, None) => Ok(rendered),
+        }
+    }
+
+    /// Renders a path over the matrix, such as the result of a path finding search.
+    /// The first address of the path is drawn with `start_char`, the last with `end_char`,
+    /// and the rest with `path_char`. Addresses outside the matrix are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `base`: Converts a value to the character drawn when the path does not cover it
+    /// * `path`: The addresses of the path, from start to end
+    /// * `path_char`: The character drawn along the path
+    /// * `start_char`: The character drawn at the start of the path
+    /// * `end_char`: The character drawn at the end of the path
+    ///
+    /// Returns: `String`, The rendered grid, with rows separated by newlines.
+    pub fn render_path<F: Fn(&T) -> char>(
+        &self,
+        base: F,
+        path: &[MatrixAddress],
+        path_char: char,
+        start_char: char,
+        end_char: char,
+    ) -> String {
+        let mut overlays = path
+            .iter()
+            .map(|address| (*address, path_char))
+            .collect::<Vec<(MatrixAddress, char)>>();
+        if let Some(first) = overlays.first_mut() {
+            first.1 = start_char;
+        }
+        if let (Some(last), true) = (overlays.last_mut(), path.len() > 1) {
+            last.1 = end_char;
+        }
+        self.render_with_overlay(base, &overlays)
+    }
+
+    /// Returns the rendered grid and the first overlay address outside the matrix, if any.
+    fn render_grid<F: Fn(&T) -> char>(
+        &self,
+        base: F,
+        overlays: &[(MatrixAddress, char)],
+    ) -> (String, Option<MatrixAddress>) {
+        let mut grid = self.data.iter().map(base).collect::<Vec<char>>();
+        let mut first_outside = None;
+        for (address, character) in overlays {
+            if self.contains_address(*address) {
+                grid[self.index_address(*address)] = *character;
+            } else if first_outside.is_none() {
+                first_outside = Some(*address);
+            }
+        }
+        let rendered = grid
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+        (rendered, first_outside)
+    }
+}
+
+impl Matrix<f64> {
+    /// Renders the matrix as ASCII art, one line per row, by mapping each value to a palette character.
+    ///
+    /// Values are normalized so that the smallest finite value maps to the first character of the
+    /// palette and the largest finite value maps to the last, with the range split into equally wide
+    /// bands. Infinities map to the first or last character, NaN is drawn as `?`, and a matrix whose
+    /// finite values are all equal is drawn entirely with the first character.
+    ///
+    /// # Arguments
+    ///
+    /// * `palette`: The characters to draw with, from the lowest intensity to the highest,
+    ///   such as `DEFAULT_PALETTE`
+    ///
+    /// Returns: `Option<String>`, None if the palette is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let field = Matrix::from_rows(vec![vec![0.0, 1.0, 2.0], vec![3.0, f64::NAN, 4.0]]).unwrap();
+    /// assert_eq!(field.to_ascii_art(&['.', 'o', 'O']).unwrap(), "..o\nO?O");
+    /// assert_eq!(field.to_ascii_art(&[]), None);
+    /// ```
+    pub fn to_ascii_art(&self, palette: &[char]) -> Option<String> {
+        let last = palette.len().checked_sub(1)?;
+        let normalize = self.normalizer();
+        Some(
+            self.render_grid(
+                |&value| match normalize(value) {
+                    Some(intensity) => {
+                        palette[((intensity * palette.len() as f64) as usize).min(last)]
+                    }
+                    None => '?',
+                },
+                &[],
+            )
+            .0,
+        )
+    }
+
+    /// Renders the matrix as a heatmap for terminals supporting 24-bit color, one line per row.
+    ///
+    /// Each cell is drawn as two spaces whose background color moves from blue at the smallest finite
+    /// value to red at the largest, normalized in the same way as `to_ascii_art`. NaN is drawn as `??`
+    /// without a background color. Every line ends by resetting the terminal colors.
+    ///
+    /// Returns: `String`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let field = Matrix::from_rows(vec![vec![0.0, 1.0]]).unwrap();
+    /// assert_eq!(
+    ///     field.to_ansi_heatmap(),
+    ///     "\x1b[48;2;0;0;255m  \x1b[48;2;255;0;0m  \x1b[0m"
+    /// );
+    /// ```
+    pub fn to_ansi_heatmap(&self) -> String {
+        let normalize = self.normalizer();
+        self.rows()
+            .map(|row| {
+                let mut line = String::new();
+                for &value in row {
+                    match normalize(value) {
+                        Some(intensity) => {
+                            let red = (intensity * 255.0).round() as u8;
+                            write!(line, "\x1b[48;2;{};0;{}m  ", red, 255 - red).unwrap();
+                        }
+                        None => line.push_str("\x1b[0m??"),
+                    }
+                }
+                line + "\x1b[0m"
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Creates a function mapping a value to its position between the smallest and largest finite
+    /// values of the matrix, from zero to one, or None for NaN.
+    fn normalizer(&self) -> impl Fn(f64) -> Option<f64> {
+        let (min, max) = self
+            .data
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        let range = max - min;
+        move |value: f64| {
+            if value.is_nan() {
+                None
+            } else if range > 0.0 {
+                Some(((value - min) / range).clamp(0.0, 1.0))
+            } else if value == f64::INFINITY {
+                Some(1.0)
+            } else {
+                Some(0.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{DEFAULT_PALETTE, Matrix};
+    use crate::matrix_address::MatrixAddress;
+    use crate::tensor::Tensor;
+    use std::collections::VecDeque;
+
+    const MAZE: &str = "#######\n#S..#.#\n#.#.#.#\n#.#...#\n#.###E#\n#######";
+
+    fn shortest_path(
+        maze: &Matrix<char>,
+        start: MatrixAddress,
+        end: MatrixAddress,
+    ) -> Vec<MatrixAddress> {
+        let mut previous = Matrix::new(7, 6, |_| None::<MatrixAddress>).unwrap();
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            if current == end {
+                break;
+            }
+            for (x, y) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+                let next = current + MatrixAddress { x, y };
+                if maze.get(next).is_some_and(|c| *c != '#')
+                    && next != start
+                    && previous[next].is_none()
+                {
+                    previous[next] = Some(current);
+                    queue.push_back(next);
+                }
+            }
+        }
+        let mut path = vec![end];
+        while let Some(step) = previous[*path.last().unwrap()] {
+            path.push(step);
+        }
+        path.reverse();
+        path
+    }
+
+    #[test]
+    fn latex_test() {
+        let matrix = Matrix::from_rows(vec![vec![1.5, -2.0, 0.0], vec![4.0, 5.25, 6.0]]).unwrap();
+        assert_eq!(
+            matrix.to_latex("pmatrix"),
+            r"\begin{pmatrix}
+1.5 & -2 & 0 \\
+4 & 5.25 & 6 \\
+\end{pmatrix}"
+        );
+        let row = Matrix::from_rows(vec![vec!["a_1", "b"]]).unwrap();
+        assert_eq!(
+            row.to_latex("matrix"),
+            "\\begin{matrix}\na_1 & b \\\\\n\\end{matrix}"
+        );
+    }
+
+    #[test]
+    fn aligned_string_test() {
+        let matrix = Matrix::from_rows(vec![
+            vec![1.5, -10.0, 100.0],
+            vec![22.25, 3.0, 0.0],
+            vec![-0.5, 7.0, 1e6],
+        ])
+        .unwrap();
+        assert_eq!(
+            matrix.to_aligned_string(|value| value.to_string()),
+            "  1.5 -10     100\n22.25   3       0\n -0.5   7 1000000"
+        );
+        let words = Matrix::from_rows(vec![vec!["é", "ab"], vec!["日本語", "c"]]).unwrap();
+        assert_eq!(
+            words.to_aligned_string(|word| word.to_string()),
+            "  é ab\n日本語  c"
+        );
+        assert_eq!(
+            Matrix::<i32>::from_rows(vec![])
+                .unwrap()
+                .to_aligned_string(|value| value.to_string()),
+            ""
+        );
+    }
+
+    #[test]
+    fn markdown_table_test() {
+        let render = |matrix: Matrix<i32>| matrix.to_markdown_table(|value| value.to_string());
+        assert_eq!(
+            render(Matrix::from_rows(vec![vec![7]]).unwrap()),
+            "|   7 |\n| --: |"
+        );
+        assert_eq!(
+            render(Matrix::from_rows(vec![vec![1, -20, 3000]]).unwrap()),
+            "|   1 | -20 | 3000 |\n| --: | --: | ---: |"
+        );
+        assert_eq!(
+            render(Matrix::from_rows(vec![vec![1], vec![22], vec![-333]]).unwrap()),
+            "|    1 |\n| ---: |\n|   22 |\n| -333 |"
+        );
+        assert_eq!(
+            render(Matrix::from_rows(vec![vec![1, 2], vec![30, 40], vec![500, 6]]).unwrap()),
+            "|   1 |   2 |\n| --: | --: |\n|  30 |  40 |\n| 500 |   6 |"
+        );
+        assert_eq!(render(Matrix::from_rows(vec![]).unwrap()), "");
+        let piped = Matrix::from_rows(vec![vec!["a|b"]]).unwrap();
+        assert_eq!(
+            piped.to_markdown_table(|cell| cell.to_string()),
+            "| a\\|b |\n| ---: |"
+        );
+    }
+
+    #[test]
+    fn render_bfs_path_test() {
+        let maze = Matrix::parse_matrix(MAZE, "", "\n", |s| s.chars().next().unwrap()).unwrap();
+        let path = shortest_path(
+            &maze,
+            MatrixAddress { x: 1, y: 1 },
+            MatrixAddress { x: 5, y: 4 },
+        );
+        assert_eq!(path.len(), 8);
+        let base = |c: &char| if *c == '#' { '#' } else { ' ' };
+        assert_eq!(
+            maze.render_path(base, &path, '*', 'S', 'E'),
+            "#######\n#S**# #\n# #*# #\n# #***#\n# ###E#\n#######"
+        );
+    }
+
+    #[test]
+    fn overlay_test() {
+        let matrix = Matrix::new(4, 3, |address| address.x + address.y).unwrap();
+        let base = |v: &i32| char::from_digit(*v as u32, 10).unwrap();
+        assert_eq!(matrix.render_with_overlay(base, &[]), "0123\n1234\n2345");
+        let overlays = [
+            (MatrixAddress { x: 0, y: 0 }, 'a'),
+            (MatrixAddress { x: 4, y: 0 }, 'b'),
+            (MatrixAddress { x: 0, y: 0 }, 'c'),
+            (MatrixAddress { x: 3, y: 2 }, 'd'),
+        ];
+        assert_eq!(
+            matrix.render_with_overlay(base, &overlays),
+            "c123\n1234\n234d"
+        );
+        assert_eq!(
+            matrix
+                .try_render_with_overlay(base, &overlays)
+                .unwrap_err()
+                .address,
+            MatrixAddress { x: 4, y: 0 }
+        );
+        assert_eq!(
+            matrix
+                .try_render_with_overlay(base, &overlays[2..])
+                .unwrap(),
+            "c123\n1234\n234d"
+        );
+        assert_eq!(
+            matrix.render_path(base, &[MatrixAddress { x: 1, y: 1 }], '*', 'S', 'E'),
+            "0123\n1S34\n2345"
+        );
+    }
+
+    #[test]
+    fn ascii_art_gradient_test() {
+        let gradient =
+            Matrix::new(10, 2, |address| (address.x * (1 - 2 * address.y)) as f64).unwrap();
+        assert_eq!(
+            gradient.to_ascii_art(DEFAULT_PALETTE).unwrap(),
+            "++**##%%@@\n+=--::..  "
+        );
+        let gradient = Matrix::new(10, 1, |address| address.x as f64 / 9.0).unwrap();
+        assert_eq!(
+            gradient.to_ascii_art(DEFAULT_PALETTE).unwrap(),
+            " .:-=+*#%@"
+        );
+    }
+
+    #[test]
+    fn ascii_art_edge_case_test() {
+        let field = Matrix::from_rows(vec![
+            vec![f64::NAN, f64::NEG_INFINITY, 1.0],
+            vec![2.0, f64::INFINITY, 3.0],
+        ])
+        .unwrap();
+        assert_eq!(field.to_ascii_art(&['a', 'b']).unwrap(), "?aa\nbbb");
+        assert_eq!(field.to_ascii_art(&[]), None);
+        let constant = Matrix::new(2, 2, |_| 5.0).unwrap();
+        assert_eq!(constant.to_ascii_art(&['a', 'b']).unwrap(), "aa\naa");
+        let nan = Matrix::new(2, 1, |_| f64::NAN).unwrap();
+        assert_eq!(nan.to_ascii_art(&['a']).unwrap(), "??");
+    }
+
+    #[test]
+    fn ansi_heatmap_test() {
+        let field = Matrix::from_rows(vec![vec![0.0, 0.5], vec![f64::NAN, 1.0]]).unwrap();
+        assert_eq!(
+            field.to_ansi_heatmap(),
+            "\x1b[48;2;0;0;255m  \x1b[48;2;128;0;127m  \x1b[0m\n\x1b[0m??\x1b[48;2;255;0;0m  \x1b[0m"
+        );
+    }
+}
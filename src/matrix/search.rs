@@ -0,0 +1,371 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The Manhattan distance between two addresses, as `MatrixAddress::manhattan_distance`, for use as
+/// the heuristic of `Matrix::astar`.
+/// It never overestimates the cost of a path when every passable cell costs at least one.
+///
+/// # Examples
+///
+/// ```
+/// use rust_tensors::matrix::manhattan_heuristic;
+/// use rust_tensors::matrix_address::MatrixAddress;
+///
+/// assert_eq!(manhattan_heuristic(MatrixAddress { x: 1, y: 5 }, MatrixAddress { x: 4, y: 3 }), 5);
+/// ```
+pub fn manhattan_heuristic(from: MatrixAddress, to: MatrixAddress) -> u32 {
+    from.manhattan_distance(to) as u32
+}
+
+impl<T> Matrix<T> {
+    /// Iterates over every address contained in the matrix, ordered by Manhattan distance from
+    /// `origin`. Addresses at the same distance are yielded in row-major order.
+    ///
+    /// The addresses are produced lazily by walking diamond shaped rings of increasing distance around
+    /// the origin, clipped to the bounds of the matrix, so taking only the nearest few addresses is cheap.
+    /// The origin does not need to be contained in the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: The address to measure distances from
+    ///
+    /// Returns: `impl Iterator<Item = MatrixAddress>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let matrix = Matrix::new(3, 3, |_| 0).unwrap();
+    /// let nearest = matrix
+    ///     .address_iter_by_distance_from(MatrixAddress { x: 0, y: 1 })
+    ///     .take(4)
+    ///     .map(|address| (address.x, address.y))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(nearest, vec![(0, 1), (0, 0), (1, 1), (0, 2)]);
+    /// ```
+    pub fn address_iter_by_distance_from(
+        &self,
+        origin: MatrixAddress,
+    ) -> impl Iterator<Item = MatrixAddress> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        let (origin_x, origin_y) = (origin.x as i64, origin.y as i64);
+        let distance_to = move |x: i64, y: i64| (x - origin_x).abs() + (y - origin_y).abs();
+        let (nearest, farthest) = if width == 0 || height == 0 {
+            (1, 0)
+        } else {
+            let corners = [
+                (0, 0),
+                (width - 1, 0),
+                (0, height - 1),
+                (width - 1, height - 1),
+            ];
+            (
+                distance_to(origin_x.clamp(0, width - 1), origin_y.clamp(0, height - 1)),
+                corners
+                    .iter()
+                    .map(|&(x, y)| distance_to(x, y))
+                    .max()
+                    .unwrap(),
+            )
+        };
+        (nearest..=farthest).flat_map(move |distance| {
+            ((origin_y - distance).max(0)..=(origin_y + distance).min(height - 1)).flat_map(
+                move |y| {
+                    let reach = distance - (y - origin_y).abs();
+                    [
+                        Some(origin_x - reach),
+                        (reach > 0).then_some(origin_x + reach),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .filter(move |x| (0..width).contains(x))
+                    .map(move |x| MatrixAddress {
+                        x: x as i32,
+                        y: y as i32,
+                    })
+                },
+            )
+        })
+    }
+
+    /// Finds a cheapest path between two addresses with the A* algorithm,
+    /// moving between orthogonally adjacent cells.
+    ///
+    /// Entering a cell costs the value returned by `cost` for it, and cells for which `cost` returns
+    /// None are impassable. The cost of the start cell is not counted. The path is cheapest as long as
+    /// the heuristic never overestimates the remaining cost, as `manhattan_heuristic` does not when
+    /// every passable cell costs at least one.
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: The address to start from
+    /// * `goal`: The address to find a path to
+    /// * `cost`: The cost of entering a cell, or None if the cell is impassable
+    /// * `heuristic`: Estimates the cost of the cheapest path from the first address to the second
+    ///
+    /// Returns: `Option<(Vec<MatrixAddress>, u32)>`, The addresses of the path from `start` to `goal`
+    /// inclusive and its total cost, or None if the goal cannot be reached or either address is not
+    /// a passable cell of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::{manhattan_heuristic, Matrix};
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let grid = Matrix::from_rows(vec![vec![1, 9, 1], vec![1, 9, 1], vec![1, 1, 1]]).unwrap();
+    /// let (path, cost) = grid
+    ///     .astar(
+    ///         MatrixAddress { x: 0, y: 0 },
+    ///         MatrixAddress { x: 2, y: 0 },
+    ///         |&cost| Some(cost),
+    ///         manhattan_heuristic,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!((path.len(), cost), (7, 6));
+    /// ```
+    pub fn astar<C, H>(
+        &self,
+        start: MatrixAddress,
+        goal: MatrixAddress,
+        cost: C,
+        heuristic: H,
+    ) -> Option<(Vec<MatrixAddress>, u32)>
+    where
+        C: Fn(&T) -> Option<u32>,
+        H: Fn(MatrixAddress, MatrixAddress) -> u32,
+    {
+        let start_index = self.index_of(start)?;
+        let goal_index = self.index_of(goal)?;
+        cost(&self.data[start_index])?;
+        cost(&self.data[goal_index])?;
+        let mut g_scores =
+            Matrix::from_flat_vec(self.width, self.height, vec![u32::MAX; self.len()]).unwrap();
+        let mut parents =
+            Matrix::from_flat_vec(self.width, self.height, vec![None; self.len()]).unwrap();
+        g_scores[start] = 0;
+        let mut open = BinaryHeap::from([Reverse((heuristic(start, goal), 0u32, start_index))]);
+        while let Some(Reverse((_, g_score, index))) = open.pop() {
+            let address = self.address_of_index(index);
+            if g_score > g_scores[address] {
+                continue;
+            }
+            if index == goal_index {
+                let mut path = vec![goal];
+                while let Some(parent) = parents[*path.last().unwrap()] {
+                    path.push(parent);
+                }
+                path.reverse();
+                return Some((path, g_score));
+            }
+            for offset in [[0, -1], [-1, 0], [1, 0], [0, 1]] {
+                let neighbour = address + offset.into();
+                let Some(neighbour_index) = self.index_of(neighbour) else {
+                    continue;
+                };
+                let Some(step_cost) = cost(&self.data[neighbour_index]) else {
+                    continue;
+                };
+                let Some(tentative) = g_score.checked_add(step_cost) else {
+                    continue;
+                };
+                if tentative < g_scores[neighbour] {
+                    g_scores[neighbour] = tentative;
+                    parents[neighbour] = Some(address);
+                    let estimate = tentative.saturating_add(heuristic(neighbour, goal));
+                    open.push(Reverse((estimate, tentative, neighbour_index)));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{Matrix, manhattan_heuristic};
+    use crate::matrix_address::MatrixAddress;
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    /// The cheapest cost of reaching every cell from `start`, by relaxing every edge until nothing changes.
+    fn relaxed_costs(grid: &Matrix<Option<u32>>, start: MatrixAddress) -> Matrix<Option<u32>> {
+        let mut costs = grid
+            .clone()
+            .transform(|address, _| (address == start).then_some(0));
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for address in grid.address_iter() {
+                let Some(step) = grid[address] else { continue };
+                for offset in [[0, -1], [-1, 0], [1, 0], [0, 1]] {
+                    let Some(&Some(from)) = costs.get(address + offset.into()) else {
+                        continue;
+                    };
+                    if costs[address].is_none_or(|cost| from + step < cost) {
+                        costs[address] = Some(from + step);
+                        changed = true;
+                    }
+                }
+            }
+        }
+        costs
+    }
+
+    fn check_path(grid: &Matrix<Option<u32>>, path: &[MatrixAddress], cost: u32) {
+        for pair in path.windows(2) {
+            assert_eq!(manhattan_heuristic(pair[0], pair[1]), 1);
+        }
+        let path_cost = path[1..]
+            .iter()
+            .map(|&address| grid[address].unwrap())
+            .sum::<u32>();
+        assert_eq!(path_cost, cost);
+    }
+
+    #[test]
+    fn astar_weighted_test() {
+        let grid = Matrix::parse_matrix("1 1 1 1 1|1 9 9 9 1|1 9 9 9 1|1 1 1 1 1", " ", "|", |s| {
+            s.parse::<u32>().ok()
+        })
+        .unwrap();
+        let (start, goal) = (MatrixAddress { x: 0, y: 1 }, MatrixAddress { x: 4, y: 2 });
+        let (path, cost) = grid
+            .astar(start, goal, |&cost| cost, manhattan_heuristic)
+            .unwrap();
+        assert_eq!((path[0], *path.last().unwrap()), (start, goal));
+        assert!(path.len() - 1 > manhattan_heuristic(start, goal) as usize);
+        assert_eq!(cost, 7);
+        check_path(&grid, &path, cost);
+
+        let (path, cost) = grid
+            .astar(start, start, |&cost| cost, manhattan_heuristic)
+            .unwrap();
+        assert_eq!((path, cost), (vec![start], 0));
+    }
+
+    #[test]
+    fn astar_unreachable_test() {
+        let grid =
+            Matrix::parse_matrix("1 # 1|1 # 1|1 # 1", " ", "|", |s| s.parse::<u32>().ok()).unwrap();
+        let start = MatrixAddress { x: 0, y: 0 };
+        assert_eq!(
+            grid.astar(
+                start,
+                MatrixAddress { x: 2, y: 2 },
+                |&cost| cost,
+                manhattan_heuristic
+            ),
+            None
+        );
+        assert_eq!(
+            grid.astar(
+                start,
+                MatrixAddress { x: 1, y: 1 },
+                |&cost| cost,
+                manhattan_heuristic
+            ),
+            None
+        );
+        assert_eq!(
+            grid.astar(
+                start,
+                MatrixAddress { x: 3, y: 0 },
+                |&cost| cost,
+                manhattan_heuristic
+            ),
+            None
+        );
+    }
+
+    fn distance(a: MatrixAddress, b: MatrixAddress) -> i32 {
+        (a.x - b.x).abs() + (a.y - b.y).abs()
+    }
+
+    #[test]
+    fn address_iter_by_distance_from_test() {
+        let matrix = Matrix::new(4, 3, |_| ()).unwrap();
+        let order = matrix
+            .address_iter_by_distance_from(MatrixAddress { x: 2, y: 1 })
+            .map(|address| (address.x, address.y))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            vec![
+                (2, 1),
+                (2, 0),
+                (1, 1),
+                (3, 1),
+                (2, 2),
+                (1, 0),
+                (3, 0),
+                (0, 1),
+                (1, 2),
+                (3, 2),
+                (0, 0),
+                (0, 2),
+            ]
+        );
+        let empty = Matrix::<()>::from_rows(vec![]).unwrap();
+        assert_eq!(
+            empty
+                .address_iter_by_distance_from(MatrixAddress { x: 0, y: 0 })
+                .count(),
+            0
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn astar_matches_relaxation_test(
+            cells in proptest::collection::vec(proptest::option::weighted(0.8, 1u32..10), 48),
+            start_index in 0usize..48,
+            goal_index in 0usize..48,
+        ) {
+            let grid = Matrix::from_flat_vec(8, 6, cells).unwrap();
+            let address = |index: usize| MatrixAddress { x: (index % 8) as i32, y: (index / 8) as i32 };
+            let (start, goal) = (address(start_index), address(goal_index));
+            let expected = if grid[start].is_some() {
+                relaxed_costs(&grid, start)[goal]
+            } else {
+                None
+            };
+            let result = grid.astar(start, goal, |&cost| cost, manhattan_heuristic);
+            assert_eq!(result.as_ref().map(|(_, cost)| *cost), expected);
+            if let Some((path, cost)) = result {
+                assert_eq!((path[0], *path.last().unwrap()), (start, goal));
+                check_path(&grid, &path, cost);
+            }
+        }
+
+        #[test]
+        fn address_iter_by_distance_from_proptest(
+            width in 1usize..20,
+            height in 1usize..20,
+            x in -10..30,
+            y in -10..30,
+        ) {
+            let matrix = Matrix::new(width, height, |_| ()).unwrap();
+            let origin = MatrixAddress { x, y };
+            let order = matrix.address_iter_by_distance_from(origin).collect::<Vec<_>>();
+            for pair in order.windows(2) {
+                let (a, b) = (pair[0], pair[1]);
+                assert!(
+                    distance(a, origin) < distance(b, origin)
+                        || (distance(a, origin) == distance(b, origin) && (a.y, a.x) < (b.y, b.x))
+                );
+            }
+            let mut sorted = order.iter().map(|address| (address.y, address.x)).collect::<Vec<_>>();
+            sorted.sort();
+            let expected = matrix.address_iter().map(|address| (address.y, address.x)).collect::<Vec<_>>();
+            assert_eq!(sorted, expected);
+            if matrix.contains_address(origin) {
+                assert_eq!(order[0], origin);
+            }
+        }
+    }
+}
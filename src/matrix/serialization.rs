@@ -0,0 +1,74 @@
+use crate::matrix::Matrix;
+use crate::matrix_error::DimensionMismatchError;
+
+/// The serialized form of a matrix, checked for consistency before it becomes a `Matrix`.
+#[derive(serde::Deserialize)]
+#[serde(rename = "Matrix")]
+pub(super) struct SerializedMatrix<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<T> TryFrom<SerializedMatrix<T>> for Matrix<T> {
+    type Error = DimensionMismatchError;
+
+    fn try_from(value: SerializedMatrix<T>) -> Result<Self, Self::Error> {
+        Matrix::from_flat_vec(value.width, value.height, value.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_address::MatrixAddress;
+
+    #[test]
+    fn matrix_round_trip_test() {
+        let matrix =
+            Matrix::new(7, 3, |address| address.x as f64 / (address.y + 1) as f64).unwrap();
+        let json = serde_json::to_string(&matrix).unwrap();
+        let decoded: Matrix<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, matrix);
+
+        let matrix = Matrix::new(2, 5, |address| format!("{:?}", address)).unwrap();
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Matrix<String>>(&json).unwrap(),
+            matrix
+        );
+    }
+
+    #[test]
+    fn matrix_format_test() {
+        let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(
+            serde_json::to_string(&matrix).unwrap(),
+            r#"{"width":3,"height":2,"data":[1,2,3,4,5,6]}"#
+        );
+        assert!(
+            serde_json::from_str::<Matrix<i32>>(r#"{"width":3,"height":2,"data":[1,2,3,4,5]}"#)
+                .is_err()
+        );
+        assert!(
+            serde_json::from_str::<Matrix<i32>>(r#"{"width":0,"height":2,"data":[1]}"#).is_err()
+        );
+        assert!(
+            serde_json::from_str::<Matrix<i32>>(
+                r#"{"width":9223372036854775808,"height":2,"data":[]}"#
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn address_round_trip_test() {
+        let address = MatrixAddress { x: -4, y: 12 };
+        let json = serde_json::to_string(&address).unwrap();
+        assert_eq!(json, r#"{"x":-4,"y":12}"#);
+        assert_eq!(
+            serde_json::from_str::<MatrixAddress>(&json).unwrap(),
+            address
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::matrix_error::{MatrixError, OutOfBoundsError};
+use std::collections::BTreeMap;
+use std::ops::Add;
+
+/// A set of values at scattered addresses, such as the cells changed by one step of a simulation.
+///
+/// A sparse matrix has no dimensions of its own, any address can be occupied. Occupied cells are
+/// visited in row-major order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseMatrix<T> {
+    /// Keyed by `(y, x)` so that the map is ordered row by row.
+    entries: BTreeMap<(i32, i32), T>,
+}
+
+/// What to do with the occupied cells of a sparse matrix which lie outside of a dense matrix.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OutOfBoundsPolicy {
+    /// Fail without changing anything.
+    Reject,
+    /// Leave out the cells outside of the dense matrix.
+    Skip,
+}
+
+impl<T> SparseMatrix<T> {
+    /// Creates a sparse matrix without any occupied cells.
+    ///
+    /// Returns: `SparseMatrix<T>`
+    pub fn new() -> Self {
+        SparseMatrix {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Occupies a cell with a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `address`: The address of the cell
+    /// * `value`: The new value of the cell
+    ///
+    /// Returns: `Option<T>`, The previous value of the cell, if it was occupied.
+    pub fn insert(&mut self, address: MatrixAddress, value: T) -> Option<T> {
+        self.entries.insert((address.y, address.x), value)
+    }
+
+    /// Empties a cell.
+    ///
+    /// Returns: `Option<T>`, The value of the cell, if it was occupied.
+    pub fn remove(&mut self, address: MatrixAddress) -> Option<T> {
+        self.entries.remove(&(address.y, address.x))
+    }
+
+    /// Gets the value of a cell.
+    ///
+    /// Returns: `Option<&T>`, None if the cell is not occupied.
+    pub fn get(&self, address: MatrixAddress) -> Option<&T> {
+        self.entries.get(&(address.y, address.x))
+    }
+
+    /// The number of occupied cells.
+    ///
+    /// Returns: `usize`
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no cell is occupied.
+    ///
+    /// Returns: `bool`
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Creates an iterator over the occupied cells in row-major order.
+    ///
+    /// Returns: `impl Iterator<Item = (MatrixAddress, &T)>`
+    pub fn iter(&self) -> impl Iterator<Item = (MatrixAddress, &T)> {
+        self.entries
+            .iter()
+            .map(|(&(y, x), value)| (MatrixAddress { x, y }, value))
+    }
+}
+
+impl<T> Default for SparseMatrix<T> {
+    fn default() -> Self {
+        SparseMatrix::new()
+    }
+}
+
+impl<T> FromIterator<(MatrixAddress, T)> for SparseMatrix<T> {
+    /// Later values replace earlier values at the same address.
+    fn from_iter<I: IntoIterator<Item = (MatrixAddress, T)>>(iter: I) -> Self {
+        SparseMatrix {
+            entries: iter
+                .into_iter()
+                .map(|(address, value)| ((address.y, address.x), value))
+                .collect(),
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Adds the occupied cells of a sparse matrix to the values at the same addresses, leaving every
+    /// other value untouched. Only the occupied cells are visited, so applying a handful of changes
+    /// to a large matrix is cheap.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta`: The values to add
+    /// * `policy`: What to do with occupied cells outside of this matrix
+    ///
+    /// Returns: `Result<(), MatrixError>`, An Err naming the first cell in row-major order outside of
+    /// this matrix if the policy is `Reject`, in which case the matrix is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::{Matrix, OutOfBoundsPolicy, SparseMatrix};
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let mut heat = Matrix::new(100, 100, |_| 1).unwrap();
+    /// let delta = SparseMatrix::from_iter([
+    ///     (MatrixAddress { x: 3, y: 4 }, 10),
+    ///     (MatrixAddress { x: 100, y: 0 }, 10),
+    /// ]);
+    /// assert!(heat.add_sparse(&delta, OutOfBoundsPolicy::Reject).is_err());
+    /// assert_eq!(heat.sum(), 10000);
+    /// heat.add_sparse(&delta, OutOfBoundsPolicy::Skip).unwrap();
+    /// assert_eq!(heat[(3, 4)], 11);
+    /// assert_eq!(heat.sum(), 10010);
+    /// ```
+    pub fn add_sparse(
+        &mut self,
+        delta: &SparseMatrix<T>,
+        policy: OutOfBoundsPolicy,
+    ) -> Result<(), MatrixError>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        if policy == OutOfBoundsPolicy::Reject
+            && let Some((address, _)) = delta
+                .iter()
+                .find(|(address, _)| self.index_of(*address).is_none())
+        {
+            return Err(OutOfBoundsError { address }.into());
+        }
+        for (address, value) in delta.iter() {
+            if let Some(index) = self.index_of(address) {
+                self.data[index] = self.data[index] + *value;
+            }
+        }
+        Ok(())
+    }
+
+    /// Pairs the occupied cells of a sparse matrix with the values of this matrix at the same
+    /// addresses, in row-major order. Occupied cells outside of this matrix are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `sparse`: The cells to visit
+    ///
+    /// Returns: `impl Iterator<Item = (MatrixAddress, &T, &U)>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::{Matrix, SparseMatrix};
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let map = Matrix::from_rows(vec![vec!['.', '#'], vec!['#', '.']]).unwrap();
+    /// let units = SparseMatrix::from_iter([(MatrixAddress { x: 1, y: 0 }, "wall walker")]);
+    /// let cells = map.masked_by(&units).collect::<Vec<_>>();
+    /// assert_eq!(cells, vec![(MatrixAddress { x: 1, y: 0 }, &'#', &"wall walker")]);
+    /// ```
+    pub fn masked_by<'a, U>(
+        &'a self,
+        sparse: &'a SparseMatrix<U>,
+    ) -> impl Iterator<Item = (MatrixAddress, &'a T, &'a U)> {
+        sparse.iter().filter_map(|(address, other)| {
+            let index = self.index_of(address)?;
+            Some((address, &self.data[index], other))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{Matrix, OutOfBoundsPolicy, SparseMatrix};
+    use crate::matrix_address::MatrixAddress;
+    use crate::matrix_error::{MatrixError, OutOfBoundsError};
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    #[test]
+    fn out_of_bounds_policy_test() {
+        let original = Matrix::new(4, 3, |address| address.x * 10 + address.y).unwrap();
+        let delta = SparseMatrix::from_iter([
+            (MatrixAddress { x: 0, y: 0 }, 5),
+            (MatrixAddress { x: -1, y: 1 }, 5),
+            (MatrixAddress { x: 3, y: 2 }, 5),
+            (MatrixAddress { x: 1, y: 3 }, 5),
+        ]);
+        let mut matrix = original.clone();
+        assert_eq!(
+            matrix.add_sparse(&delta, OutOfBoundsPolicy::Reject),
+            Err(MatrixError::OutOfBounds(OutOfBoundsError {
+                address: MatrixAddress { x: -1, y: 1 }
+            }))
+        );
+        assert_eq!(matrix, original);
+        matrix.add_sparse(&delta, OutOfBoundsPolicy::Skip).unwrap();
+        for (address, value) in matrix.address_value_iter() {
+            let added = if address == [0, 0].into() || address == [3, 2].into() {
+                5
+            } else {
+                0
+            };
+            assert_eq!(*value, original[address] + added);
+        }
+        assert_eq!(
+            matrix
+                .masked_by(&delta)
+                .map(|(address, value, _)| (address, *value))
+                .collect::<Vec<_>>(),
+            vec![([0, 0].into(), 5), ([3, 2].into(), 37)]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn add_sparse_test(changes in proptest::collection::vec((0i32..6, 0i32..5, -50i32..50), 0..12)) {
+            let original = Matrix::new(6, 5, |address| address.y * 6 + address.x).unwrap();
+            let delta = changes
+                .iter()
+                .map(|&(x, y, value)| (MatrixAddress { x, y }, value))
+                .collect::<SparseMatrix<i32>>();
+            let mut matrix = original.clone();
+            matrix.add_sparse(&delta, OutOfBoundsPolicy::Reject).unwrap();
+            for (address, value) in matrix.address_value_iter() {
+                assert_eq!(*value, original[address] + delta.get(address).copied().unwrap_or(0));
+            }
+            assert_eq!(matrix.masked_by(&delta).count(), delta.len());
+        }
+    }
+}
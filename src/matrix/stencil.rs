@@ -0,0 +1,306 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::tensor::Tensor;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// The result of `Matrix::iterate_until_cycle`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum IterationOutcome<T> {
+    /// The state after `start + length` steps equals the state after `start` steps, which is `state`.
+    /// Every later state repeats with period `length`.
+    Cycle {
+        start: usize,
+        length: usize,
+        state: Matrix<T>,
+    },
+    /// No state recurred within the step limit. `state` is the state after the limit.
+    NoCycle { state: Matrix<T> },
+}
+
+impl<T: Clone> IterationOutcome<T> {
+    /// Computes the state after `n_steps` steps from the original matrix, using the detected cycle to
+    /// skip ahead. At most `length - 1` steps are simulated, however large `n_steps` is.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_steps`: The number of steps from the original matrix
+    /// * `step`: The same step function which was given to `iterate_until_cycle`
+    ///
+    /// Returns: `Option<Matrix<T>>`, None if no cycle was found or `n_steps` is before the cycle start,
+    /// as those states are not retained.
+    pub fn state_after<F>(&self, n_steps: usize, step: F) -> Option<Matrix<T>>
+    where
+        F: Fn(&Matrix<T>) -> Matrix<T>,
+    {
+        match self {
+            IterationOutcome::Cycle {
+                start,
+                length,
+                state,
+            } if n_steps >= *start => {
+                Some((0..(n_steps - start) % length).fold(state.clone(), |state, _| step(&state)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Repeatedly applies a deterministic step to the matrix until a state recurs.
+    ///
+    /// Only a fingerprint of each state is kept, so memory stays small even for many steps.
+    /// When a fingerprint matches an earlier one, the earlier state is recomputed from this matrix
+    /// and compared in full, so a fingerprint collision can not report a false cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `step`: Computes the next state from the current one
+    /// * `max_steps`: The largest number of steps to simulate
+    ///
+    /// Returns: `IterationOutcome<T>`, The start and length of the cycle with the state at its start,
+    /// or the state after `max_steps` steps if no state recurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::{IterationOutcome, Matrix};
+    ///
+    /// let counter = Matrix::new(1, 1, |_| 7).unwrap();
+    /// let step = |matrix: &Matrix<i32>| matrix.clone().transform(|_, value| (value * 2) % 10);
+    /// let outcome = counter.iterate_until_cycle(step, 100);
+    /// // 7 -> 4 -> 8 -> 6 -> 2 -> 4
+    /// assert!(matches!(outcome, IterationOutcome::Cycle { start: 1, length: 4, .. }));
+    /// assert_eq!(outcome.state_after(1_000_000_002, step).unwrap()[(0, 0)], 8);
+    /// ```
+    pub fn iterate_until_cycle<F>(&self, step: F, max_steps: usize) -> IterationOutcome<T>
+    where
+        T: Hash + Eq + Clone,
+        F: Fn(&Matrix<T>) -> Matrix<T>,
+    {
+        let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+        let mut state = self.clone();
+        for steps in 0..=max_steps {
+            let fingerprint = state.fingerprint();
+            for &earlier in seen.get(&fingerprint).into_iter().flatten() {
+                let earlier_state = (0..earlier).fold(self.clone(), |state, _| step(&state));
+                if earlier_state == state {
+                    return IterationOutcome::Cycle {
+                        start: earlier,
+                        length: steps - earlier,
+                        state: earlier_state,
+                    };
+                }
+            }
+            seen.entry(fingerprint).or_default().push(steps);
+            if steps < max_steps {
+                state = step(&state);
+            }
+        }
+        IterationOutcome::NoCycle { state }
+    }
+
+    fn fingerprint(&self) -> u64
+    where
+        T: Hash,
+    {
+        let mut hasher = DefaultHasher::new();
+        self.dimensions().hash(&mut hasher);
+        self.data.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Matrix<f64> {
+    /// Relaxes the matrix towards a solution of Laplace's equation by Jacobi iteration.
+    ///
+    /// Cells for which `boundary` returns a value are set to that value and stay pinned. Every other
+    /// cell is repeatedly replaced by the average of its orthogonal neighbours inside the matrix,
+    /// with all cells of a sweep computed from the values of the previous sweep.
+    /// This is useful for potential fields, influence maps and smoothly interpolating between known values.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundary`: Returns the pinned value of an address, or None if the cell is free to change
+    /// * `iterations`: The number of sweeps to perform
+    ///
+    /// Returns: `f64`, The largest change of any cell during the final sweep,
+    /// or zero if no sweeps were performed. Callers can relax repeatedly until this is small enough.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut field = Matrix::new(5, 1, |_| 0.0).unwrap();
+    /// let boundary = |address: rust_tensors::matrix_address::MatrixAddress| match address.x {
+    ///     0 => Some(0.0),
+    ///     4 => Some(8.0),
+    ///     _ => None,
+    /// };
+    /// while field.relax(boundary, 10) > 1e-12 {}
+    /// assert!((field[(2, 0)] - 4.0).abs() < 1e-9);
+    /// ```
+    pub fn relax<F>(&mut self, boundary: F, iterations: usize) -> f64
+    where
+        F: Fn(MatrixAddress) -> Option<f64>,
+    {
+        let (width, height) = self.dimensions();
+        let pinned = self
+            .address_iter()
+            .map(boundary)
+            .collect::<Vec<Option<f64>>>();
+        for (value, pin) in self.data.iter_mut().zip(&pinned) {
+            if let Some(pin) = pin {
+                *value = *pin;
+            }
+        }
+        let mut next = self.data.clone();
+        let mut largest_change = 0.0;
+        for _ in 0..iterations {
+            largest_change = 0.0f64;
+            for y in 0..height {
+                for x in 0..width {
+                    let index = y * width + x;
+                    if pinned[index].is_some() {
+                        continue;
+                    }
+                    let neighbours = [
+                        (x > 0).then(|| index - 1),
+                        (x + 1 < width).then(|| index + 1),
+                        (y > 0).then(|| index - width),
+                        (y + 1 < height).then(|| index + width),
+                    ];
+                    let (sum, count) = neighbours
+                        .into_iter()
+                        .flatten()
+                        .fold((0.0, 0), |(sum, count), neighbour| {
+                            (sum + self.data[neighbour], count + 1)
+                        });
+                    if count == 0 {
+                        continue;
+                    }
+                    let value = sum / count as f64;
+                    largest_change = largest_change.max((value - self.data[index]).abs());
+                    next[index] = value;
+                }
+            }
+            std::mem::swap(&mut self.data, &mut next);
+        }
+        largest_change
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{IterationOutcome, Matrix};
+    use crate::matrix_address::MatrixAddress;
+    use crate::tensor::Tensor;
+
+    fn life_step(board: &Matrix<bool>) -> Matrix<bool> {
+        board.clone().transform(|address, &alive| {
+            let neighbours = (-1..=1)
+                .flat_map(|dy| (-1..=1).map(move |dx| MatrixAddress { x: dx, y: dy }))
+                .filter(|&offset| offset != MatrixAddress { x: 0, y: 0 })
+                .filter(|&offset| board.get(address + offset) == Some(&true))
+                .count();
+            neighbours == 3 || (alive && neighbours == 2)
+        })
+    }
+
+    fn parse_board(board: &str) -> Matrix<bool> {
+        Matrix::parse_matrix(board, "", "|", |s| s == "#").unwrap()
+    }
+
+    #[test]
+    fn iterate_until_cycle_blinker_test() {
+        let horizontal = parse_board(".....|.....|.###.|.....|.....");
+        let vertical = parse_board(".....|..#..|..#..|..#..|.....");
+        let outcome = horizontal.iterate_until_cycle(life_step, 10);
+        assert_eq!(
+            outcome,
+            IterationOutcome::Cycle {
+                start: 0,
+                length: 2,
+                state: horizontal.clone()
+            }
+        );
+        assert_eq!(outcome.state_after(1_000_001, life_step), Some(vertical));
+        assert_eq!(outcome.state_after(1_000_000, life_step), Some(horizontal));
+    }
+
+    #[test]
+    fn iterate_until_cycle_fast_forward_test() {
+        // An R-pentomino in a small box settles into oscillators after a transient.
+        let board =
+            parse_board("........|........|...##...|..##....|...#....|........|........|........");
+        let outcome = board.iterate_until_cycle(life_step, 1000);
+        let IterationOutcome::Cycle { start, length, .. } = outcome else {
+            panic!("No cycle found");
+        };
+        let mut states = vec![board];
+        for _ in 0..start + 3 * length {
+            states.push(life_step(states.last().unwrap()));
+        }
+        assert_eq!(states[start], states[start + length]);
+        assert!((start + 1..start + length).all(|n| states[n] != states[start]));
+        if start > 0 {
+            assert_ne!(states[start - 1], states[start - 1 + length]);
+        }
+        for (n, state) in states.iter().enumerate() {
+            assert_eq!(
+                outcome.state_after(n, life_step).as_ref(),
+                (n >= start).then_some(state)
+            );
+        }
+
+        let limited = parse_board("##|#.").iterate_until_cycle(life_step, 0);
+        assert_eq!(
+            limited,
+            IterationOutcome::NoCycle {
+                state: parse_board("##|#.")
+            }
+        );
+        assert_eq!(limited.state_after(0, life_step), None);
+    }
+
+    #[test]
+    fn relax_linear_gradient_test() {
+        let size = 12;
+        let mut field = Matrix::new(size, size, |_| 0.0).unwrap();
+        let boundary = |address: MatrixAddress| match address.x {
+            0 => Some(0.0),
+            x if x == size as i32 - 1 => Some(1.0),
+            _ => None,
+        };
+        let mut sweeps = 0;
+        while field.relax(boundary, 50) > 1e-12 {
+            sweeps += 50;
+            assert!(sweeps < 100_000, "Relaxation did not converge");
+        }
+        for (address, &value) in field.address_value_iter() {
+            let expected = address.x as f64 / (size - 1) as f64;
+            assert!((value - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn relax_pins_boundary_test() {
+        let mut field = Matrix::new(3, 3, |address| (address.x + address.y) as f64).unwrap();
+        let center = MatrixAddress { x: 1, y: 1 };
+        let boundary = |address: MatrixAddress| (address == center).then_some(-5.0);
+        assert_eq!(field.relax(boundary, 0), 0.0);
+        assert_eq!(field[center], -5.0);
+        let change = field.relax(boundary, 1);
+        assert_eq!(field[center], -5.0);
+        // The top left corner averages (1, 0) and (0, 1) from the previous sweep.
+        assert_eq!(field[(0, 0)], 1.0);
+        assert_eq!(field[(1, 0)], (0.0 + 2.0 - 5.0) / 3.0);
+        assert!(change > 0.0);
+
+        let mut single = Matrix::new(1, 1, |_| 3.0).unwrap();
+        assert_eq!(single.relax(|_| None, 10), 0.0);
+        assert_eq!(single[(0, 0)], 3.0);
+    }
+}
@@ -0,0 +1,1561 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::matrix_error::{DimensionMismatchError, OutOfBoundsError};
+use crate::tensor::Tensor;
+use std::cmp::Ordering;
+
+/// Describes how a structural edit moved the cells of a matrix, so that addresses computed before
+/// the edit can be translated to the cells they pointed at with `MatrixAddress::remap`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EditRecord {
+    /// A row was inserted at `at`, moving the rows at and below it down by one.
+    InsertRow { at: usize },
+    /// A column was inserted at `at`, moving the columns at and right of it right by one.
+    InsertColumn { at: usize },
+    /// The row at `at` was removed, moving the rows below it up by one.
+    RemoveRow { at: usize },
+    /// The column at `at` was removed, moving the columns right of it left by one.
+    RemoveColumn { at: usize },
+    /// The rows `a` and `b` traded places.
+    SwapRows { a: usize, b: usize },
+    /// The columns `a` and `b` traded places.
+    SwapColumns { a: usize, b: usize },
+    /// The matrix was resized to the given dimensions, keeping its top left region in place.
+    Resize { width: usize, height: usize },
+}
+
+impl<T> Matrix<T> {
+    /// Swaps two entire rows of the matrix in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `a`: The index of the first row
+    /// * `b`: The index of the second row
+    ///
+    /// # Panics
+    ///
+    /// Panics if either row index is not smaller than the height of the matrix.
+    /// Swapping a row with itself does nothing.
+    ///
+    /// Returns: `EditRecord`, The description of the edit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// matrix.swap_rows(0, 2);
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![5, 6], vec![3, 4], vec![1, 2]]).unwrap());
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> EditRecord {
+        for row in [a, b] {
+            assert!(
+                row < self.height,
+                "Row index {} is out of bounds for a {}x{} matrix",
+                row,
+                self.width,
+                self.height
+            );
+        }
+        let (low, high) = (a.min(b), a.max(b));
+        if low != high {
+            let (head, tail) = self.data.split_at_mut(high * self.width);
+            head[low * self.width..(low + 1) * self.width].swap_with_slice(&mut tail[..self.width]);
+        }
+        EditRecord::SwapRows { a, b }
+    }
+
+    /// Swaps two entire columns of the matrix in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `a`: The index of the first column
+    /// * `b`: The index of the second column
+    ///
+    /// # Panics
+    ///
+    /// Panics if either column index is not smaller than the width of the matrix.
+    /// Swapping a column with itself does nothing.
+    ///
+    /// Returns: `EditRecord`, The description of the edit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// matrix.swap_columns(0, 1);
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![2, 1, 3], vec![5, 4, 6]]).unwrap());
+    /// ```
+    pub fn swap_columns(&mut self, a: usize, b: usize) -> EditRecord {
+        for column in [a, b] {
+            assert!(
+                column < self.width,
+                "Column index {} is out of bounds for a {}x{} matrix",
+                column,
+                self.width,
+                self.height
+            );
+        }
+        if a != b {
+            self.rows_mut().for_each(|row| row.swap(a, b));
+        }
+        EditRecord::SwapColumns { a, b }
+    }
+
+    /// Swaps two columns of the matrix, the former name of `swap_columns`.
+    ///
+    /// Returns: `EditRecord`, The description of the edit.
+    #[deprecated(note = "use swap_columns")]
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> EditRecord {
+        self.swap_columns(a, b)
+    }
+
+    /// Inserts a row into the matrix, shifting the rows at and below `at` down by one.
+    /// A matrix without any rows takes its width from the inserted row.
+    ///
+    /// # Arguments
+    ///
+    /// * `at`: The index the inserted row will have, where the height of the matrix appends the row
+    /// * `values`: The values of the row, from left to right
+    ///
+    /// Returns: `Result<EditRecord, DimensionMismatchError>`, The description of the edit, or an Err if the
+    /// number of values is not the width of the matrix, in which case the matrix is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the height of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 2], vec![5, 6]]).unwrap();
+    /// matrix.insert_row(1, vec![3, 4]).unwrap();
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap());
+    /// assert!(matrix.insert_row(3, vec![7]).is_err());
+    /// ```
+    pub fn insert_row(
+        &mut self,
+        at: usize,
+        values: Vec<T>,
+    ) -> Result<EditRecord, DimensionMismatchError> {
+        assert!(
+            at <= self.height,
+            "Row index {} is out of bounds for inserting into a {}x{} matrix",
+            at,
+            self.width,
+            self.height
+        );
+        if self.height == 0 {
+            self.width = values.len();
+        } else if values.len() != self.width {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Cannot insert a row of length {} into a {}x{} matrix",
+                    values.len(),
+                    self.width,
+                    self.height
+                ),
+            });
+        }
+        let start = at * self.width;
+        self.data.splice(start..start, values);
+        self.height += 1;
+        self.debug_assert_invariants();
+        Ok(EditRecord::InsertRow { at })
+    }
+
+    /// Inserts a column into the matrix, shifting the columns at and right of `at` right by one.
+    /// A matrix without any columns takes its height from the inserted column.
+    ///
+    /// # Arguments
+    ///
+    /// * `at`: The index the inserted column will have, where the width of the matrix appends the column
+    /// * `values`: The values of the column, from top to bottom
+    ///
+    /// Returns: `Result<EditRecord, DimensionMismatchError>`, The description of the edit, or an Err if the
+    /// number of values is not the height of the matrix, in which case the matrix is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the width of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 3], vec![4, 6]]).unwrap();
+    /// matrix.insert_column(1, vec![2, 5]).unwrap();
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap());
+    /// ```
+    pub fn insert_column(
+        &mut self,
+        at: usize,
+        values: Vec<T>,
+    ) -> Result<EditRecord, DimensionMismatchError> {
+        assert!(
+            at <= self.width,
+            "Column index {} is out of bounds for inserting into a {}x{} matrix",
+            at,
+            self.width,
+            self.height
+        );
+        if self.width == 0 {
+            self.height = values.len();
+        } else if values.len() != self.height {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Cannot insert a column of length {} into a {}x{} matrix",
+                    values.len(),
+                    self.width,
+                    self.height
+                ),
+            });
+        }
+        let mut old_data = std::mem::take(&mut self.data).into_iter();
+        let mut data = Vec::with_capacity((self.width + 1) * self.height);
+        for value in values {
+            data.extend(old_data.by_ref().take(at));
+            data.push(value);
+            data.extend(old_data.by_ref().take(self.width - at));
+        }
+        self.data = data;
+        self.width += 1;
+        self.debug_assert_invariants();
+        Ok(EditRecord::InsertColumn { at })
+    }
+
+    /// Removes a row from the matrix, shifting the rows below it up by one.
+    /// Removing the only row leaves a matrix of zero height, which has no addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `y`: The index of the row to remove
+    ///
+    /// Returns: `(Vec<T>, EditRecord)`, The values of the removed row, from left to right,
+    /// and the description of the edit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is not smaller than the height of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// assert_eq!(matrix.remove_row(1).0, vec![3, 4]);
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 2], vec![5, 6]]).unwrap());
+    /// ```
+    pub fn remove_row(&mut self, y: usize) -> (Vec<T>, EditRecord) {
+        assert!(
+            y < self.height,
+            "Row index {} is out of bounds for a {}x{} matrix",
+            y,
+            self.width,
+            self.height
+        );
+        self.height -= 1;
+        let removed = self
+            .data
+            .drain(y * self.width..(y + 1) * self.width)
+            .collect();
+        self.debug_assert_invariants();
+        (removed, EditRecord::RemoveRow { at: y })
+    }
+
+    /// Removes a column from the matrix, shifting the columns right of it left by one.
+    /// Removing the only column leaves a matrix of zero width, which has no addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: The index of the column to remove
+    ///
+    /// Returns: `(Vec<T>, EditRecord)`, The values of the removed column, from top to bottom,
+    /// and the description of the edit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not smaller than the width of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(matrix.remove_column(0).0, vec![1, 4]);
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![2, 3], vec![5, 6]]).unwrap());
+    /// ```
+    pub fn remove_column(&mut self, x: usize) -> (Vec<T>, EditRecord) {
+        assert!(
+            x < self.width,
+            "Column index {} is out of bounds for a {}x{} matrix",
+            x,
+            self.width,
+            self.height
+        );
+        let mut removed = Vec::with_capacity(self.height);
+        let mut data = Vec::with_capacity((self.width - 1) * self.height);
+        for (index, value) in std::mem::take(&mut self.data).into_iter().enumerate() {
+            if index % self.width == x {
+                removed.push(value);
+            } else {
+                data.push(value);
+            }
+        }
+        self.data = data;
+        self.width -= 1;
+        self.debug_assert_invariants();
+        (removed, EditRecord::RemoveColumn { at: x })
+    }
+
+    /// Changes the dimensions of the matrix in place, keeping the values of the top left region both
+    /// sizes share at the same addresses. Values outside the new bounds are dropped, and newly exposed
+    /// cells are set to clones of `fill`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_width`: The width of the resized matrix
+    /// * `new_height`: The height of the resized matrix
+    /// * `fill`: The value of the newly exposed cells
+    ///
+    /// Returns: `EditRecord`, The description of the edit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// matrix.resize(2, 3, 0);
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 2], vec![4, 5], vec![0, 0]]).unwrap());
+    /// ```
+    pub fn resize(&mut self, new_width: usize, new_height: usize, fill: T) -> EditRecord
+    where
+        T: Clone,
+    {
+        self.resize_with(new_width, new_height, |_| fill.clone())
+    }
+
+    /// Changes the dimensions of the matrix in place, as `resize` does,
+    /// with the values of newly exposed cells created by a mapper function.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_width`: The width of the resized matrix
+    /// * `new_height`: The height of the resized matrix
+    /// * `address_value_converter`: Converts the address of a newly exposed cell to its value.
+    ///
+    /// Returns: `EditRecord`, The description of the edit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// matrix.resize_with(3, 2, |address| address.x * 10 + address.y);
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 2, 20], vec![3, 4, 21]]).unwrap());
+    /// ```
+    pub fn resize_with<F>(
+        &mut self,
+        new_width: usize,
+        new_height: usize,
+        address_value_converter: F,
+    ) -> EditRecord
+    where
+        F: Fn(MatrixAddress) -> T,
+    {
+        let kept_width = self.width.min(new_width);
+        let mut old_data = std::mem::take(&mut self.data).into_iter();
+        let mut data = Vec::with_capacity(new_width * new_height);
+        for y in 0..new_height {
+            let kept_in_row = if y < self.height {
+                data.extend(old_data.by_ref().take(kept_width));
+                old_data
+                    .by_ref()
+                    .take(self.width - kept_width)
+                    .for_each(drop);
+                kept_width
+            } else {
+                0
+            };
+            data.extend((kept_in_row..new_width).map(|x| {
+                address_value_converter(MatrixAddress {
+                    x: x as i32,
+                    y: y as i32,
+                })
+            }));
+        }
+        self.data = data;
+        self.width = new_width;
+        self.height = new_height;
+        self.debug_assert_invariants();
+        EditRecord::Resize {
+            width: new_width,
+            height: new_height,
+        }
+    }
+
+    /// Creates a copy of the matrix with new dimensions holding the same values in the same row-major
+    /// order, so a 6x4 matrix can be read as a 3x8 matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_width`: The width of the copy
+    /// * `new_height`: The height of the copy
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`, An Err if the copy would not hold the same
+    /// number of values as the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.reshape(2, 3).unwrap(),
+    ///     Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap()
+    /// );
+    /// assert!(matrix.reshape(4, 2).is_err());
+    /// ```
+    pub fn reshape(
+        &self,
+        new_width: usize,
+        new_height: usize,
+    ) -> Result<Matrix<T>, DimensionMismatchError>
+    where
+        T: Clone,
+    {
+        self.check_reshape(new_width, new_height)?;
+        Ok(Matrix::from_parts(new_width, new_height, self.data.clone()))
+    }
+
+    /// Changes the dimensions of the matrix without moving any values, the in-place version of
+    /// `reshape`. The matrix is unchanged if the dimensions do not fit.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_width`: The new width of the matrix
+    /// * `new_height`: The new height of the matrix
+    ///
+    /// Returns: `Result<(), DimensionMismatchError>`, An Err if the matrix would not hold the same
+    /// number of values as before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(6, 4, |address| address.y * 6 + address.x).unwrap();
+    /// matrix.reshape_in_place(3, 8).unwrap();
+    /// assert_eq!(matrix, Matrix::new(3, 8, |address| address.y * 3 + address.x).unwrap());
+    /// ```
+    pub fn reshape_in_place(
+        &mut self,
+        new_width: usize,
+        new_height: usize,
+    ) -> Result<(), DimensionMismatchError> {
+        self.check_reshape(new_width, new_height)?;
+        self.width = new_width;
+        self.height = new_height;
+        self.debug_assert_invariants();
+        Ok(())
+    }
+
+    fn check_reshape(
+        &self,
+        new_width: usize,
+        new_height: usize,
+    ) -> Result<(), DimensionMismatchError> {
+        if new_width.checked_mul(new_height) != Some(self.data.len()) {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Cannot reshape a {}x{} matrix to {}x{}",
+                    self.width, self.height, new_width, new_height
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Translates addresses computed before a structural edit to the addresses of the same cells in
+    /// this matrix, after the edit was applied to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `edit`: The description of the edit, as returned by it
+    /// * `addresses`: The addresses from before the edit
+    ///
+    /// Returns: `impl Iterator<Item = Option<MatrixAddress>>`, The new address of each given address in
+    /// order, where None means the cell was removed by the edit or was never part of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let mut matrix = Matrix::new(3, 3, |address| address.x + address.y * 3).unwrap();
+    /// let waypoints = [MatrixAddress { x: 0, y: 0 }, MatrixAddress { x: 2, y: 1 }, MatrixAddress { x: 1, y: 2 }];
+    /// let (_, edit) = matrix.remove_column(1);
+    /// let remapped: Vec<_> = matrix.remap_addresses(&edit, waypoints).collect();
+    /// assert_eq!(remapped, vec![Some(MatrixAddress { x: 0, y: 0 }), Some(MatrixAddress { x: 1, y: 1 }), None]);
+    /// assert_eq!(matrix[remapped[1].unwrap()], 5);
+    /// ```
+    pub fn remap_addresses<I>(
+        &self,
+        edit: &EditRecord,
+        addresses: I,
+    ) -> impl Iterator<Item = Option<MatrixAddress>>
+    where
+        I: IntoIterator<Item = MatrixAddress>,
+    {
+        addresses.into_iter().map(move |address| {
+            address
+                .remap(edit)
+                .filter(|remapped| self.contains_address(*remapped))
+        })
+    }
+
+    /// Copies the rectangular region between two corners, including both, into a new matrix.
+    /// A rectangle whose bottom right corner lies above or left of its top left corner is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `top_left`: The address of the top left cell of the region
+    /// * `bottom_right`: The address of the bottom right cell of the region
+    ///
+    /// Returns: `Result<Matrix<T>, OutOfBoundsError>`, An Err holding the first corner which lies
+    /// outside of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let map = Matrix::new(4, 4, |address| address.y * 4 + address.x).unwrap();
+    /// let viewport = map.submatrix(MatrixAddress { x: 1, y: 2 }, MatrixAddress { x: 3, y: 3 }).unwrap();
+    /// assert_eq!(viewport, Matrix::from_rows(vec![vec![9, 10, 11], vec![13, 14, 15]]).unwrap());
+    /// let outside = MatrixAddress { x: 4, y: 3 };
+    /// assert_eq!(map.submatrix(MatrixAddress { x: 0, y: 0 }, outside).unwrap_err().address, outside);
+    /// ```
+    pub fn submatrix(
+        &self,
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+    ) -> Result<Matrix<T>, OutOfBoundsError>
+    where
+        T: Clone,
+    {
+        for address in [top_left, bottom_right] {
+            if !self.contains_address(address) {
+                return Err(OutOfBoundsError { address });
+            }
+        }
+        Ok(self.region(top_left, bottom_right))
+    }
+
+    /// Copies the part of the rectangular region between two corners, including both, which lies
+    /// inside the matrix into a new matrix. Unlike `submatrix`, corners outside the matrix are
+    /// allowed, and the region is cut off at the edges of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `top_left`: The address of the top left cell of the region
+    /// * `bottom_right`: The address of the bottom right cell of the region
+    ///
+    /// Returns: `Matrix<T>`, which is empty if the region does not overlap the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let map = Matrix::new(4, 4, |address| address.y * 4 + address.x).unwrap();
+    /// let viewport = map.submatrix_clamped(MatrixAddress { x: -2, y: 2 }, MatrixAddress { x: 1, y: 9 });
+    /// assert_eq!(viewport, Matrix::from_rows(vec![vec![8, 9], vec![12, 13]]).unwrap());
+    /// ```
+    pub fn submatrix_clamped(
+        &self,
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+    ) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let top_left = MatrixAddress {
+            x: top_left.x.max(0),
+            y: top_left.y.max(0),
+        };
+        let bottom_right = MatrixAddress {
+            x: bottom_right.x.min(self.width as i32 - 1),
+            y: bottom_right.y.min(self.height as i32 - 1),
+        };
+        self.region(top_left, bottom_right)
+    }
+
+    /// Copies the region between two corners inside the matrix, or an empty matrix if the region is
+    /// empty in either dimension.
+    pub(crate) fn region(&self, top_left: MatrixAddress, bottom_right: MatrixAddress) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        if bottom_right.x < top_left.x || bottom_right.y < top_left.y {
+            return Matrix::from_parts(0, 0, Vec::new());
+        }
+        let (x, y) = (top_left.x as usize, top_left.y as usize);
+        let width = (bottom_right.x - top_left.x) as usize + 1;
+        let height = (bottom_right.y - top_left.y) as usize + 1;
+        let mut data = Vec::with_capacity(width * height);
+        for row in self.rows().skip(y).take(height) {
+            data.extend_from_slice(&row[x..x + width]);
+        }
+        Matrix::from_parts(width, height, data)
+    }
+
+    /// Joins two matrices side by side, with this matrix to the left of `right`.
+    ///
+    /// # Arguments
+    ///
+    /// * `right`: The matrix whose columns come second
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`, An Err if the heights differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let left = Matrix::from_rows(vec![vec![1], vec![3]]).unwrap();
+    /// let right = Matrix::from_rows(vec![vec![2], vec![4]]).unwrap();
+    /// assert_eq!(
+    ///     left.concat_horizontal(&right).unwrap(),
+    ///     Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap()
+    /// );
+    /// ```
+    pub fn concat_horizontal(&self, right: &Matrix<T>) -> Result<Matrix<T>, DimensionMismatchError>
+    where
+        T: Clone,
+    {
+        let left = self;
+        if left.height != right.height {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Cannot join matrices side by side with heights {} and {}",
+                    left.height, right.height
+                ),
+            });
+        }
+        let mut data = Vec::with_capacity(left.data.len() + right.data.len());
+        for (left_row, right_row) in left.rows().zip(right.rows()) {
+            data.extend_from_slice(left_row);
+            data.extend_from_slice(right_row);
+        }
+        Ok(Matrix::from_parts(
+            left.width + right.width,
+            left.height,
+            data,
+        ))
+    }
+
+    /// Joins two matrices top to bottom, with this matrix above `below`. The values of both are
+    /// simply appended, as the rows of a matrix are contiguous.
+    ///
+    /// # Arguments
+    ///
+    /// * `below`: The matrix whose rows come second
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`, An Err if the widths differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let top = Matrix::from_rows(vec![vec![1, 2]]).unwrap();
+    /// let bottom = Matrix::from_rows(vec![vec![3, 4]]).unwrap();
+    /// assert_eq!(
+    ///     top.concat_vertical(&bottom).unwrap(),
+    ///     Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap()
+    /// );
+    /// ```
+    pub fn concat_vertical(&self, below: &Matrix<T>) -> Result<Matrix<T>, DimensionMismatchError>
+    where
+        T: Clone,
+    {
+        if self.width != below.width {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Cannot join matrices top to bottom with widths {} and {}",
+                    self.width, below.width
+                ),
+            });
+        }
+        Ok(Matrix::from_parts(
+            self.width,
+            self.height + below.height,
+            [self.data.as_slice(), below.data.as_slice()].concat(),
+        ))
+    }
+
+    /// Joins any number of matrices side by side, from left to right.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrices`: The matrices to join, which must all have the same height
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`, An Err naming the first matrix whose height
+    /// differs from the first matrix's. Joining no matrices produces a 0x0 matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let tile = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(
+    ///     Matrix::hstack(&[&tile, &tile, &tile]).unwrap(),
+    ///     Matrix::from_rows(vec![vec![1, 2, 1, 2, 1, 2], vec![3, 4, 3, 4, 3, 4]]).unwrap()
+    /// );
+    /// ```
+    pub fn hstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, DimensionMismatchError>
+    where
+        T: Clone,
+    {
+        let height = matrices.first().map_or(0, |matrix| matrix.height);
+        if let Some((index, matrix)) = matrices
+            .iter()
+            .enumerate()
+            .find(|(_, matrix)| matrix.height != height)
+        {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Cannot join matrices side by side, matrix {} has height {} instead of {}",
+                    index, matrix.height, height
+                ),
+            });
+        }
+        let width = matrices.iter().map(|matrix| matrix.width).sum();
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for matrix in matrices {
+                data.extend_from_slice(&matrix.data[y * matrix.width..(y + 1) * matrix.width]);
+            }
+        }
+        Ok(Matrix::from_parts(width, height, data))
+    }
+
+    /// Joins any number of matrices top to bottom, from top to bottom.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrices`: The matrices to join, which must all have the same width
+    ///
+    /// Returns: `Result<Matrix<T>, DimensionMismatchError>`, An Err naming the first matrix whose width
+    /// differs from the first matrix's. Joining no matrices produces a 0x0 matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let tile = Matrix::from_rows(vec![vec![1, 2]]).unwrap();
+    /// assert_eq!(
+    ///     Matrix::vstack(&[&tile, &tile]).unwrap(),
+    ///     Matrix::from_rows(vec![vec![1, 2], vec![1, 2]]).unwrap()
+    /// );
+    /// ```
+    pub fn vstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, DimensionMismatchError>
+    where
+        T: Clone,
+    {
+        let width = matrices.first().map_or(0, |matrix| matrix.width);
+        if let Some((index, matrix)) = matrices
+            .iter()
+            .enumerate()
+            .find(|(_, matrix)| matrix.width != width)
+        {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "Cannot join matrices top to bottom, matrix {} has width {} instead of {}",
+                    index, matrix.width, width
+                ),
+            });
+        }
+        let height = matrices.iter().map(|matrix| matrix.height).sum();
+        let data = matrices
+            .iter()
+            .flat_map(|matrix| matrix.data.iter().cloned())
+            .collect();
+        Ok(Matrix::from_parts(width, height, data))
+    }
+
+    /// Splits the matrix into the rows above `y` and the rows from `y` down, the inverse of
+    /// `concat_vertical`. No values are cloned, the values of the bottom half are moved out of the
+    /// buffer of the top half.
+    ///
+    /// # Arguments
+    ///
+    /// * `y`: The index of the first row of the bottom half, where 0 and the height of the matrix
+    ///   leave one half without any rows
+    ///
+    /// Returns: `(Matrix<T>, Matrix<T>)`, The top and bottom halves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is greater than the height of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// let (top, bottom) = matrix.split_at_row(1);
+    /// assert_eq!(top, Matrix::from_rows(vec![vec![1, 2]]).unwrap());
+    /// assert_eq!(bottom, Matrix::from_rows(vec![vec![3, 4], vec![5, 6]]).unwrap());
+    /// ```
+    pub fn split_at_row(mut self, y: usize) -> (Matrix<T>, Matrix<T>) {
+        assert!(
+            y <= self.height,
+            "Row index {} is out of bounds for a {}x{} matrix",
+            y,
+            self.width,
+            self.height
+        );
+        let bottom = self.data.split_off(y * self.width);
+        (
+            Matrix::from_parts(self.width, y, self.data),
+            Matrix::from_parts(self.width, self.height - y, bottom),
+        )
+    }
+
+    /// Splits the matrix into the columns left of `x` and the columns from `x` rightwards, the
+    /// inverse of `concat_horizontal`. No values are cloned, but every row is divided between two
+    /// new buffers.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: The index of the first column of the right half, where 0 and the width of the matrix
+    ///   leave one half without any columns
+    ///
+    /// Returns: `(Matrix<T>, Matrix<T>)`, The left and right halves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is greater than the width of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let (left, right) = matrix.split_at_column(2);
+    /// assert_eq!(left, Matrix::from_rows(vec![vec![1, 2], vec![4, 5]]).unwrap());
+    /// assert_eq!(right, Matrix::from_rows(vec![vec![3], vec![6]]).unwrap());
+    /// ```
+    pub fn split_at_column(self, x: usize) -> (Matrix<T>, Matrix<T>) {
+        assert!(
+            x <= self.width,
+            "Column index {} is out of bounds for a {}x{} matrix",
+            x,
+            self.width,
+            self.height
+        );
+        let (width, height) = self.dimensions();
+        let mut left = Vec::with_capacity(x * height);
+        let mut right = Vec::with_capacity((width - x) * height);
+        for (index, value) in self.data.into_iter().enumerate() {
+            if index % width < x {
+                left.push(value);
+            } else {
+                right.push(value);
+            }
+        }
+        (
+            Matrix::from_parts(x, height, left),
+            Matrix::from_parts(width - x, height, right),
+        )
+    }
+
+    /// Sorts the rows of the matrix in place, moving each row as a whole. The sort is stable, so
+    /// rows which compare as equal keep their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmp`: Compares two rows, each from left to right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![2, 9], vec![1, 5], vec![2, 0]]).unwrap();
+    /// matrix.sort_rows_by(|a, b| a.cmp(b));
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 5], vec![2, 0], vec![2, 9]]).unwrap());
+    /// ```
+    pub fn sort_rows_by<F: FnMut(&[T], &[T]) -> Ordering>(&mut self, mut cmp: F) {
+        let width = self.width;
+        let mut order = (0..self.height).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            cmp(
+                &self.data[a * width..(a + 1) * width],
+                &self.data[b * width..(b + 1) * width],
+            )
+        });
+        apply_order(&order, |a, b| {
+            self.swap_rows(a, b);
+        });
+    }
+
+    /// Sorts the rows of the matrix in place by their values in one column, moving each row as a
+    /// whole. The sort is stable, so rows with equal keys keep their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: The index of the column holding the keys
+    /// * `cmp`: Compares two keys
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not smaller than the width of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut table = Matrix::from_rows(vec![
+    ///     vec!["carol", "31"],
+    ///     vec!["alice", "27"],
+    ///     vec!["bob", "27"],
+    /// ])
+    /// .unwrap();
+    /// table.sort_rows_by_key_column(1, |a, b| a.cmp(b));
+    /// assert_eq!(table.column(0).unwrap(), vec![&"alice", &"bob", &"carol"]);
+    /// ```
+    pub fn sort_rows_by_key_column<F: FnMut(&T, &T) -> Ordering>(&mut self, x: usize, mut cmp: F) {
+        assert!(
+            x < self.width,
+            "Column index {} is out of bounds for a {}x{} matrix",
+            x,
+            self.width,
+            self.height
+        );
+        self.sort_rows_by(|a, b| cmp(&a[x], &b[x]));
+    }
+
+    /// Sorts the columns of the matrix in place, moving each column as a whole. The sort is stable,
+    /// so columns which compare as equal keep their order. Every column is gathered into a list of
+    /// references to be compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmp`: Compares two columns, each from top to bottom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let mut matrix = Matrix::from_rows(vec![vec![3, 1, 2], vec![0, 1, 0]]).unwrap();
+    /// matrix.sort_columns_by(|a, b| a.cmp(b));
+    /// assert_eq!(matrix, Matrix::from_rows(vec![vec![1, 2, 3], vec![1, 0, 0]]).unwrap());
+    /// ```
+    pub fn sort_columns_by<F: FnMut(&[&T], &[&T]) -> Ordering>(&mut self, mut cmp: F) {
+        let columns = (0..self.width)
+            .map(|x| {
+                (0..self.height)
+                    .map(|y| &self.data[y * self.width + x])
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut order = (0..self.width).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| cmp(&columns[a], &columns[b]));
+        drop(columns);
+        apply_order(&order, |a, b| {
+            self.swap_columns(a, b);
+        });
+    }
+
+    /// Sorts the columns of the matrix in place by their values in one row, moving each column as a
+    /// whole. The sort is stable, so columns with equal keys keep their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `y`: The index of the row holding the keys
+    /// * `cmp`: Compares two keys
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is not smaller than the height of the matrix.
+    pub fn sort_columns_by_key_row<F: FnMut(&T, &T) -> Ordering>(&mut self, y: usize, mut cmp: F) {
+        assert!(
+            y < self.height,
+            "Row index {} is out of bounds for a {}x{} matrix",
+            y,
+            self.width,
+            self.height
+        );
+        let keys = &self.data[y * self.width..(y + 1) * self.width];
+        let mut order = (0..self.width).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| cmp(&keys[a], &keys[b]));
+        apply_order(&order, |a, b| {
+            self.swap_columns(a, b);
+        });
+    }
+}
+
+/// Rearranges a sequence with swaps so that position `i` ends up holding the item which was at
+/// `order[i]`.
+fn apply_order<F: FnMut(usize, usize)>(order: &[usize], mut swap: F) {
+    for (position, &source) in order.iter().enumerate() {
+        // Items before `position` are already in place, so an item which was moved away from
+        // `source` is found by following where it was swapped to.
+        let mut current = source;
+        while current < position {
+            current = order[current];
+        }
+        if current != position {
+            swap(position, current);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{EditRecord, Matrix};
+    use crate::matrix_address::MatrixAddress;
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    #[test]
+    fn swap_rows_test() {
+        let (width, height) = (5, 4);
+        let original = Matrix::new(width, height, |address| address.y * 10 + address.x).unwrap();
+        let mut matrix = original.clone();
+        matrix.swap_rows(1, 3);
+        assert_eq!(
+            matrix,
+            Matrix::new(width, height, |address| {
+                [0, 3, 2, 1][address.y as usize] * 10 + address.x
+            })
+            .unwrap()
+        );
+        matrix.swap_rows(3, 1);
+        assert_eq!(matrix, original);
+        matrix.swap_rows(2, 2);
+        assert_eq!(matrix, original);
+    }
+
+    #[test]
+    fn swap_columns_test() {
+        let (width, height) = (5, 4);
+        let original = Matrix::new(width, height, |address| address.y * 10 + address.x).unwrap();
+        let mut matrix = original.clone();
+        matrix.swap_columns(4, 0);
+        assert_eq!(
+            matrix,
+            Matrix::new(width, height, |address| {
+                address.y * 10 + [4, 1, 2, 3, 0][address.x as usize]
+            })
+            .unwrap()
+        );
+        matrix.swap_columns(0, 4);
+        matrix.swap_columns(3, 3);
+        assert_eq!(matrix, original);
+        #[allow(deprecated)]
+        let record = matrix.swap_cols(1, 2);
+        assert_eq!(record, EditRecord::SwapColumns { a: 1, b: 2 });
+        matrix.swap_columns(2, 1);
+        assert_eq!(matrix, original);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index 4 is out of bounds for a 5x4 matrix")]
+    fn swap_rows_out_of_bounds_test() {
+        Matrix::new(5, 4, |_| 0).unwrap().swap_rows(0, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column index 5 is out of bounds for a 5x4 matrix")]
+    fn swap_columns_out_of_bounds_test() {
+        Matrix::new(5, 4, |_| 0).unwrap().swap_columns(5, 0);
+    }
+
+    #[test]
+    fn insert_into_empty_test() {
+        let mut matrix = Matrix::<i32>::from_rows(vec![]).unwrap();
+        matrix.insert_row(0, vec![1, 2, 3]).unwrap();
+        matrix.insert_row(0, vec![0, 0, 0]).unwrap();
+        assert!(matrix.insert_row(2, vec![1, 2]).is_err());
+        assert_eq!(
+            matrix,
+            Matrix::from_rows(vec![vec![0, 0, 0], vec![1, 2, 3]]).unwrap()
+        );
+
+        let mut matrix = Matrix::<i32>::from_rows(vec![]).unwrap();
+        matrix.insert_column(0, vec![1, 2]).unwrap();
+        matrix.insert_column(1, vec![3, 4]).unwrap();
+        assert!(matrix.insert_column(0, vec![5, 6, 7]).is_err());
+        assert_eq!(
+            matrix,
+            Matrix::from_rows(vec![vec![1, 3], vec![2, 4]]).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Column index 3 is out of bounds for inserting into a 2x2 matrix")]
+    fn insert_column_out_of_bounds_test() {
+        let mut matrix = Matrix::new(2, 2, |_| 0).unwrap();
+        let _ = matrix.insert_column(3, vec![1, 1]);
+    }
+
+    /// Checks every value of the matrix against a model kept as a list of rows.
+    fn assert_matches_model(matrix: &Matrix<i32>, model: &[Vec<i32>]) {
+        assert_eq!(matrix.height, model.len());
+        assert_eq!(
+            matrix.address_iter().count(),
+            model.iter().map(Vec::len).sum::<usize>()
+        );
+        for (address, value) in matrix.address_value_iter() {
+            assert_eq!(*value, model[address.y as usize][address.x as usize]);
+        }
+    }
+
+    #[test]
+    fn remove_until_empty_test() {
+        let original = Matrix::new(5, 4, |address| address.y * 10 + address.x).unwrap();
+        let original_model = original.rows().map(<[i32]>::to_vec).collect::<Vec<_>>();
+
+        let (mut matrix, mut model) = (original.clone(), original_model.clone());
+        for y in [2, 0, 1, 0] {
+            assert_eq!(matrix.remove_row(y).0, model.remove(y));
+            assert_matches_model(&matrix, &model);
+        }
+        assert_eq!((matrix.width, matrix.height), (5, 0));
+        assert_eq!(matrix.address_iter().next(), None);
+
+        let (mut matrix, mut model) = (original, original_model);
+        for x in [4, 1, 0, 1, 0] {
+            let removed = model
+                .iter_mut()
+                .map(|row| row.remove(x))
+                .collect::<Vec<_>>();
+            assert_eq!(matrix.remove_column(x).0, removed);
+            assert_matches_model(&matrix, &model);
+        }
+        assert_eq!((matrix.width, matrix.height), (0, 4));
+        assert_eq!(matrix.address_iter().next(), None);
+        assert_eq!(matrix.iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index 0 is out of bounds for a 3x0 matrix")]
+    fn remove_row_from_empty_test() {
+        let mut matrix = Matrix::new(3, 1, |_| 0).unwrap();
+        matrix.remove_row(0);
+        matrix.remove_row(0);
+    }
+
+    #[test]
+    fn concat_test() {
+        let a = Matrix::new(2, 3, |address| address.y * 10 + address.x).unwrap();
+        let b = Matrix::new(3, 3, |address| -(address.y * 10 + address.x)).unwrap();
+        let joined = Matrix::concat_horizontal(&a, &b).unwrap();
+        assert_eq!((joined.width, joined.height), (5, 3));
+        for (address, value) in joined.address_value_iter() {
+            let expected = if address.x < 2 {
+                a[address]
+            } else {
+                b[(address.x - 2, address.y)]
+            };
+            assert_eq!(*value, expected);
+        }
+        let c = Matrix::new(2, 4, |address| address.y * 100 + address.x).unwrap();
+        let stacked = Matrix::concat_vertical(&a, &c).unwrap();
+        assert_eq!((stacked.width, stacked.height), (2, 7));
+        for (address, value) in stacked.address_value_iter() {
+            let expected = if address.y < 3 {
+                a[address]
+            } else {
+                c[(address.x, address.y - 3)]
+            };
+            assert_eq!(*value, expected);
+        }
+        assert_eq!(
+            Matrix::concat_horizontal(&a, &c).unwrap_err().message,
+            "Cannot join matrices side by side with heights 3 and 4"
+        );
+        assert_eq!(
+            Matrix::concat_vertical(&a, &b).unwrap_err().message,
+            "Cannot join matrices top to bottom with widths 2 and 3"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Column index 4 is out of bounds for a 3x2 matrix")]
+    fn split_at_column_out_of_bounds_test() {
+        Matrix::new(3, 2, |_| 0).unwrap().split_at_column(4);
+    }
+
+    #[test]
+    fn stack_test() {
+        let tiles = [
+            Matrix::from_rows(vec![vec![1, 2], vec![5, 6]]).unwrap(),
+            Matrix::from_rows(vec![vec![3], vec![7]]).unwrap(),
+            Matrix::from_rows(vec![vec![], vec![]]).unwrap(),
+            Matrix::from_rows(vec![vec![4], vec![8]]).unwrap(),
+        ];
+        let tiles = tiles.iter().collect::<Vec<_>>();
+        let expected = Matrix::from_rows(vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]]).unwrap();
+        assert_eq!(Matrix::hstack(&tiles).unwrap(), expected);
+        assert_eq!(
+            tiles[0].concat_horizontal(tiles[1]).unwrap(),
+            Matrix::from_rows(vec![vec![1, 2, 3], vec![5, 6, 7]]).unwrap()
+        );
+
+        let map = Matrix::vstack(&[&expected, tiles[1], &expected]);
+        assert!(map.is_err());
+        let map = Matrix::vstack(&[&expected, &expected]).unwrap();
+        assert_eq!(map.dimensions(), (4, 4));
+        assert_eq!(map.row(3), Some([5, 6, 7, 8].as_slice()));
+
+        assert_eq!(Matrix::<i32>::hstack(&[]).unwrap().dimensions(), (0, 0));
+        assert_eq!(Matrix::<i32>::vstack(&[]).unwrap().dimensions(), (0, 0));
+        assert_eq!(
+            Matrix::hstack(&[tiles[0], &expected, &map])
+                .unwrap_err()
+                .message,
+            "Cannot join matrices side by side, matrix 2 has height 4 instead of 2"
+        );
+        assert_eq!(
+            Matrix::vstack(&[tiles[0], &expected]).unwrap_err().message,
+            "Cannot join matrices top to bottom, matrix 1 has width 4 instead of 2"
+        );
+    }
+
+    fn resized(width: usize, height: usize) -> Matrix<i32> {
+        let mut matrix = Matrix::new(4, 3, |address| address.y * 10 + address.x).unwrap();
+        matrix.resize(width, height, -1);
+        assert_eq!((matrix.width, matrix.height), (width, height));
+        matrix
+    }
+
+    fn expected(width: usize, height: usize) -> Matrix<i32> {
+        Matrix::new(width, height, |address| {
+            if address.x < 4 && address.y < 3 {
+                address.y * 10 + address.x
+            } else {
+                -1
+            }
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn resize_width_test() {
+        assert_eq!(resized(6, 3), expected(6, 3));
+        assert_eq!(resized(2, 3), expected(2, 3));
+        assert_eq!(resized(4, 3), expected(4, 3));
+    }
+
+    #[test]
+    fn resize_height_test() {
+        assert_eq!(resized(4, 5), expected(4, 5));
+        assert_eq!(resized(4, 1), expected(4, 1));
+    }
+
+    #[test]
+    fn resize_both_test() {
+        assert_eq!(resized(7, 1), expected(7, 1));
+        assert_eq!(resized(1, 6), expected(1, 6));
+        assert_eq!(resized(0, 0).address_iter().count(), 0);
+        let mut matrix = resized(0, 2);
+        matrix.resize(2, 2, 5);
+        assert_eq!(matrix, Matrix::new(2, 2, |_| 5).unwrap());
+    }
+
+    #[test]
+    fn resize_with_test() {
+        let mut matrix = Matrix::new(2, 2, |address| vec![address.x, address.y]).unwrap();
+        matrix.resize_with(3, 1, |address| vec![address.x * 100]);
+        assert_eq!(
+            matrix,
+            Matrix::from_rows(vec![vec![vec![0, 0], vec![1, 0], vec![200]]]).unwrap()
+        );
+    }
+
+    /// Applies `edit` to a matrix whose values are unique and checks that every address before the
+    /// edit, including ones at and next to the edit position, is remapped to the cell with its value.
+    fn assert_remaps(edit: impl FnOnce(&mut Matrix<i32>) -> EditRecord) {
+        let before = Matrix::new(5, 4, |address| address.y * 10 + address.x).unwrap();
+        let mut after = before.clone();
+        let record = edit(&mut after);
+        let addresses = before.address_iter().collect::<Vec<_>>();
+        let remapped = after.remap_addresses(&record, addresses.iter().copied());
+        for (address, new_address) in addresses.iter().zip(remapped) {
+            let survivor = after.iter().any(|value| *value == before[*address]);
+            assert_eq!(
+                new_address.is_some(),
+                survivor,
+                "{:?} {:?}",
+                record,
+                address
+            );
+            if let Some(new_address) = new_address {
+                assert_eq!(after[new_address], before[*address]);
+            }
+        }
+    }
+
+    #[test]
+    fn remap_test() {
+        for at in 0..=4 {
+            assert_remaps(|matrix| matrix.insert_row(at, vec![-1; 5]).unwrap());
+        }
+        for at in 0..=5 {
+            assert_remaps(|matrix| matrix.insert_column(at, vec![-1; 4]).unwrap());
+        }
+        for at in 0..4 {
+            assert_remaps(|matrix| matrix.remove_row(at).1);
+        }
+        for at in 0..5 {
+            assert_remaps(|matrix| matrix.remove_column(at).1);
+        }
+        for (a, b) in [(0, 3), (2, 1), (1, 1)] {
+            assert_remaps(|matrix| matrix.swap_rows(a, b));
+            assert_remaps(|matrix| matrix.swap_columns(a, b));
+        }
+        for (width, height) in [(3, 2), (7, 6), (5, 1), (0, 0)] {
+            assert_remaps(|matrix| matrix.resize(width, height, -1));
+        }
+    }
+
+    #[test]
+    fn remap_outside_test() {
+        let matrix = Matrix::new(2, 2, |_| 0).unwrap();
+        let outside = [MatrixAddress { x: -1, y: 0 }, MatrixAddress { x: 0, y: 2 }];
+        let edit = EditRecord::InsertColumn { at: 1 };
+        assert_eq!(
+            matrix.remap_addresses(&edit, outside).collect::<Vec<_>>(),
+            vec![None, None]
+        );
+    }
+
+    #[test]
+    fn submatrix_test() {
+        let matrix = Matrix::new(5, 4, |address| address.y * 10 + address.x).unwrap();
+        let corner = MatrixAddress { x: 4, y: 0 };
+        assert_eq!(
+            matrix.submatrix(corner, corner).unwrap(),
+            Matrix::from_rows(vec![vec![4]]).unwrap()
+        );
+        assert_eq!(
+            matrix
+                .submatrix(MatrixAddress { x: 0, y: 0 }, MatrixAddress { x: 4, y: 3 })
+                .unwrap(),
+            matrix
+        );
+        let inverted = matrix
+            .submatrix(MatrixAddress { x: 2, y: 2 }, MatrixAddress { x: 1, y: 3 })
+            .unwrap();
+        assert_eq!(inverted.iter().count(), 0);
+        for (top_left, bottom_right, offending) in [
+            ([-1, 0], [2, 2], [-1, 0]),
+            ([0, 0], [2, 4], [2, 4]),
+            ([5, 0], [9, 9], [5, 0]),
+        ] {
+            let error = matrix
+                .submatrix(top_left.into(), bottom_right.into())
+                .unwrap_err();
+            assert_eq!(error.address, MatrixAddress::from(offending));
+        }
+    }
+
+    #[test]
+    fn submatrix_clamped_test() {
+        let matrix = Matrix::new(5, 4, |address| address.y * 10 + address.x).unwrap();
+        let clamped = matrix.submatrix_clamped([-3, -3].into(), [100, 100].into());
+        assert_eq!(clamped, matrix);
+        let clamped = matrix.submatrix_clamped([3, -1].into(), [7, 0].into());
+        assert_eq!(clamped, Matrix::from_rows(vec![vec![3, 4]]).unwrap());
+        let disjoint = matrix.submatrix_clamped([5, 0].into(), [7, 2].into());
+        assert_eq!(disjoint.iter().count(), 0);
+    }
+
+    #[test]
+    fn reshape_test() {
+        let mut matrix = Matrix::new(6, 4, |address| address.y * 6 + address.x).unwrap();
+        assert_eq!(
+            matrix.reshape(5, 5).unwrap_err().message,
+            "Cannot reshape a 6x4 matrix to 5x5"
+        );
+        assert!(matrix.reshape_in_place(usize::MAX, 2).is_err());
+        assert_eq!(matrix.dimensions(), (6, 4));
+        for (width, height) in [(1, 24), (24, 1), (8, 3), (6, 4)] {
+            let reshaped = matrix.reshape(width, height).unwrap();
+            assert_eq!(
+                reshaped.iter().collect::<Vec<_>>(),
+                matrix.iter().collect::<Vec<_>>()
+            );
+            matrix.reshape_in_place(width, height).unwrap();
+            assert_eq!(matrix, reshaped);
+        }
+        let mut empty = Matrix::<i32>::from_rows(vec![vec![]; 3]).unwrap();
+        empty.reshape_in_place(7, 0).unwrap();
+        assert_eq!(empty.dimensions(), (7, 0));
+    }
+
+    #[test]
+    fn sort_stability_test() {
+        let mut table = Matrix::from_rows(vec![
+            vec!["b", "1"],
+            vec!["a", "2"],
+            vec!["b", "3"],
+            vec!["a", "4"],
+            vec!["c", "5"],
+            vec!["a", "6"],
+        ])
+        .unwrap();
+        table.sort_rows_by_key_column(0, |a, b| a.cmp(b));
+        assert_eq!(
+            table.column(1).unwrap(),
+            vec![&"2", &"4", &"6", &"1", &"3", &"5"]
+        );
+        table.sort_rows_by(|a, b| b[0].cmp(a[0]));
+        assert_eq!(
+            table.column(1).unwrap(),
+            vec![&"5", &"1", &"3", &"2", &"4", &"6"]
+        );
+
+        let mut matrix = Matrix::from_rows(vec![vec![2, 1, 2, 1], vec![0, 1, 2, 3]]).unwrap();
+        matrix.sort_columns_by_key_row(0, |a, b| a.cmp(b));
+        assert_eq!(
+            matrix,
+            Matrix::from_rows(vec![vec![1, 1, 2, 2], vec![1, 3, 0, 2]]).unwrap()
+        );
+        matrix.sort_columns_by(|a, b| a[0].cmp(b[0]).reverse());
+        assert_eq!(
+            matrix,
+            Matrix::from_rows(vec![vec![2, 2, 1, 1], vec![0, 2, 1, 3]]).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Column index 2 is out of bounds for a 2x1 matrix")]
+    fn sort_rows_by_key_column_out_of_bounds_test() {
+        Matrix::new(2, 1, |_| 0)
+            .unwrap()
+            .sort_rows_by_key_column(2, |a, b| a.cmp(b));
+    }
+
+    proptest! {
+        #[test]
+        fn sort_rows_matches_model_test(keys in proptest::collection::vec(0i32..4, 0..20), width in 1usize..4) {
+            let mut matrix = Matrix::from_parts(width, keys.len(), keys.iter().enumerate().flat_map(|(y, &key)| {
+                (0..width).map(move |x| if x == 0 { key } else { (y * width + x) as i32 })
+            }).collect());
+            let mut model = matrix.rows().map(<[i32]>::to_vec).collect::<Vec<_>>();
+            model.sort_by_key(|row| row[0]);
+            matrix.sort_rows_by_key_column(0, |a, b| a.cmp(b));
+            assert_matches_model(&matrix, &model);
+
+            let mut columns = Matrix::from_cols(model.clone()).unwrap();
+            columns.sort_columns_by(|a, b| b.cmp(a));
+            model.sort_by(|a, b| b.cmp(a));
+            assert_eq!(columns, Matrix::from_cols(model).unwrap());
+        }
+
+        #[test]
+        fn split_round_trip_test(width in 0usize..6, height in 0usize..6, at in 0usize..7) {
+            let matrix = Matrix::from_flat_vec(width, height, (0..width * height).collect()).unwrap();
+            if at <= height {
+                let (top, bottom) = matrix.clone().split_at_row(at);
+                assert_eq!(top.dimensions(), (width, at));
+                assert_eq!(bottom.dimensions(), (width, height - at));
+                assert_eq!(top.concat_vertical(&bottom).unwrap(), matrix);
+            }
+            if at <= width {
+                let (left, right) = matrix.clone().split_at_column(at);
+                assert_eq!(left.dimensions(), (at, height));
+                assert_eq!(right.dimensions(), (width - at, height));
+                assert_eq!(left.concat_horizontal(&right).unwrap(), matrix);
+            }
+        }
+
+        #[test]
+        fn structural_sequence_invariants_test(
+            operations in proptest::collection::vec((0u8..10, 0usize..8, 0usize..8), 0..40),
+        ) {
+            let mut matrix = Matrix::new(3, 3, |address| address.y * 3 + address.x).unwrap();
+            for (step, (operation, a, b)) in operations.into_iter().enumerate() {
+                let (width, height) = (matrix.width, matrix.height);
+                let fill = -(step as i32) - 1;
+                match operation {
+                    0 => matrix.insert_row(a.min(height), vec![fill; width]).map(|_| ()).unwrap(),
+                    1 => matrix.insert_column(a.min(width), vec![fill; height]).map(|_| ()).unwrap(),
+                    2 if height > 0 => drop(matrix.remove_row(a % height)),
+                    3 if width > 0 => drop(matrix.remove_column(a % width)),
+                    4 if height > 0 => drop(matrix.swap_rows(a % height, b % height)),
+                    5 if width > 0 => drop(matrix.swap_columns(a % width, b % width)),
+                    6 => drop(matrix.resize(a, b, fill)),
+                    7 => matrix = matrix.rotate_90_cw(),
+                    8 => matrix = matrix.flip_horizontal(),
+                    9 => matrix = matrix.pad(1, fill),
+                    _ => {}
+                }
+                assert!(matrix.check_invariants().is_ok());
+                if matrix.width > 0 && matrix.height > 0 {
+                    let displayed = matrix.to_display_string(|value| value.to_string(), " ", "|");
+                    let parsed = Matrix::parse_matrix(&displayed, " ", "|", |s| s.parse().unwrap()).unwrap();
+                    assert_eq!(parsed, matrix);
+                }
+            }
+        }
+
+        #[test]
+        fn insert_row_test(width in 1usize..10, height in 1usize..10, at in 0usize..10) {
+            let at = at.min(height);
+            let mut matrix = Matrix::new(width, height, |address| address.y * 100 + address.x).unwrap();
+            matrix.insert_row(at, (0..width as i32).map(|x| -1 - x).collect()).unwrap();
+            let expected = Matrix::new(width, height + 1, |address| match (address.y as usize).cmp(&at) {
+                std::cmp::Ordering::Less => address.y * 100 + address.x,
+                std::cmp::Ordering::Equal => -1 - address.x,
+                std::cmp::Ordering::Greater => (address.y - 1) * 100 + address.x,
+            })
+            .unwrap();
+            assert_eq!(matrix.clone(), expected);
+            assert!(matrix.insert_row(0, vec![0; width + 1]).is_err());
+            assert_eq!(matrix, expected);
+        }
+
+        #[test]
+        fn insert_column_test(width in 1usize..10, height in 1usize..10, at in 0usize..10) {
+            let at = at.min(width);
+            let mut matrix = Matrix::new(width, height, |address| address.y * 100 + address.x).unwrap();
+            matrix.insert_column(at, (0..height as i32).map(|y| -1 - y).collect()).unwrap();
+            let expected = Matrix::new(width + 1, height, |address| match (address.x as usize).cmp(&at) {
+                std::cmp::Ordering::Less => address.y * 100 + address.x,
+                std::cmp::Ordering::Equal => -1 - address.y,
+                std::cmp::Ordering::Greater => address.y * 100 + address.x - 1,
+            })
+            .unwrap();
+            assert_eq!(matrix.clone(), expected);
+            assert!(matrix.insert_column(0, vec![0; height + 1]).is_err());
+            assert_eq!(matrix, expected);
+        }
+    }
+}
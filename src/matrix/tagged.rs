@@ -0,0 +1,203 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::matrix_error::DimensionMismatchError;
+use crate::tensor::Tensor;
+
+/// A borrowed matrix paired with an owned matrix of metadata of the same dimensions, such as visited
+/// flags or path costs for a search over a map which must not change.
+///
+/// The values of the borrowed matrix are never copied, and only the metadata can be mutated.
+#[derive(Debug, Clone)]
+pub struct TaggedMatrix<'a, T, M> {
+    data: &'a Matrix<T>,
+    metadata: Matrix<M>,
+}
+
+impl<T> Matrix<T> {
+    /// Pairs the matrix with a matrix of metadata, one value per cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata`: The metadata, where the value at each address belongs to the cell at that address
+    ///
+    /// Returns: `Result<TaggedMatrix<T, M>, DimensionMismatchError>`, An Err if the dimensions of the
+    /// two matrices differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let map = Matrix::from_rows(vec![vec!['.', '#'], vec!['.', '.']]).unwrap();
+    /// let mut tagged = map.tag(Matrix::new(2, 2, |_| false).unwrap()).unwrap();
+    /// *tagged.get_mut_meta(MatrixAddress { x: 1, y: 0 }).unwrap() = true;
+    /// assert_eq!(tagged.get(MatrixAddress { x: 1, y: 0 }), Some((&'#', &true)));
+    /// assert!(map.tag(Matrix::new(1, 2, |_| false).unwrap()).is_err());
+    /// ```
+    pub fn tag<M>(
+        &self,
+        metadata: Matrix<M>,
+    ) -> Result<TaggedMatrix<'_, T, M>, DimensionMismatchError> {
+        self.check_same_dimensions(&metadata)?;
+        Ok(TaggedMatrix {
+            data: self,
+            metadata,
+        })
+    }
+
+    /// Pairs the matrix with metadata created for each cell from its address and value.
+    ///
+    /// # Arguments
+    ///
+    /// * `tagger`: Creates the metadata of a cell
+    ///
+    /// Returns: `TaggedMatrix<T, M>`
+    pub fn tag_with<M, F>(&self, tagger: F) -> TaggedMatrix<'_, T, M>
+    where
+        F: Fn(MatrixAddress, &T) -> M,
+    {
+        TaggedMatrix {
+            data: self,
+            metadata: Matrix::from_parts(
+                self.width,
+                self.height,
+                self.address_value_iter()
+                    .map(|(address, value)| tagger(address, value))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl<'a, T, M> TaggedMatrix<'a, T, M> {
+    /// Gets the value and metadata of a cell.
+    ///
+    /// Returns: `Option<(&T, &M)>`, None if the address is not contained in the matrix.
+    pub fn get(&self, address: MatrixAddress) -> Option<(&'a T, &M)> {
+        let index = self.data.index_of(address)?;
+        Some((&self.data.data[index], &self.metadata.data[index]))
+    }
+
+    /// Gets the metadata of a cell mutably.
+    ///
+    /// Returns: `Option<&mut M>`, None if the address is not contained in the matrix.
+    pub fn get_mut_meta(&mut self, address: MatrixAddress) -> Option<&mut M> {
+        self.metadata.get_mut(address)
+    }
+
+    /// Creates an iterator over every cell in row-major order, with its value and its metadata.
+    pub fn iter(&self) -> impl Iterator<Item = (MatrixAddress, &'a T, &M)> {
+        let data = self.data;
+        self.metadata
+            .address_value_iter()
+            .map(move |(address, metadata)| (address, &data[address], metadata))
+    }
+
+    /// Creates an iterator over every cell in row-major order, with its value and mutable access to
+    /// its metadata.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (MatrixAddress, &'a T, &mut M)> {
+        let width = self.metadata.width;
+        self.data
+            .data
+            .iter()
+            .zip(self.metadata.data.iter_mut())
+            .enumerate()
+            .map(move |(index, (value, metadata))| {
+                (
+                    Matrix::<M>::row_major_address(width, index),
+                    value,
+                    metadata,
+                )
+            })
+    }
+
+    /// Returns: `&'a Matrix<T>`, The borrowed matrix.
+    pub fn data(&self) -> &'a Matrix<T> {
+        self.data
+    }
+
+    /// Returns: `&Matrix<M>`, The matrix of metadata.
+    pub fn metadata(&self) -> &Matrix<M> {
+        &self.metadata
+    }
+
+    /// Releases the borrowed matrix, returning the metadata.
+    pub fn into_metadata(self) -> Matrix<M> {
+        self.metadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_address::MatrixAddress;
+    use std::collections::VecDeque;
+
+    const MAZE: &str = "..#...|.##.#.|....#.|.#..#.|...#..";
+
+    fn maze() -> Matrix<char> {
+        Matrix::parse_matrix(MAZE, "", "|", |s| s.chars().next().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn tagged_bfs_test() {
+        let map = maze();
+        let original = map.clone();
+        let mut tagged = map.tag_with(|_, _| None::<u32>);
+        let start = MatrixAddress { x: 0, y: 0 };
+        *tagged.get_mut_meta(start).unwrap() = Some(0);
+        let mut frontier = VecDeque::from([start]);
+        while let Some(address) = frontier.pop_front() {
+            let distance = tagged.get(address).unwrap().1.unwrap();
+            for step in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                let next = address + MatrixAddress::from([step.0, step.1]);
+                if let Some((&'.', None)) = tagged.get(next) {
+                    *tagged.get_mut_meta(next).unwrap() = Some(distance + 1);
+                    frontier.push_back(next);
+                }
+            }
+        }
+        // The map is still readable while the tagged matrix is alive.
+        assert_eq!(map, original);
+        assert_eq!(
+            tagged.get(MatrixAddress { x: 5, y: 4 }).unwrap().1,
+            &Some(13)
+        );
+        assert_eq!(
+            tagged.get(MatrixAddress { x: 3, y: 0 }).unwrap().1,
+            &Some(7)
+        );
+        assert_eq!(tagged.get(MatrixAddress { x: 2, y: 0 }).unwrap().1, &None);
+        assert_eq!(tagged.get(MatrixAddress { x: 6, y: 0 }), None);
+
+        for (address, value, distance) in tagged.iter_mut() {
+            assert_eq!(*value, map[address]);
+            if *value == '#' {
+                assert_eq!(*distance, None);
+                *distance = Some(u32::MAX);
+            }
+        }
+        let walls = tagged
+            .iter()
+            .filter(|(_, _, distance)| **distance == Some(u32::MAX))
+            .count();
+        assert_eq!(walls, MAZE.matches('#').count());
+        assert_eq!(
+            tagged
+                .into_metadata()
+                .iter()
+                .filter(|d| d.is_none())
+                .count(),
+            0
+        );
+        assert_eq!(map, original);
+    }
+
+    #[test]
+    fn tag_shape_mismatch_test() {
+        let map = maze();
+        assert!(map.tag(Matrix::new(6, 5, |_| 0u8).unwrap()).is_ok());
+        assert!(map.tag(Matrix::new(5, 6, |_| 0u8).unwrap()).is_err());
+    }
+}
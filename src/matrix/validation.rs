@@ -0,0 +1,197 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::tensor::Tensor;
+use std::fmt::Debug;
+
+type RuleCheck<T> = dyn Fn(MatrixAddress, &T, &Matrix<T>) -> Option<String>;
+
+/// A named check applied to every cell of a matrix by `Matrix::validate`.
+/// The check receives the address, the value and the whole matrix, so it may inspect neighbors,
+/// and returns a message describing the problem if the cell violates the rule.
+pub struct ValidationRule<T> {
+    pub name: String,
+    check: Box<RuleCheck<T>>,
+}
+
+/// A single failure of a validation rule at an address.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Violation {
+    pub rule_name: String,
+    pub address: MatrixAddress,
+    pub message: String,
+}
+
+impl<T> ValidationRule<T> {
+    /// Creates a rule from a name and a check.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: The name reported with every violation of the rule
+    /// * `check`: Returns `Some(message)` when the cell violates the rule, and `None` otherwise
+    pub fn new<F>(name: &str, check: F) -> Self
+    where
+        F: Fn(MatrixAddress, &T, &Matrix<T>) -> Option<String> + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            check: Box::new(check),
+        }
+    }
+
+    /// A rule requiring every value to lie within the inclusive range between `min` and `max`.
+    pub fn in_range(min: T, max: T) -> Self
+    where
+        T: PartialOrd + Debug + 'static,
+    {
+        Self::new("in_range", move |_, value, _| {
+            if *value < min || *value > max {
+                Some(format!(
+                    "{:?} is not between {:?} and {:?}",
+                    value, min, max
+                ))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// A rule requiring that no value equals the default value of its type.
+    pub fn no_default_values() -> Self
+    where
+        T: Default + PartialEq + Debug + 'static,
+    {
+        Self::new("no_default_values", |_, value, _| {
+            if *value == T::default() {
+                Some(format!("{:?} is the default value", value))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Checks every cell of the matrix against every rule, collecting all the violations
+    /// instead of stopping at the first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules`: The rules to check
+    ///
+    /// Returns: `Vec<Violation>`, The violations in row-major address order, and in rule order for
+    /// a single address. Empty if the matrix satisfies every rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::{Matrix, ValidationRule};
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 0, 3], vec![4, 5, 60]]).unwrap();
+    /// let violations = matrix.validate(&[ValidationRule::in_range(0, 9), ValidationRule::no_default_values()]);
+    /// assert_eq!(violations.len(), 2);
+    /// assert_eq!(violations[0].rule_name, "no_default_values");
+    /// assert_eq!(violations[1].rule_name, "in_range");
+    /// ```
+    pub fn validate(&self, rules: &[ValidationRule<T>]) -> Vec<Violation> {
+        self.address_value_iter()
+            .flat_map(|(address, value)| {
+                rules.iter().filter_map(move |rule| {
+                    (rule.check)(address, value, self).map(|message| Violation {
+                        rule_name: rule.name.clone(),
+                        address,
+                        message,
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{Matrix, ValidationRule};
+    use crate::matrix_address::MatrixAddress;
+    use crate::tensor::Tensor;
+
+    fn maze(data_str: &str) -> Matrix<char> {
+        Matrix::parse_matrix(data_str, "", "\n", |s| s.chars().next().unwrap()).unwrap()
+    }
+
+    fn border_rule() -> ValidationRule<char> {
+        ValidationRule::new("border_is_wall", |address, value, matrix| {
+            let largest = matrix.largest_contained_address();
+            let on_border = address.x == 0
+                || address.y == 0
+                || address.x == largest.x
+                || address.y == largest.y;
+            (on_border && *value != '#').then(|| format!("{:?} on the border", value))
+        })
+    }
+
+    fn start_exit_rule() -> ValidationRule<char> {
+        ValidationRule::new("start_has_exit", |address, value, matrix| {
+            if *value != 'S' {
+                return None;
+            }
+            let has_exit = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .into_iter()
+                .filter_map(|(x, y)| matrix.get(address + MatrixAddress { x, y }))
+                .any(|neighbor| *neighbor != '#');
+            (!has_exit).then(|| "start is walled in".to_string())
+        })
+    }
+
+    #[test]
+    fn maze_validation_test() {
+        let valid = maze("#####\n#S..#\n#.#E#\n#####");
+        assert!(
+            valid
+                .validate(&[border_rule(), start_exit_rule()])
+                .is_empty()
+        );
+
+        let invalid = maze("##.##\n#S#.#\n###S.\n#####");
+        let violations = invalid.validate(&[border_rule(), start_exit_rule()]);
+        let summary = violations
+            .iter()
+            .map(|violation| {
+                (
+                    violation.rule_name.as_str(),
+                    violation.address.x,
+                    violation.address.y,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("border_is_wall", 2, 0),
+                ("start_has_exit", 1, 1),
+                ("border_is_wall", 4, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn built_in_rules_test() {
+        let matrix = Matrix::from_rows(vec![vec![-1.0, 0.0, 0.5], vec![2.0, 0.25, 0.0]]).unwrap();
+        let violations = matrix.validate(&[
+            ValidationRule::in_range(0.0, 1.0),
+            ValidationRule::no_default_values(),
+        ]);
+        let summary = violations
+            .iter()
+            .map(|violation| (violation.rule_name.as_str(), violation.address))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("in_range", MatrixAddress { x: 0, y: 0 }),
+                ("no_default_values", MatrixAddress { x: 1, y: 0 }),
+                ("in_range", MatrixAddress { x: 0, y: 1 }),
+                ("no_default_values", MatrixAddress { x: 2, y: 1 }),
+            ]
+        );
+        assert_eq!(violations[0].message, "-1.0 is not between 0.0 and 1.0");
+    }
+}
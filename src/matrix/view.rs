@@ -0,0 +1,369 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::tensor::Tensor;
+use std::ops::{Index, IndexMut};
+
+/// A borrowed rectangular region of a matrix, addressed in its own coordinates so that its top left
+/// cell is at `(0, 0)`.
+///
+/// A view implements `Tensor`, so algorithms written against `Tensor` can read a region without
+/// copying it. It cannot be used to change the matrix.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T> {
+    matrix: &'a Matrix<T>,
+    top_left: MatrixAddress,
+    width: usize,
+    height: usize,
+}
+
+/// A mutably borrowed rectangular region of a matrix, addressed in its own coordinates so that its top
+/// left cell is at `(0, 0)`.
+///
+/// Every write through the view stays inside its rectangle: `get_mut` returns None for addresses
+/// outside of it, and indexing with them panics.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    matrix: &'a mut Matrix<T>,
+    top_left: MatrixAddress,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Matrix<T> {
+    /// Creates a view of the rectangular region between two corners, including both.
+    /// A rectangle whose bottom right corner lies above or left of its top left corner gives an
+    /// empty view.
+    ///
+    /// # Arguments
+    ///
+    /// * `top_left`: The address of the top left cell of the region
+    /// * `bottom_right`: The address of the bottom right cell of the region
+    ///
+    /// Returns: `Option<MatrixView<T>>`, None if either corner lies outside of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    /// use rust_tensors::tensor::Tensor;
+    ///
+    /// let matrix = Matrix::new(4, 4, |address| address.y * 4 + address.x).unwrap();
+    /// let view = matrix.view(MatrixAddress { x: 1, y: 2 }, MatrixAddress { x: 3, y: 3 }).unwrap();
+    /// assert_eq!(view[(0, 0)], 9);
+    /// assert_eq!(view.get(MatrixAddress { x: 2, y: 1 }), Some(&15));
+    /// assert_eq!(view.get(MatrixAddress { x: 3, y: 0 }), None);
+    /// assert_eq!(view.address_value_iter().map(|(_, value)| value).sum::<i32>(), 72);
+    /// ```
+    pub fn view(
+        &self,
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+    ) -> Option<MatrixView<'_, T>> {
+        if !self.contains_address(top_left) || !self.contains_address(bottom_right) {
+            return None;
+        }
+        Some(MatrixView {
+            matrix: self,
+            top_left,
+            width: (bottom_right.x - top_left.x + 1).max(0) as usize,
+            height: (bottom_right.y - top_left.y + 1).max(0) as usize,
+        })
+    }
+
+    /// Creates a mutable view of the rectangular region between two corners, including both.
+    /// A rectangle whose bottom right corner lies above or left of its top left corner gives an
+    /// empty view.
+    ///
+    /// # Arguments
+    ///
+    /// * `top_left`: The address of the top left cell of the region
+    /// * `bottom_right`: The address of the bottom right cell of the region
+    ///
+    /// Returns: `Option<MatrixViewMut<T>>`, None if either corner lies outside of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let mut map = Matrix::new(4, 3, |_| '.').unwrap();
+    /// let mut sprite = map.view_mut(MatrixAddress { x: 1, y: 1 }, MatrixAddress { x: 2, y: 2 }).unwrap();
+    /// sprite.fill('#');
+    /// sprite[(1, 0)] = '@';
+    /// assert_eq!(map.to_display_string(|c| *c, "", "\n"), "....\n.#@.\n.##.");
+    /// ```
+    pub fn view_mut(
+        &mut self,
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+    ) -> Option<MatrixViewMut<'_, T>> {
+        if !self.contains_address(top_left) || !self.contains_address(bottom_right) {
+            return None;
+        }
+        Some(MatrixViewMut {
+            matrix: self,
+            top_left,
+            width: (bottom_right.x - top_left.x + 1).max(0) as usize,
+            height: (bottom_right.y - top_left.y + 1).max(0) as usize,
+        })
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Returns: `&'a Matrix<T>`, The matrix the view borrows from.
+    pub fn matrix(&self) -> &'a Matrix<T> {
+        self.matrix
+    }
+
+    /// Returns: `MatrixAddress`, The address in the viewed matrix of the view's `(0, 0)`.
+    pub fn offset(&self) -> MatrixAddress {
+        self.top_left
+    }
+
+    /// Copies the values of the view into a new matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let matrix = Matrix::new(3, 3, |address| address.y * 3 + address.x).unwrap();
+    /// let corner = MatrixAddress { x: 2, y: 2 };
+    /// assert_eq!(matrix.view(corner, corner).unwrap().to_matrix(), Matrix::from_rows(vec![vec![8]]).unwrap());
+    /// ```
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let bottom_right = MatrixAddress {
+            x: self.top_left.x + self.width as i32 - 1,
+            y: self.top_left.y + self.height as i32 - 1,
+        };
+        self.matrix.region(self.top_left, bottom_right)
+    }
+}
+
+impl<'a, T: 'a> Tensor<'a, T, i32, MatrixAddress, 2> for MatrixView<'a, T> {
+    fn smallest_contained_address(&self) -> MatrixAddress {
+        MatrixAddress { x: 0, y: 0 }
+    }
+
+    fn largest_contained_address(&self) -> MatrixAddress {
+        MatrixAddress {
+            x: self.width as i32 - 1,
+            y: self.height as i32 - 1,
+        }
+    }
+}
+
+impl<T> Index<MatrixAddress> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: MatrixAddress) -> &Self::Output {
+        assert!(
+            self.contains_address(index),
+            "Address ({}, {}) is out of bounds for a {}x{} view",
+            index.x,
+            index.y,
+            self.width,
+            self.height
+        );
+        &self.matrix[self.top_left + index]
+    }
+}
+
+impl<T> Index<(i32, i32)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: (i32, i32)) -> &Self::Output {
+        &self[MatrixAddress {
+            x: index.0,
+            y: index.1,
+        }]
+    }
+}
+
+impl<T> MatrixViewMut<'_, T> {
+    /// Returns: `MatrixAddress`, The address in the viewed matrix of the view's `(0, 0)`.
+    pub fn offset(&self) -> MatrixAddress {
+        self.top_left
+    }
+
+    /// Sets every cell of the view to a clone of the value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The new value of every cell in the view
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        let (x, y) = (self.top_left.x as usize, self.top_left.y as usize);
+        for row in self.matrix.rows_mut().skip(y).take(self.height) {
+            row[x..x + self.width].fill(value.clone());
+        }
+    }
+
+    /// Panics unless the address lies inside the view.
+    fn check_address(&self, address: MatrixAddress) {
+        assert!(
+            self.contains_address(address),
+            "Address ({}, {}) is out of bounds for a {}x{} view",
+            address.x,
+            address.y,
+            self.width,
+            self.height
+        );
+    }
+}
+
+impl<'a, T: 'a> Tensor<'a, T, i32, MatrixAddress, 2> for MatrixViewMut<'_, T> {
+    fn smallest_contained_address(&self) -> MatrixAddress {
+        MatrixAddress { x: 0, y: 0 }
+    }
+
+    fn largest_contained_address(&self) -> MatrixAddress {
+        MatrixAddress {
+            x: self.width as i32 - 1,
+            y: self.height as i32 - 1,
+        }
+    }
+}
+
+impl<T> Index<MatrixAddress> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: MatrixAddress) -> &Self::Output {
+        self.check_address(index);
+        &self.matrix[self.top_left + index]
+    }
+}
+
+impl<T> Index<(i32, i32)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: (i32, i32)) -> &Self::Output {
+        &self[MatrixAddress {
+            x: index.0,
+            y: index.1,
+        }]
+    }
+}
+
+impl<T> IndexMut<MatrixAddress> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, index: MatrixAddress) -> &mut Self::Output {
+        self.check_address(index);
+        let top_left = self.top_left;
+        &mut self.matrix[top_left + index]
+    }
+}
+
+impl<T> IndexMut<(i32, i32)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, index: (i32, i32)) -> &mut Self::Output {
+        &mut self[MatrixAddress {
+            x: index.0,
+            y: index.1,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_address::MatrixAddress;
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    /// A fold written only against `Tensor`, so it runs on both matrices and views.
+    fn weighted_sum<'a, M: Tensor<'a, i64, i32, MatrixAddress, 2>>(tensor: &'a M) -> i64 {
+        tensor
+            .address_value_iter()
+            .map(|(address, value)| value * (address.x as i64 + 2 * address.y as i64 + 1))
+            .sum()
+    }
+
+    #[test]
+    fn view_bounds_test() {
+        let matrix = Matrix::new(4, 3, |address| address.x as i64).unwrap();
+        assert!(matrix.view([0, 0].into(), [4, 0].into()).is_none());
+        assert!(matrix.view([-1, 0].into(), [2, 2].into()).is_none());
+        let empty = matrix.view([2, 2].into(), [1, 2].into()).unwrap();
+        assert_eq!(empty.address_iter().count(), 0);
+        let view = matrix.view([1, 1].into(), [2, 2].into()).unwrap();
+        assert!(view.contains_address([1, 1].into()));
+        assert!(!view.contains_address([2, 1].into()));
+        assert_eq!(view.offset(), MatrixAddress { x: 1, y: 1 });
+    }
+
+    #[test]
+    #[should_panic(expected = "Address (2, 0) is out of bounds for a 2x2 view")]
+    fn view_index_out_of_bounds_test() {
+        let matrix = Matrix::new(4, 3, |_| 0).unwrap();
+        let view = matrix.view([1, 1].into(), [2, 2].into()).unwrap();
+        let _ = view[(2, 0)];
+    }
+
+    #[test]
+    fn view_mut_test() {
+        let original = Matrix::new(5, 4, |address| address.y * 10 + address.x).unwrap();
+        let mut matrix = original.clone();
+        let mut view = matrix.view_mut([1, 1].into(), [3, 2].into()).unwrap();
+        assert_eq!(view[(0, 0)], 11);
+        assert_eq!(view.get_mut([3, 0].into()), None);
+        assert_eq!(view.get_mut([0, -1].into()), None);
+        *view.get_mut([2, 1].into()).unwrap() = -1;
+        view[(0, 1)] = -2;
+        for (address, value) in matrix.address_value_iter() {
+            let expected = match (address.x, address.y) {
+                (3, 2) => -1,
+                (1, 2) => -2,
+                _ => original[address],
+            };
+            assert_eq!(*value, expected);
+        }
+    }
+
+    #[test]
+    fn view_mut_fill_test() {
+        let original = Matrix::new(5, 4, |address| address.y * 10 + address.x).unwrap();
+        let mut matrix = original.clone();
+        matrix
+            .view_mut([2, 1].into(), [4, 3].into())
+            .unwrap()
+            .fill(0);
+        for (address, value) in matrix.address_value_iter() {
+            let inside = address.x >= 2 && address.y >= 1;
+            assert_eq!(*value, if inside { 0 } else { original[address] });
+        }
+        assert!(matrix.view_mut([2, 1].into(), [5, 3].into()).is_none());
+        matrix
+            .view_mut([2, 1].into(), [1, 3].into())
+            .unwrap()
+            .fill(9);
+        assert!(matrix.iter().all(|value| *value != 9));
+    }
+
+    #[test]
+    #[should_panic(expected = "Address (0, 2) is out of bounds for a 3x2 view")]
+    fn view_mut_index_out_of_bounds_test() {
+        let mut matrix = Matrix::new(5, 4, |_| 0).unwrap();
+        let mut view = matrix.view_mut([1, 1].into(), [3, 2].into()).unwrap();
+        view[(0, 2)] = 1;
+    }
+
+    proptest! {
+        #[test]
+        fn view_matches_submatrix_test(x0 in 0i32..6, y0 in 0i32..5, x1 in 0i32..6, y1 in 0i32..5) {
+            let matrix = Matrix::new(6, 5, |address| (address.x * 31 - address.y * 17) as i64).unwrap();
+            let (top_left, bottom_right) = (MatrixAddress { x: x0, y: y0 }, MatrixAddress { x: x1, y: y1 });
+            let view = matrix.view(top_left, bottom_right).unwrap();
+            let submatrix = matrix.submatrix(top_left, bottom_right).unwrap();
+            assert_eq!(weighted_sum(&view), weighted_sum(&submatrix));
+            assert_eq!(view.address_iter().count(), submatrix.address_iter().count());
+            assert_eq!(view.to_matrix(), submatrix);
+        }
+    }
+}
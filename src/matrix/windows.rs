@@ -0,0 +1,437 @@
+use crate::address_iterator::AddressIterator;
+use crate::matrix::{Matrix, MatrixView};
+use crate::matrix_address::MatrixAddress;
+use std::collections::VecDeque;
+
+/// Iterates over every placement of a rectangular window inside a matrix, in row-major order of the
+/// window's top left corner, yielding the corner and a copy of the values under the window.
+///
+/// Created by `Matrix::windows`.
+pub struct WindowIter<'a, T> {
+    matrix: &'a Matrix<T>,
+    window_width: usize,
+    window_height: usize,
+    corners: AddressIterator<i32, MatrixAddress, 2>,
+}
+
+impl<T> Matrix<T> {
+    /// Creates an iterator over every overlapping window of the given size which fits inside the
+    /// matrix, moving one cell at a time in row-major order.
+    /// A window larger than the matrix in either dimension fits nowhere, so the iterator is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_width`: The width of each window
+    /// * `window_height`: The height of each window
+    ///
+    /// Returns: `WindowIter<'_, T>`, yielding the address of each window's top left corner
+    /// together with a matrix of clones of the values it covers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either window dimension is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+    /// let sums: Vec<(MatrixAddress, i32)> = matrix
+    ///     .windows(2, 2)
+    ///     .map(|(corner, window)| (corner, window.iter().sum()))
+    ///     .collect();
+    /// assert_eq!(sums[0], (MatrixAddress { x: 0, y: 0 }, 12));
+    /// assert_eq!(sums[3], (MatrixAddress { x: 1, y: 1 }, 28));
+    /// assert_eq!(sums.len(), 4);
+    /// ```
+    pub fn windows(&self, window_width: usize, window_height: usize) -> WindowIter<'_, T>
+    where
+        T: Clone,
+    {
+        WindowIter {
+            matrix: self,
+            window_width,
+            window_height,
+            corners: self.window_corners(window_width, window_height),
+        }
+    }
+
+    /// Creates an iterator over every overlapping window of the given size which fits inside the
+    /// matrix, like `windows`, but borrowing each window as a view instead of copying its values.
+    /// A window larger than the matrix in either dimension fits nowhere, so the iterator is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_width`: The width of each window
+    /// * `window_height`: The height of each window
+    ///
+    /// Returns: `impl ExactSizeIterator<Item = (MatrixAddress, MatrixView<T>)>`, yielding the address
+    /// of each window's top left corner together with a view of it, for
+    /// `(width - window_width + 1) * (height - window_height + 1)` windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either window dimension is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    /// use rust_tensors::tensor::Tensor;
+    ///
+    /// let matrix = Matrix::new(5, 4, |address| (address.x * 3 + address.y * 7) % 10).unwrap();
+    /// let (brightest, _) = matrix
+    ///     .window_views(3, 3)
+    ///     .max_by_key(|(_, view)| view.address_value_iter().map(|(_, value)| value).sum::<i32>())
+    ///     .unwrap();
+    /// assert_eq!(brightest, MatrixAddress { x: 2, y: 0 });
+    /// assert_eq!(matrix.window_views(3, 3).len(), 6);
+    /// assert_eq!(matrix.window_views(6, 1).len(), 0);
+    /// ```
+    pub fn window_views(
+        &self,
+        window_width: usize,
+        window_height: usize,
+    ) -> impl ExactSizeIterator<Item = (MatrixAddress, MatrixView<'_, T>)> {
+        self.window_corners(window_width, window_height)
+            .map(move |corner| {
+                let bottom_right = MatrixAddress {
+                    x: corner.x + window_width as i32 - 1,
+                    y: corner.y + window_height as i32 - 1,
+                };
+                (corner, self.view(corner, bottom_right).unwrap())
+            })
+    }
+
+    /// The top left corners of every placement of a window inside the matrix.
+    fn window_corners(
+        &self,
+        window_width: usize,
+        window_height: usize,
+    ) -> AddressIterator<i32, MatrixAddress, 2> {
+        assert!(
+            window_width > 0 && window_height > 0,
+            "Window dimensions must be nonzero, got {}x{}",
+            window_width,
+            window_height
+        );
+        // Compared as usize first, since a window larger than `i32::MAX` would wrap when cast.
+        if window_width > self.width || window_height > self.height {
+            return AddressIterator::new([0, 0], [-1, -1]);
+        }
+        AddressIterator::new(
+            [0, 0],
+            [
+                (self.width - window_width) as i32,
+                (self.height - window_height) as i32,
+            ],
+        )
+    }
+}
+
+impl<T: Clone> Iterator for WindowIter<'_, T> {
+    type Item = (MatrixAddress, Matrix<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let corner = self.corners.next()?;
+        let bottom_right = MatrixAddress {
+            x: corner.x + self.window_width as i32 - 1,
+            y: corner.y + self.window_height as i32 - 1,
+        };
+        Some((corner, self.matrix.region(corner, bottom_right)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.corners.size_hint()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for WindowIter<'_, T> {}
+
+impl<T: PartialOrd + Clone> Matrix<T> {
+    /// Computes for each cell the largest value of the horizontal window ending at that cell, which
+    /// covers the cell and up to `window - 1` cells to its left.
+    /// Runs in time proportional to the size of the matrix, regardless of the window size.
+    ///
+    /// # Arguments
+    ///
+    /// * `window`: The width of the window, where windows near the left edge are cut off by it
+    ///
+    /// Returns: `Matrix<T>`, of the same dimensions as the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![3, 1, 4, 1, 5, 9, 2, 6]]).unwrap();
+    /// assert_eq!(matrix.sliding_row_max(3), Matrix::from_rows(vec![vec![3, 3, 4, 4, 5, 9, 9, 9]]).unwrap());
+    /// ```
+    pub fn sliding_row_max(&self, window: usize) -> Matrix<T> {
+        self.sliding_region_max(window, 1)
+    }
+
+    /// Computes for each cell the smallest value of the horizontal window ending at that cell,
+    /// as `sliding_row_max` does for the largest.
+    ///
+    /// # Arguments
+    ///
+    /// * `window`: The width of the window, where windows near the left edge are cut off by it
+    ///
+    /// Returns: `Matrix<T>`, of the same dimensions as the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window is zero.
+    pub fn sliding_row_min(&self, window: usize) -> Matrix<T> {
+        self.sliding_region_min(window, 1)
+    }
+
+    /// Computes for each cell the largest value of the rectangular window whose bottom right corner is
+    /// that cell, which is max-pooling with a stride of one.
+    /// Runs in time proportional to the size of the matrix, regardless of the window size.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_width`: The width of the window, where windows near the left edge are cut off by it
+    /// * `window_height`: The height of the window, where windows near the top edge are cut off by it
+    ///
+    /// Returns: `Matrix<T>`, of the same dimensions as the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either window dimension is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 5, 2], vec![4, 3, 0], vec![0, 1, 2]]).unwrap();
+    /// assert_eq!(
+    ///     matrix.sliding_region_max(2, 2),
+    ///     Matrix::from_rows(vec![vec![1, 5, 5], vec![4, 5, 5], vec![4, 4, 3]]).unwrap()
+    /// );
+    /// ```
+    pub fn sliding_region_max(&self, window_width: usize, window_height: usize) -> Matrix<T> {
+        self.sliding_region_extreme(window_width, window_height, |new, kept| new >= kept)
+    }
+
+    /// Computes for each cell the smallest value of the rectangular window whose bottom right corner
+    /// is that cell, as `sliding_region_max` does for the largest.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_width`: The width of the window, where windows near the left edge are cut off by it
+    /// * `window_height`: The height of the window, where windows near the top edge are cut off by it
+    ///
+    /// Returns: `Matrix<T>`, of the same dimensions as the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either window dimension is zero.
+    pub fn sliding_region_min(&self, window_width: usize, window_height: usize) -> Matrix<T> {
+        self.sliding_region_extreme(window_width, window_height, |new, kept| new <= kept)
+    }
+
+    /// Finds the extreme of every window with a horizontal pass over the rows followed by a vertical
+    /// pass over the row results, both working on indices into the data so values are cloned once.
+    fn sliding_region_extreme(
+        &self,
+        window_width: usize,
+        window_height: usize,
+        supersedes: fn(&T, &T) -> bool,
+    ) -> Matrix<T> {
+        assert!(
+            window_width > 0 && window_height > 0,
+            "Window dimensions must be nonzero, got {}x{}",
+            window_width,
+            window_height
+        );
+        let width = self.width;
+        let mut row_best = Vec::with_capacity(self.len());
+        for start in (0..self.height).map(|y| y * width) {
+            sliding_extreme(
+                width,
+                window_width,
+                |x| &self.data[start + x],
+                supersedes,
+                |_, best_x| row_best.push(start + best_x),
+            );
+        }
+        let mut region_best = vec![0; self.len()];
+        for x in 0..width {
+            sliding_extreme(
+                self.height,
+                window_height,
+                |y| &self.data[row_best[y * width + x]],
+                supersedes,
+                |y, best_y| region_best[y * width + x] = row_best[best_y * width + x],
+            );
+        }
+        Matrix::from_parts(
+            width,
+            self.height,
+            region_best
+                .into_iter()
+                .map(|index| self.data[index].clone())
+                .collect(),
+        )
+    }
+}
+
+/// Reports for each position of a sequence the position of the extreme value among it and the
+/// `window - 1` positions before it, using a deque of the positions which could still become the
+/// extreme of a later window.
+///
+/// A value supersedes an earlier one when `supersedes(value, earlier)` is true, which removes the
+/// earlier position from the deque for good.
+fn sliding_extreme<'a, T: 'a>(
+    length: usize,
+    window: usize,
+    value: impl Fn(usize) -> &'a T,
+    supersedes: fn(&T, &T) -> bool,
+    mut report: impl FnMut(usize, usize),
+) {
+    let mut candidates = VecDeque::new();
+    for position in 0..length {
+        while candidates
+            .back()
+            .is_some_and(|&kept| supersedes(value(position), value(kept)))
+        {
+            candidates.pop_back();
+        }
+        candidates.push_back(position);
+        if candidates
+            .front()
+            .is_some_and(|&first| first + window <= position)
+        {
+            candidates.pop_front();
+        }
+        report(position, candidates[0]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    #[test]
+    fn windows_larger_than_matrix_test() {
+        let matrix = Matrix::new(3, 2, |address| address.x).unwrap();
+        assert_eq!(matrix.windows(4, 1).count(), 0);
+        assert_eq!(matrix.windows(1, 3).count(), 0);
+        assert_eq!(matrix.windows(3_000_000_000, 1).len(), 0);
+        assert_eq!(matrix.window_views(1, 3_000_000_000).len(), 0);
+        let whole = matrix.windows(3, 2).collect::<Vec<_>>();
+        assert_eq!(whole.len(), 1);
+        assert_eq!(whole[0].1, matrix);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window dimensions must be nonzero, got 2x0")]
+    fn zero_window_view_test() {
+        let _ = Matrix::new(3, 2, |_| 0).unwrap().window_views(2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window dimensions must be nonzero, got 0x1")]
+    fn zero_window_test() {
+        Matrix::new(3, 2, |_| 0).unwrap().windows(0, 1);
+    }
+
+    /// Computes the extreme of every window ending at each cell by looking at every cell of it.
+    fn brute_force(
+        matrix: &Matrix<i32>,
+        window_width: usize,
+        window_height: usize,
+        pick: fn(i32, i32) -> i32,
+    ) -> Matrix<i32> {
+        Matrix::new(matrix.width, matrix.height, |address| {
+            let (x, y) = (address.x as usize, address.y as usize);
+            let xs = (x + 1).saturating_sub(window_width)..=x;
+            let ys = (y + 1).saturating_sub(window_height)..=y;
+            ys.flat_map(|y| xs.clone().map(move |x| (x, y)))
+                .map(|(x, y)| matrix[(x as i32, y as i32)])
+                .reduce(pick)
+                .unwrap()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[should_panic(expected = "Window dimensions must be nonzero, got 0x1")]
+    fn zero_sliding_window_test() {
+        Matrix::new(3, 2, |_| 0).unwrap().sliding_row_max(0);
+    }
+
+    proptest! {
+        #[test]
+        fn window_views_sum_test(width in 1usize..7, height in 1usize..7, window_width in 1usize..8, window_height in 1usize..8) {
+            let matrix = Matrix::new(width, height, |address| address.x * 11 - address.y * 5 + 3).unwrap();
+            let views = matrix.window_views(window_width, window_height);
+            let expected_count = (width + 1).saturating_sub(window_width) * (height + 1).saturating_sub(window_height);
+            assert_eq!(views.len(), expected_count);
+            let mut count = 0;
+            for (corner, view) in views {
+                let sum = view.address_value_iter().map(|(_, value)| value).sum::<i32>();
+                let mut expected = 0;
+                for y in 0..window_height as i32 {
+                    for x in 0..window_width as i32 {
+                        expected += matrix[(corner.x + x, corner.y + y)];
+                    }
+                }
+                assert_eq!(sum, expected);
+                count += 1;
+            }
+            assert_eq!(count, expected_count);
+            assert!(matrix.window_views(window_width, window_height).map(|(corner, _)| corner).eq(matrix.windows(window_width, window_height).map(|(corner, _)| corner)));
+        }
+
+        #[test]
+        fn windows_test(width in 1usize..8, height in 1usize..8, window_width in 1usize..5, window_height in 1usize..5) {
+            let matrix = Matrix::new(width, height, |address| address.y * 100 + address.x).unwrap();
+            let windows = matrix.windows(window_width, window_height);
+            let expected_count = (width + 1).saturating_sub(window_width) * (height + 1).saturating_sub(window_height);
+            assert_eq!(windows.len(), expected_count);
+            let mut corners = Vec::new();
+            for (corner, window) in windows {
+                assert_eq!((window.width, window.height), (window_width, window_height));
+                for (offset, value) in window.address_value_iter() {
+                    assert_eq!(*value, matrix[corner + offset]);
+                }
+                corners.push(corner);
+            }
+            assert_eq!(corners.len(), expected_count);
+            assert!(corners.windows(2).all(|pair| (pair[0].y, pair[0].x) < (pair[1].y, pair[1].x)));
+        }
+
+        #[test]
+        fn sliding_extremes_test(
+            rows in proptest::collection::vec(proptest::collection::vec(-20i32..20, 7), 1..9),
+            window_width in 1usize..10,
+            window_height in 1usize..12,
+        ) {
+            let matrix = Matrix::from_rows(rows).unwrap();
+            assert_eq!(matrix.sliding_row_max(window_width), brute_force(&matrix, window_width, 1, i32::max));
+            assert_eq!(matrix.sliding_row_min(window_width), brute_force(&matrix, window_width, 1, i32::min));
+            assert_eq!(
+                matrix.sliding_region_max(window_width, window_height),
+                brute_force(&matrix, window_width, window_height, i32::max)
+            );
+            assert_eq!(
+                matrix.sliding_region_min(window_width, window_height),
+                brute_force(&matrix, window_width, window_height, i32::min)
+            );
+        }
+    }
+}
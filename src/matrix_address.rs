@@ -1,13 +1,78 @@
 use crate::adressable::Addressable;
-use std::ops::{Add, Neg, Sub};
+use crate::matrix::EditRecord;
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MatrixAddress {
     pub x: i32,
     pub y: i32,
 }
 
 impl MatrixAddress {
+    /// The Manhattan, or L1, distance to another address, the number of orthogonal steps between
+    /// them.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The address to measure to
+    ///
+    /// Returns: `i32`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let from = MatrixAddress { x: 1, y: 5 };
+    /// assert_eq!(from.manhattan_distance(MatrixAddress { x: 4, y: 3 }), 5);
+    /// ```
+    pub fn manhattan_distance(self, other: MatrixAddress) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The Chebyshev, or L∞, distance to another address, the number of steps between them when
+    /// diagonal steps are allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The address to measure to
+    ///
+    /// Returns: `i32`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let from = MatrixAddress { x: 1, y: 5 };
+    /// assert_eq!(from.chebyshev_distance(MatrixAddress { x: 4, y: 3 }), 3);
+    /// ```
+    pub fn chebyshev_distance(self, other: MatrixAddress) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The Euclidean, or L2, distance to another address.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The address to measure to
+    ///
+    /// Returns: `f64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let from = MatrixAddress { x: 1, y: 5 };
+    /// assert_eq!(from.euclidean_distance(MatrixAddress { x: 4, y: 1 }), 5.0);
+    /// ```
+    pub fn euclidean_distance(self, other: MatrixAddress) -> f64 {
+        f64::from(self.x - other.x).hypot(f64::from(self.y - other.y))
+    }
+
     /// Scales the position of the matrix address by the floating point scalar.
     /// Epsilon is added to the results before truncation to avoid floating point precision issues
     /// # Arguments
@@ -36,6 +101,103 @@ impl MatrixAddress {
             y: y as i32,
         }
     }
+
+    /// Finds the cell of a coarse grid, with cells of `cell_size` by `cell_size` addresses, which
+    /// contains the address. Coordinates are divided rounding towards negative infinity, so that
+    /// -1 belongs to the cell before 0 rather than sharing cell 0 with it.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell_size`: The width and height of a coarse cell
+    ///
+    /// Returns: `MatrixAddress`, The address of the coarse cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// assert_eq!(MatrixAddress { x: 7, y: 8 }.bucket(4), MatrixAddress { x: 1, y: 2 });
+    /// assert_eq!(MatrixAddress { x: -1, y: -4 }.bucket(4), MatrixAddress { x: -1, y: -1 });
+    /// assert_eq!(MatrixAddress { x: -5, y: 3 }.bucket(4), MatrixAddress { x: -2, y: 0 });
+    /// ```
+    pub fn bucket(&self, cell_size: u32) -> MatrixAddress {
+        assert!(cell_size != 0, "Bucket size must be nonzero");
+        let floor = |value: i32| i64::from(value).div_euclid(i64::from(cell_size)) as i32;
+        MatrixAddress {
+            x: floor(self.x),
+            y: floor(self.y),
+        }
+    }
+
+    /// Translates an address computed before a structural edit of a matrix
+    /// to the address of the same cell after the edit.
+    ///
+    /// # Arguments
+    ///
+    /// * `edit`: The description of the edit, as returned by it
+    ///
+    /// Returns: `Option<MatrixAddress>`, The new address of the cell, or None if the edit removed it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::EditRecord;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    ///
+    /// let address = MatrixAddress { x: 3, y: 2 };
+    /// assert_eq!(address.remap(&EditRecord::InsertRow { at: 2 }), Some(MatrixAddress { x: 3, y: 3 }));
+    /// assert_eq!(address.remap(&EditRecord::RemoveColumn { at: 1 }), Some(MatrixAddress { x: 2, y: 2 }));
+    /// assert_eq!(address.remap(&EditRecord::RemoveColumn { at: 3 }), None);
+    /// assert_eq!(address.remap(&EditRecord::SwapRows { a: 0, b: 2 }), Some(MatrixAddress { x: 3, y: 0 }));
+    /// ```
+    pub fn remap(&self, edit: &EditRecord) -> Option<MatrixAddress> {
+        let insert = |value: i32, at: usize| {
+            if value >= at as i32 { value + 1 } else { value }
+        };
+        let remove = |value: i32, at: usize| match value.cmp(&(at as i32)) {
+            Ordering::Less => Some(value),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(value - 1),
+        };
+        let swap = |value: i32, a: usize, b: usize| {
+            if value == a as i32 {
+                b as i32
+            } else if value == b as i32 {
+                a as i32
+            } else {
+                value
+            }
+        };
+        let Self { x, y } = *self;
+        match *edit {
+            EditRecord::InsertRow { at } => Some(Self {
+                x,
+                y: insert(y, at),
+            }),
+            EditRecord::InsertColumn { at } => Some(Self {
+                x: insert(x, at),
+                y,
+            }),
+            EditRecord::RemoveRow { at } => remove(y, at).map(|y| Self { x, y }),
+            EditRecord::RemoveColumn { at } => remove(x, at).map(|x| Self { x, y }),
+            EditRecord::SwapRows { a, b } => Some(Self {
+                x,
+                y: swap(y, a, b),
+            }),
+            EditRecord::SwapColumns { a, b } => Some(Self {
+                x: swap(x, a, b),
+                y,
+            }),
+            EditRecord::Resize { width, height } => {
+                (x < width as i32 && y < height as i32).then_some(*self)
+            }
+        }
+    }
 }
 
 impl Addressable<i32, 2usize> for MatrixAddress {
@@ -57,9 +219,9 @@ impl From<[i32; 2]> for MatrixAddress {
     }
 }
 
-impl Into<[i32; 2]> for MatrixAddress {
-    fn into(self) -> [i32; 2] {
-        [self.x, self.y]
+impl From<MatrixAddress> for [i32; 2] {
+    fn from(value: MatrixAddress) -> Self {
+        [value.x, value.y]
     }
 }
 
@@ -96,6 +258,37 @@ impl Neg for MatrixAddress {
     }
 }
 
+impl Mul<i32> for MatrixAddress {
+    type Output = MatrixAddress;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        MatrixAddress {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl Mul<MatrixAddress> for i32 {
+    type Output = MatrixAddress;
+
+    fn mul(self, rhs: MatrixAddress) -> Self::Output {
+        rhs * self
+    }
+}
+
+/// Divides both coordinates with integer division, rounding towards zero like `i32` division.
+impl Div<i32> for MatrixAddress {
+    type Output = MatrixAddress;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        MatrixAddress {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::adressable::Addressable;
@@ -122,6 +315,85 @@ mod tests {
 
             let a1 = MatrixAddress{x: x1, y: y1};
             assert_eq!(a1.scale(s as f64), MatrixAddress{x: a1.x * s, y: a1.y * s});
+            assert_eq!(a1 * s, s * a1);
+            assert_eq!(a1 * s, a1.scale(s as f64));
+            assert_eq!((a1 + a2) * s, a1 * s + a2 * s);
+            if s != 0 {
+                assert_eq!(a1 * s / s, a1);
+                assert_eq!(a1 / s, MatrixAddress{x: x1 / s, y: y1 / s});
+            }
         }
+
+        #[test]
+        fn distance_test(points in proptest::array::uniform3((-10000i32..10000, -10000i32..10000))) {
+            let [a, b, c] = points.map(|(x, y)| MatrixAddress { x, y });
+            for (p, q) in [(a, b), (b, c), (a, c)] {
+                assert_eq!(p.manhattan_distance(q), q.manhattan_distance(p));
+                assert_eq!(p.chebyshev_distance(q), q.chebyshev_distance(p));
+                assert_eq!(p.euclidean_distance(q), q.euclidean_distance(p));
+                assert!(p.chebyshev_distance(q) as f64 <= p.euclidean_distance(q));
+                assert!(p.euclidean_distance(q) <= p.manhattan_distance(q) as f64);
+            }
+            assert!(a.manhattan_distance(c) <= a.manhattan_distance(b) + b.manhattan_distance(c));
+            assert!(a.chebyshev_distance(c) <= a.chebyshev_distance(b) + b.chebyshev_distance(c));
+            assert!(a.euclidean_distance(c) <= a.euclidean_distance(b) + b.euclidean_distance(c) + 1e-9);
+            assert_eq!(a.manhattan_distance(a), 0);
+            assert_eq!(a.chebyshev_distance(a), 0);
+            assert_eq!(a.euclidean_distance(a), 0.0);
+        }
+
+        #[test]
+        fn bucket_test(x in -1000i32..1000, y in -1000i32..1000, cell_size in 1u32..50) {
+            let address = MatrixAddress { x, y };
+            let bucket = address.bucket(cell_size);
+            let size = cell_size as i32;
+            assert!((bucket.x * size..(bucket.x + 1) * size).contains(&x));
+            assert!((bucket.y * size..(bucket.y + 1) * size).contains(&y));
+        }
+    }
+
+    #[test]
+    fn scalar_division_test() {
+        let address = MatrixAddress { x: 7, y: -7 };
+        assert_eq!(address / 2, MatrixAddress { x: 3, y: -3 });
+        assert_eq!(address / -7, MatrixAddress { x: -1, y: 1 });
+        assert_eq!(address.bucket(2), MatrixAddress { x: 3, y: -4 });
+    }
+
+    #[test]
+    #[should_panic]
+    fn divide_by_zero_test() {
+        let _ = MatrixAddress { x: 1, y: 1 } / 0;
+    }
+
+    #[test]
+    fn bucket_boundary_test() {
+        let buckets = (-5..5)
+            .map(|x| MatrixAddress { x, y: -x }.bucket(3))
+            .map(|bucket| (bucket.x, bucket.y))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            buckets,
+            vec![
+                (-2, 1),
+                (-2, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, 0),
+                (0, 0),
+                (0, -1),
+                (0, -1),
+                (1, -1),
+                (1, -2)
+            ]
+        );
+        assert_eq!(
+            MatrixAddress {
+                x: i32::MIN,
+                y: i32::MAX
+            }
+            .bucket(u32::MAX),
+            MatrixAddress { x: -1, y: 0 }
+        );
     }
 }
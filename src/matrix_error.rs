@@ -0,0 +1,162 @@
+use crate::matrix_address::MatrixAddress;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error returned when the dimensions of two matrices are incompatible for an operation.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DimensionMismatchError {
+    pub message: String,
+}
+
+impl Display for DimensionMismatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for DimensionMismatchError {}
+
+/// The error returned when an address lies outside of a matrix.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OutOfBoundsError {
+    pub address: MatrixAddress,
+}
+
+impl Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Address ({}, {}) is out of bounds",
+            self.address.x, self.address.y
+        )
+    }
+}
+
+impl Error for OutOfBoundsError {}
+
+/// The error returned when an operation which requires a square matrix is given a non-square matrix.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NotSquareError {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Display for NotSquareError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Matrix is not square: {}x{}", self.width, self.height)
+    }
+}
+
+impl Error for NotSquareError {}
+
+/// The error returned when a matrix cannot be inverted.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InversionError {
+    NotSquare(NotSquareError),
+    /// The matrix has no inverse, because its determinant is zero or negligibly small.
+    Singular,
+}
+
+impl Display for InversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InversionError::NotSquare(error) => write!(f, "Cannot invert: {}", error),
+            InversionError::Singular => write!(f, "Cannot invert: Matrix is singular"),
+        }
+    }
+}
+
+impl Error for InversionError {}
+
+impl From<NotSquareError> for InversionError {
+    fn from(value: NotSquareError) -> Self {
+        InversionError::NotSquare(value)
+    }
+}
+
+/// The general error type for fallible matrix operations.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum MatrixError {
+    DimensionMismatch(DimensionMismatchError),
+    OutOfBounds(OutOfBoundsError),
+    NotSquare(NotSquareError),
+    /// A row index was not smaller than the height of the matrix.
+    RowOutOfBounds {
+        row: usize,
+        height: usize,
+    },
+    /// A region whose top left corner lies below or to the right of its bottom right corner.
+    InvalidRegion {
+        top_left: MatrixAddress,
+        bottom_right: MatrixAddress,
+    },
+    /// The matrix is singular, or so close to singular that the result would be meaningless.
+    Singular,
+    /// Reading or writing failed. Holds the kind and description of the underlying `std::io::Error`.
+    Io {
+        kind: std::io::ErrorKind,
+        message: String,
+    },
+    /// An operation of a sequence failed. `index` is the position of the failed operation.
+    OpFailed {
+        index: usize,
+        error: Box<MatrixError>,
+    },
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixError::DimensionMismatch(error) => write!(f, "{}", error),
+            MatrixError::OutOfBounds(error) => write!(f, "{}", error),
+            MatrixError::NotSquare(error) => write!(f, "{}", error),
+            MatrixError::RowOutOfBounds { row, height } => write!(
+                f,
+                "Row {} is out of bounds for a matrix of height {}",
+                row, height
+            ),
+            MatrixError::InvalidRegion {
+                top_left,
+                bottom_right,
+            } => write!(
+                f,
+                "Region from ({}, {}) to ({}, {}) is inverted",
+                top_left.x, top_left.y, bottom_right.x, bottom_right.y
+            ),
+            MatrixError::Singular => write!(f, "Matrix is singular"),
+            MatrixError::Io { message, .. } => write!(f, "IO error: {}", message),
+            MatrixError::OpFailed { index, error } => {
+                write!(f, "Operation {} failed: {}", index, error)
+            }
+        }
+    }
+}
+
+impl Error for MatrixError {}
+
+impl From<DimensionMismatchError> for MatrixError {
+    fn from(value: DimensionMismatchError) -> Self {
+        MatrixError::DimensionMismatch(value)
+    }
+}
+
+impl From<OutOfBoundsError> for MatrixError {
+    fn from(value: OutOfBoundsError) -> Self {
+        MatrixError::OutOfBounds(value)
+    }
+}
+
+impl From<NotSquareError> for MatrixError {
+    fn from(value: NotSquareError) -> Self {
+        MatrixError::NotSquare(value)
+    }
+}
+
+impl From<std::io::Error> for MatrixError {
+    fn from(value: std::io::Error) -> Self {
+        MatrixError::Io {
+            kind: value.kind(),
+            message: value.to_string(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::adressable::Addressable;
+use std::ops::{Add, Neg, Sub};
+
+/// An address into an `N` dimensional tensor, holding one coordinate per dimension.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct NDAddress<const N: usize>(pub [i32; N]);
+
+impl<const N: usize> Addressable<i32, N> for NDAddress<N> {
+    fn get_value_at_dimension_index(&self, index: usize) -> i32 {
+        match self.0.get(index) {
+            Some(value) => *value,
+            None => panic!("Invalid Dimension Index"),
+        }
+    }
+}
+
+impl<const N: usize> From<[i32; N]> for NDAddress<N> {
+    fn from(value: [i32; N]) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize> From<NDAddress<N>> for [i32; N] {
+    fn from(value: NDAddress<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> Add for NDAddress<N> {
+    type Output = NDAddress<N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        NDAddress(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<const N: usize> Sub for NDAddress<N> {
+    type Output = NDAddress<N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        NDAddress(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<const N: usize> Neg for NDAddress<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        NDAddress(self.0.map(|value| -value))
+    }
+}
@@ -0,0 +1,270 @@
+use crate::matrix_error::DimensionMismatchError;
+use crate::nd_address::NDAddress;
+use crate::tensor::Tensor;
+use std::ops::{Index, IndexMut};
+
+/// A tensor with any number of dimensions `N`, stored in a flat vector.
+/// Dimension 0 varies fastest, matching the order of `address_iter`,
+/// so a two dimensional `NDTensor` has the same layout as a `Matrix`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct NDTensor<T, const N: usize> {
+    shape: [usize; N],
+    strides: [usize; N],
+    data: Vec<T>,
+}
+
+impl<T, const N: usize> NDTensor<T, N> {
+    /// Creates a new NDTensor based on a shape and a mapper function.
+    /// Will return None if and only if `N` is zero, any dimension of the shape is zero,
+    /// or the shape holds more values than fit in a `usize`.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: The number of values along each dimension
+    /// * `address_value_converter`: Converts an address to a value.
+    ///
+    /// Returns: `Option<NDTensor<T, N>>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::nd_address::NDAddress;
+    /// use rust_tensors::nd_tensor::NDTensor;
+    ///
+    /// let tensor = NDTensor::new([2, 3, 4, 5], |address| address.0.iter().sum::<i32>()).unwrap();
+    /// assert_eq!(tensor[NDAddress([1, 2, 3, 4])], 10);
+    /// ```
+    pub fn new<F>(shape: [usize; N], address_value_converter: F) -> Option<Self>
+    where
+        F: Fn(NDAddress<N>) -> T,
+    {
+        if N == 0 || shape.contains(&0) {
+            return None;
+        }
+        let count = Self::value_count(shape)?;
+        let mut tensor = NDTensor {
+            shape,
+            strides: Self::strides(shape),
+            data: Vec::with_capacity(count),
+        };
+        tensor
+            .address_iter()
+            .for_each(|address| tensor.data.push(address_value_converter(address)));
+        Some(tensor)
+    }
+
+    /// Creates a tensor from a shape and its values, ordered with dimension 0 varying fastest.
+    /// Will return an Err if `N` is zero, any dimension of the shape is zero,
+    /// or the number of values is not the product of the shape.
+    /// A shape whose product overflows a `usize` is rejected as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: The number of values along each dimension
+    /// * `data`: The values of the tensor
+    ///
+    /// Returns: `Result<NDTensor<T, N>, DimensionMismatchError>`
+    pub fn from_flat_vec(shape: [usize; N], data: Vec<T>) -> Result<Self, DimensionMismatchError> {
+        if N == 0 || shape.contains(&0) || Self::value_count(shape) != Some(data.len()) {
+            return Err(DimensionMismatchError {
+                message: format!(
+                    "A tensor of shape {:?} cannot hold {} values",
+                    shape,
+                    data.len()
+                ),
+            });
+        }
+        Ok(NDTensor {
+            shape,
+            strides: Self::strides(shape),
+            data,
+        })
+    }
+
+    /// The number of values along each dimension.
+    pub fn shape(&self) -> [usize; N] {
+        self.shape
+    }
+
+    /// The product of the shape, None if it overflows.
+    fn value_count(shape: [usize; N]) -> Option<usize> {
+        shape
+            .iter()
+            .try_fold(1usize, |count, &length| count.checked_mul(length))
+    }
+
+    fn strides(shape: [usize; N]) -> [usize; N] {
+        let mut strides = [1; N];
+        for dimension in 1..N {
+            strides[dimension] = strides[dimension - 1] * shape[dimension - 1];
+        }
+        strides
+    }
+
+    /// Finds the position of an address in the backing storage of the tensor, where dimension 0
+    /// varies fastest.
+    ///
+    /// # Arguments
+    ///
+    /// * `address`: The address to locate
+    ///
+    /// Returns: `Option<usize>`, None if the address is not contained in the tensor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::nd_address::NDAddress;
+    /// use rust_tensors::nd_tensor::NDTensor;
+    ///
+    /// let tensor = NDTensor::new([2, 3, 4], |_| 0).unwrap();
+    /// assert_eq!(tensor.index_of(NDAddress([1, 2, 3])), Some(23));
+    /// assert_eq!(tensor.index_of(NDAddress([2, 0, 0])), None);
+    /// ```
+    pub fn index_of(&self, address: NDAddress<N>) -> Option<usize> {
+        self.contains_address(address).then(|| {
+            address
+                .0
+                .iter()
+                .zip(&self.strides)
+                .map(|(&coordinate, &stride)| coordinate as usize * stride)
+                .sum()
+        })
+    }
+
+    /// Finds the address of a position in the backing storage of the tensor, the inverse of
+    /// `index_of`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The position in the backing storage
+    ///
+    /// Returns: `Option<NDAddress<N>>`, None if the index is not smaller than the number of values.
+    pub fn address_of(&self, index: usize) -> Option<NDAddress<N>> {
+        (index < self.data.len()).then(|| {
+            NDAddress(std::array::from_fn(|dimension| {
+                (index / self.strides[dimension] % self.shape[dimension]) as i32
+            }))
+        })
+    }
+
+    fn index_address(&self, address: NDAddress<N>) -> usize {
+        self.index_of(address).unwrap_or_else(|| {
+            panic!(
+                "Address {:?} is out of bounds for a tensor of shape {:?}",
+                address.0, self.shape
+            )
+        })
+    }
+}
+
+impl<'a, T: 'a, const N: usize> Tensor<'a, T, i32, NDAddress<N>, N> for NDTensor<T, N> {
+    fn smallest_contained_address(&self) -> NDAddress<N> {
+        NDAddress([0; N])
+    }
+
+    fn largest_contained_address(&self) -> NDAddress<N> {
+        NDAddress(self.shape.map(|length| (length - 1) as i32))
+    }
+}
+
+impl<T, const N: usize> Index<NDAddress<N>> for NDTensor<T, N> {
+    type Output = T;
+
+    fn index(&self, index: NDAddress<N>) -> &Self::Output {
+        &self.data[self.index_address(index)]
+    }
+}
+
+impl<T, const N: usize> IndexMut<NDAddress<N>> for NDTensor<T, N> {
+    fn index_mut(&mut self, index: NDAddress<N>) -> &mut Self::Output {
+        let index = self.index_address(index);
+        &mut self.data[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::nd_address::NDAddress;
+    use crate::nd_tensor::NDTensor;
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    #[test]
+    fn strides_test() {
+        let tensor = NDTensor::new([2, 3, 4, 5], |_| 0u8).unwrap();
+        assert_eq!(tensor.strides, [1, 2, 6, 24]);
+        assert_eq!(tensor.data.len(), 120);
+        assert_eq!(tensor.index_address(NDAddress([1, 2, 3, 4])), 119);
+        assert_eq!(tensor.index_address(NDAddress([1, 0, 1, 0])), 7);
+    }
+
+    #[test]
+    fn iteration_order_test() {
+        let tensor = NDTensor::new([3, 2, 4, 2, 3], |_| 0u8).unwrap();
+        tensor
+            .address_iter()
+            .enumerate()
+            .for_each(|(i, address)| assert_eq!(tensor.index_address(address), i));
+        assert_eq!(tensor.address_iter().count(), 144);
+    }
+
+    #[test]
+    fn matches_matrix_test() {
+        let (width, height) = (7, 5);
+        let matrix = Matrix::new(width, height, |address| address.x * 100 + address.y).unwrap();
+        let tensor =
+            NDTensor::new([width, height], |address| address.0[0] * 100 + address.0[1]).unwrap();
+        assert_eq!(tensor.data, matrix.into_iter().collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn shape_validation_test() {
+        assert!(NDTensor::new([3, 0, 2], |_| 0).is_none());
+        assert!(NDTensor::<i32, 0>::new([], |_| 0).is_none());
+        assert!(NDTensor::from_flat_vec([2, 3], vec![0; 5]).is_err());
+        assert!(NDTensor::from_flat_vec([2, 0], Vec::<i32>::new()).is_err());
+        assert!(NDTensor::new([usize::MAX, 2, 2], |_| 0).is_none());
+        assert!(NDTensor::from_flat_vec([usize::MAX / 2, 3, 1], Vec::<i32>::new()).is_err());
+        let tensor = NDTensor::from_flat_vec([2, 3], (0..6).collect()).unwrap();
+        assert_eq!(tensor.shape(), [2, 3]);
+        assert_eq!(tensor[NDAddress([1, 2])], 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Address [3, 0] is out of bounds for a tensor of shape [3, 2]")]
+    fn index_out_of_bounds_test() {
+        let tensor = NDTensor::new([3, 2], |_| 0).unwrap();
+        let _ = tensor[NDAddress([3, 0])];
+    }
+
+    #[test]
+    fn set_test() {
+        let mut tensor = NDTensor::new([4, 3, 2, 2], |_| 0usize).unwrap();
+        let addresses = tensor.address_iter().collect::<Vec<NDAddress<4>>>();
+        for address in addresses {
+            tensor[address] = tensor.index_address(address);
+        }
+        tensor
+            .address_value_iter()
+            .for_each(|(address, value)| assert_eq!(*value, tensor.index_address(address)));
+    }
+
+    proptest! {
+        #[test]
+        fn contains_address_test(a in -5..5, b in -5..5, c in -5..5, shape in proptest::array::uniform3(1usize..5)) {
+            let tensor = NDTensor::new(shape, |_| 0u8).unwrap();
+            let address = NDAddress([a, b, c]);
+            let inside = address.0.iter().zip(&shape).all(|(&v, &l)| v >= 0 && v < l as i32);
+            assert_eq!(tensor.contains_address(address), inside);
+        }
+
+        #[test]
+        fn arithmetic_test(a in proptest::array::uniform4(-1000i32..1000), b in proptest::array::uniform4(-1000i32..1000)) {
+            let (a, b) = (NDAddress(a), NDAddress(b));
+            assert_eq!(a - b, a + (-b));
+            assert_eq!(a + b - b, a);
+            assert_eq!(-(-a), a);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::matrix::Matrix;
+use crate::matrix_address::MatrixAddress;
+use crate::tensor::Tensor;
+use std::ops::{Add, Index, IndexMut, Mul};
+
+/// A matrix whose width `W` and height `H` are part of its type, stored inline as an array of rows.
+///
+/// Operations between fixed-size matrices check their shapes at compile time,
+/// so a product of incompatible matrices does not compile rather than returning an Err.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SMatrix<T, const W: usize, const H: usize> {
+    rows: [[T; W]; H],
+}
+
+impl<T, const W: usize, const H: usize> SMatrix<T, W, H> {
+    /// Creates a new SMatrix based on a mapper function.
+    ///
+    /// # Arguments
+    ///
+    /// * `address_value_converter`: Converts an address to a value.
+    ///
+    /// Returns: `SMatrix<T, W, H>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::s_matrix::SMatrix;
+    ///
+    /// let matrix = SMatrix::<i32, 3, 2>::new(|address| address.x + 3 * address.y);
+    /// assert_eq!(matrix, SMatrix::from_rows([[0, 1, 2], [3, 4, 5]]));
+    /// ```
+    pub fn new<F>(address_value_converter: F) -> Self
+    where
+        F: Fn(MatrixAddress) -> T,
+    {
+        SMatrix {
+            rows: std::array::from_fn(|y| {
+                std::array::from_fn(|x| {
+                    address_value_converter(MatrixAddress {
+                        x: x as i32,
+                        y: y as i32,
+                    })
+                })
+            }),
+        }
+    }
+
+    /// Creates a matrix from an array of rows, from top to bottom.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: The rows of the matrix
+    ///
+    /// Returns: `SMatrix<T, W, H>`
+    pub fn from_rows(rows: [[T; W]; H]) -> Self {
+        SMatrix { rows }
+    }
+
+    /// Consumes the matrix, returning its array of rows.
+    pub fn into_rows(self) -> [[T; W]; H] {
+        self.rows
+    }
+
+    /// Creates the transpose of the matrix, whose rows are the columns of this matrix.
+    ///
+    /// Returns: `SMatrix<T, H, W>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::s_matrix::SMatrix;
+    ///
+    /// let matrix = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(matrix.transpose(), SMatrix::from_rows([[1, 4], [2, 5], [3, 6]]));
+    /// ```
+    pub fn transpose(&self) -> SMatrix<T, H, W>
+    where
+        T: Clone,
+    {
+        SMatrix {
+            rows: std::array::from_fn(|y| std::array::from_fn(|x| self.rows[x][y].clone())),
+        }
+    }
+
+    /// Computes the matrix product of this matrix and `rhs`.
+    /// The width of this matrix must equal the height of `rhs`, which is checked at compile time.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs`: The matrix to multiply by, on the right
+    ///
+    /// Returns: `SMatrix<T, W2, H>`, with the width of `rhs` and the height of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::s_matrix::SMatrix;
+    ///
+    /// let a = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let b = SMatrix::from_rows([[7, 8], [9, 10], [11, 12]]);
+    /// assert_eq!(a.mat_mul(&b), SMatrix::from_rows([[58, 64], [139, 154]]));
+    /// assert_eq!(&a * &b, SMatrix::from_rows([[58, 64], [139, 154]]));
+    /// assert_eq!(a * b, SMatrix::from_rows([[58, 64], [139, 154]]));
+    /// ```
+    ///
+    /// Multiplying matrices of incompatible shapes does not compile:
+    ///
+    /// ```compile_fail
+    /// use rust_tensors::s_matrix::SMatrix;
+    ///
+    /// let a = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let b = SMatrix::from_rows([[7, 8], [9, 10]]);
+    /// let _ = a.mat_mul(&b);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rust_tensors::s_matrix::SMatrix;
+    ///
+    /// let a = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let _ = a * a;
+    /// ```
+    pub fn mat_mul<const W2: usize>(&self, rhs: &SMatrix<T, W2, W>) -> SMatrix<T, W2, H>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Default + Clone,
+    {
+        SMatrix {
+            rows: std::array::from_fn(|y| {
+                std::array::from_fn(|x| {
+                    (0..W).fold(T::default(), |sum, k| {
+                        sum + self.rows[y][k].clone() * rhs.rows[k][x].clone()
+                    })
+                })
+            }),
+        }
+    }
+}
+
+impl<T, const W: usize, const H: usize, const W2: usize> Mul<SMatrix<T, W2, W>> for SMatrix<T, W, H>
+where
+    T: Add<Output = T> + Mul<Output = T> + Default + Clone,
+{
+    type Output = SMatrix<T, W2, H>;
+
+    fn mul(self, rhs: SMatrix<T, W2, W>) -> Self::Output {
+        self.mat_mul(&rhs)
+    }
+}
+
+impl<T, const W: usize, const H: usize, const W2: usize> Mul<&SMatrix<T, W2, W>>
+    for &SMatrix<T, W, H>
+where
+    T: Add<Output = T> + Mul<Output = T> + Default + Clone,
+{
+    type Output = SMatrix<T, W2, H>;
+
+    fn mul(self, rhs: &SMatrix<T, W2, W>) -> Self::Output {
+        self.mat_mul(rhs)
+    }
+}
+
+impl<T, const W: usize, const H: usize> From<SMatrix<T, W, H>> for Matrix<T> {
+    fn from(value: SMatrix<T, W, H>) -> Self {
+        Matrix::from_flat_vec(W, H, value.rows.into_iter().flatten().collect()).unwrap()
+    }
+}
+
+impl<'a, T: 'a, const W: usize, const H: usize> Tensor<'a, T, i32, MatrixAddress, 2>
+    for SMatrix<T, W, H>
+{
+    fn smallest_contained_address(&self) -> MatrixAddress {
+        MatrixAddress { x: 0, y: 0 }
+    }
+
+    fn largest_contained_address(&self) -> MatrixAddress {
+        MatrixAddress {
+            x: W as i32 - 1,
+            y: H as i32 - 1,
+        }
+    }
+}
+
+impl<T, const W: usize, const H: usize> Index<MatrixAddress> for SMatrix<T, W, H> {
+    type Output = T;
+
+    fn index(&self, index: MatrixAddress) -> &Self::Output {
+        &self.rows[index.y as usize][index.x as usize]
+    }
+}
+
+impl<T, const W: usize, const H: usize> IndexMut<MatrixAddress> for SMatrix<T, W, H> {
+    fn index_mut(&mut self, index: MatrixAddress) -> &mut Self::Output {
+        &mut self.rows[index.y as usize][index.x as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::matrix_address::MatrixAddress;
+    use crate::s_matrix::SMatrix;
+    use crate::tensor::Tensor;
+    use proptest::proptest;
+
+    #[test]
+    fn transpose_test() {
+        let matrix = SMatrix::<i32, 4, 2>::new(|address| address.x * 10 + address.y);
+        let transposed = matrix.transpose();
+        for address in matrix.address_iter() {
+            assert_eq!(
+                matrix[address],
+                transposed[MatrixAddress {
+                    x: address.y,
+                    y: address.x
+                }]
+            );
+        }
+        assert_eq!(transposed.transpose(), matrix);
+    }
+
+    #[test]
+    fn into_matrix_test() {
+        let matrix = Matrix::from(SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(
+            matrix,
+            Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+        );
+        assert_eq!(matrix.address_iter().count(), 6);
+    }
+
+    proptest! {
+        #[test]
+        fn mat_mul_agrees_with_matrix_test(seed in -1000i64..1000) {
+            let a = SMatrix::<i64, 3, 4>::new(|address| seed * address.x as i64 - 7 * address.y as i64);
+            let b = SMatrix::<i64, 5, 3>::new(|address| (address.x * address.y) as i64 + seed);
+            let product: SMatrix<i64, 5, 4> = a * b;
+            assert_eq!(product, a.mat_mul(&b));
+            assert_eq!(
+                Matrix::from(product),
+                Matrix::from(a).mat_mul(&Matrix::from(b)).unwrap()
+            );
+            assert_eq!(b.transpose() * a.transpose(), product.transpose());
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::{address_iterator::AddressIterator, adressable::AddressValue};
 use std::ops::{Index, IndexMut};
 
 pub trait Tensor<'a, T: 'a, V: AddressValue, A: Addressable<V, DIMENSION>, const DIMENSION: usize>:
-    Index<A, Output = T> + IndexMut<A, Output = T>
+    Index<A, Output = T>
 {
     fn smallest_contained_address(&self) -> A;
     fn largest_contained_address(&self) -> A;
@@ -32,7 +32,10 @@ pub trait Tensor<'a, T: 'a, V: AddressValue, A: Addressable<V, DIMENSION>, const
     /// * `address`: The address of the value to be retrieved
     ///
     /// Returns: `Option<&mut T>`, A mutable reference to the value if it exists.
-    fn get_mut(&mut self, address: A) -> Option<&mut T> {
+    fn get_mut(&mut self, address: A) -> Option<&mut T>
+    where
+        Self: IndexMut<A, Output = T>,
+    {
         if self.contains_address(address) {
             Some(&mut self[address])
         } else {
@@ -75,10 +78,143 @@ pub trait Tensor<'a, T: 'a, V: AddressValue, A: Addressable<V, DIMENSION>, const
         )
     }
 
+    /// Finds the first address, in the order of `address_iter`, whose value satisfies a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate`: Whether a value is the one being searched for
+    ///
+    /// Returns: `Option<A>`, None if no value satisfies the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::matrix_address::MatrixAddress;
+    /// use rust_tensors::tensor::Tensor;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1, 8, 3], vec![9, 2, 7]]).unwrap();
+    /// assert_eq!(matrix.find_address(|&value| value > 5), Some(MatrixAddress { x: 1, y: 0 }));
+    /// assert_eq!(matrix.find_address(|&value| value > 9), None);
+    /// ```
+    fn find_address<F: Fn(&T) -> bool>(&self, predicate: F) -> Option<A> {
+        self.address_iter()
+            .find(|&address| predicate(&self[address]))
+    }
+
+    /// Creates an iterator over every address, in the order of `address_iter`, whose value
+    /// satisfies a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate`: Whether a value is one being searched for
+    ///
+    /// Returns: `impl Iterator<Item = A>`
+    fn find_all_addresses<F: Fn(&T) -> bool>(&self, predicate: F) -> impl Iterator<Item = A> {
+        self.address_iter()
+            .filter(move |&address| predicate(&self[address]))
+    }
+
+    /// Finds the first address, in the order of `address_iter`, holding a value equal to `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The value being searched for
+    ///
+    /// Returns: `Option<A>`, None if no value is equal.
+    fn position_of(&self, value: &T) -> Option<A>
+    where
+        T: PartialEq,
+    {
+        self.find_address(|candidate| candidate == value)
+    }
+
     fn address_value_iter(&'a self) -> AddressValueIterator<'a, T, V, A, Self, DIMENSION>
     where
         Self: Sized,
     {
-        AddressValueIterator::<'a, T, V, A, Self, DIMENSION>::new(&self)
+        AddressValueIterator::<'a, T, V, A, Self, DIMENSION>::new(self)
+    }
+
+    /// Combines every value into an accumulator, in the order of `address_iter`.
+    ///
+    /// # Arguments
+    ///
+    /// * `init`: The initial value of the accumulator
+    /// * `f`: Combines the accumulator with a value
+    ///
+    /// Returns: `B`, The final value of the accumulator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::tensor::Tensor;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec!["a", "b"], vec!["c", "d"]]).unwrap();
+    /// assert_eq!(matrix.fold_values(String::new(), |text, value| text + value), "abcd");
+    /// ```
+    fn fold_values<B, F: FnMut(B, &T) -> B>(&'a self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+    {
+        self.address_value_iter()
+            .fold(init, |accumulator, (_, value)| f(accumulator, value))
+    }
+
+    /// Counts the values which satisfy a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate`: Whether a value is counted
+    ///
+    /// Returns: `usize`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::matrix::Matrix;
+    /// use rust_tensors::tensor::Tensor;
+    ///
+    /// let board = Matrix::from_rows(vec![vec![true, false], vec![true, true]]).unwrap();
+    /// assert_eq!(board.count_matching(|&alive| alive), 3);
+    /// ```
+    fn count_matching<F: Fn(&T) -> bool>(&'a self, predicate: F) -> usize
+    where
+        Self: Sized,
+    {
+        self.address_value_iter()
+            .filter(|(_, value)| predicate(value))
+            .count()
+    }
+
+    /// Whether any value satisfies a predicate, stopping at the first one which does. False for a
+    /// tensor without any values.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate`: Whether a value is the one being searched for
+    ///
+    /// Returns: `bool`
+    fn any_value<F: Fn(&T) -> bool>(&'a self, predicate: F) -> bool
+    where
+        Self: Sized,
+    {
+        self.address_value_iter().any(|(_, value)| predicate(value))
+    }
+
+    /// Whether every value satisfies a predicate, stopping at the first one which does not. True for
+    /// a tensor without any values.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate`: The condition every value should satisfy
+    ///
+    /// Returns: `bool`
+    fn all_values<F: Fn(&T) -> bool>(&'a self, predicate: F) -> bool
+    where
+        Self: Sized,
+    {
+        self.address_value_iter().all(|(_, value)| predicate(value))
     }
 }
@@ -0,0 +1,307 @@
+use crate::address_3d::Address3D;
+use crate::matrix_error::DimensionMismatchError;
+use crate::tensor::Tensor;
+use std::ops::{Index, IndexMut};
+
+/// A three-dimensional tensor, stored as layers of rows in a flat row-major vector.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Tensor3D<T> {
+    width: usize,
+    height: usize,
+    depth: usize,
+    data: Vec<T>,
+}
+
+impl<T> Tensor3D<T> {
+    /// Creates a new Tensor3D based on dimensions and a mapper function.
+    /// Will return None if and only if the width, height or depth are equal to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `width`: The number of values along the x dimension
+    /// * `height`: The number of values along the y dimension
+    /// * `depth`: The number of values along the z dimension
+    /// * `address_value_converter`: Converts an address to a value.
+    ///
+    /// Returns: `Option<Tensor3D<T>>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::address_3d::Address3D;
+    /// use rust_tensors::tensor_3d::Tensor3D;
+    ///
+    /// let tensor = Tensor3D::new(4, 3, 2, |address| address.x + address.y + address.z).unwrap();
+    /// assert_eq!(tensor[Address3D { x: 3, y: 2, z: 1 }], 6);
+    /// ```
+    pub fn new<F>(
+        width: usize,
+        height: usize,
+        depth: usize,
+        address_value_converter: F,
+    ) -> Option<Self>
+    where
+        F: Fn(Address3D) -> T,
+    {
+        if width == 0 || height == 0 || depth == 0 {
+            return None;
+        }
+        let mut tensor = Tensor3D {
+            width,
+            height,
+            depth,
+            data: Vec::with_capacity(width * height * depth),
+        };
+        tensor
+            .address_iter()
+            .for_each(|address| tensor.data.push(address_value_converter(address)));
+        Some(tensor)
+    }
+
+    /// Creates a tensor from nested layers, where `layers[z][y][x]` is the value at `(x, y, z)`.
+    /// Fallible, and will return an Err if any dimension is empty or the nesting is not uniform.
+    ///
+    /// # Arguments
+    ///
+    /// * `layers`: The layers of the tensor, each a list of rows
+    ///
+    /// Returns: `Result<Tensor3D<T>, DimensionMismatchError>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::tensor_3d::Tensor3D;
+    ///
+    /// let tensor = Tensor3D::parse(&[[[0, 1], [2, 3]], [[4, 5], [6, 7]]]).unwrap();
+    /// assert_eq!(tensor, Tensor3D::new(2, 2, 2, |a| a.x + 2 * a.y + 4 * a.z).unwrap());
+    /// ```
+    pub fn parse<L: AsRef<[R]>, R: AsRef<[T]>>(
+        layers: &[L],
+    ) -> Result<Tensor3D<T>, DimensionMismatchError>
+    where
+        T: Clone,
+    {
+        let depth = layers.len();
+        let height = layers.first().map_or(0, |layer| layer.as_ref().len());
+        let width = layers
+            .first()
+            .and_then(|layer| layer.as_ref().first())
+            .map_or(0, |row| row.as_ref().len());
+        if width == 0 || height == 0 || depth == 0 {
+            return Err(DimensionMismatchError {
+                message: "Tensor dimensions must be non-zero".into(),
+            });
+        }
+        let uniform = layers.iter().all(|layer| {
+            layer.as_ref().len() == height
+                && layer.as_ref().iter().all(|row| row.as_ref().len() == width)
+        });
+        if !uniform {
+            return Err(DimensionMismatchError {
+                message: "Layer or row lengths are not constant".into(),
+            });
+        }
+        Ok(Tensor3D {
+            width,
+            height,
+            depth,
+            data: layers
+                .iter()
+                .flat_map(|layer| layer.as_ref().iter())
+                .flat_map(|row| row.as_ref().iter().cloned())
+                .collect(),
+        })
+    }
+
+    /// Finds the position of an address in the backing storage of the tensor, where x varies
+    /// fastest and z slowest.
+    ///
+    /// # Arguments
+    ///
+    /// * `address`: The address to locate
+    ///
+    /// Returns: `Option<usize>`, None if the address is not contained in the tensor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_tensors::address_3d::Address3D;
+    /// use rust_tensors::tensor_3d::Tensor3D;
+    ///
+    /// let tensor = Tensor3D::new(4, 3, 2, |_| 0).unwrap();
+    /// assert_eq!(tensor.index_of(Address3D { x: 1, y: 2, z: 1 }), Some(21));
+    /// assert_eq!(tensor.index_of(Address3D { x: 1, y: 3, z: 0 }), None);
+    /// ```
+    pub fn index_of(&self, address: Address3D) -> Option<usize> {
+        self.contains_address(address).then(|| {
+            (address.z as usize * self.height + address.y as usize) * self.width
+                + address.x as usize
+        })
+    }
+
+    /// Finds the address of a position in the backing storage of the tensor, the inverse of
+    /// `index_of`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The position in the backing storage
+    ///
+    /// Returns: `Option<Address3D>`, None if the index is not smaller than the number of values.
+    pub fn address_of(&self, index: usize) -> Option<Address3D> {
+        (index < self.data.len()).then(|| Address3D {
+            x: (index % self.width) as i32,
+            y: (index / self.width % self.height) as i32,
+            z: (index / (self.width * self.height)) as i32,
+        })
+    }
+
+    fn index_address(&self, address: Address3D) -> usize {
+        self.index_of(address).unwrap_or_else(|| {
+            panic!(
+                "Address ({}, {}, {}) is out of bounds for a {}x{}x{} tensor",
+                address.x, address.y, address.z, self.width, self.height, self.depth
+            )
+        })
+    }
+}
+
+impl<'a, T: 'a> Tensor<'a, T, i32, Address3D, 3> for Tensor3D<T> {
+    fn smallest_contained_address(&self) -> Address3D {
+        Address3D { x: 0, y: 0, z: 0 }
+    }
+
+    fn largest_contained_address(&self) -> Address3D {
+        Address3D {
+            x: (self.width - 1) as i32,
+            y: (self.height - 1) as i32,
+            z: (self.depth - 1) as i32,
+        }
+    }
+}
+
+impl<T> Index<Address3D> for Tensor3D<T> {
+    type Output = T;
+
+    fn index(&self, index: Address3D) -> &Self::Output {
+        &self.data[self.index_address(index)]
+    }
+}
+
+impl<T> IndexMut<Address3D> for Tensor3D<T> {
+    fn index_mut(&mut self, index: Address3D) -> &mut Self::Output {
+        let index = self.index_address(index);
+        &mut self.data[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::address_3d::Address3D;
+    use crate::tensor::Tensor;
+    use crate::tensor_3d::Tensor3D;
+    use proptest::proptest;
+
+    #[test]
+    fn find_address_test() {
+        let tensor =
+            Tensor3D::new(3, 2, 2, |address| (address.x + address.y + address.z) % 3).unwrap();
+        assert_eq!(
+            tensor.find_address(|&value| value == 2),
+            Some(Address3D { x: 2, y: 0, z: 0 })
+        );
+        assert_eq!(
+            tensor
+                .find_all_addresses(|&value| value == 0)
+                .collect::<Vec<_>>(),
+            tensor
+                .address_iter()
+                .filter(|address| tensor[*address] == 0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(tensor.position_of(&1), Some(Address3D { x: 1, y: 0, z: 0 }));
+        assert_eq!(tensor.position_of(&3), None);
+        assert_eq!(tensor.count_matching(|&value| value == 0), 4);
+        assert!(tensor.any_value(|&value| value == 2));
+        assert!(tensor.all_values(|&value| value < 3));
+        assert!(!tensor.all_values(|&value| value < 2));
+        assert_eq!(tensor.fold_values(0, |count, _| count + 1), 12);
+        assert_eq!(tensor.fold_values(0, |sum, value| sum + value), 12);
+    }
+
+    #[test]
+    fn index_order_test() {
+        let (width, height, depth) = (5, 4, 3);
+        let tensor = Tensor3D::new(width, height, depth, |address| {
+            (address.z * height as i32 + address.y) * width as i32 + address.x
+        })
+        .unwrap();
+        assert_eq!(
+            tensor.data,
+            (0..(width * height * depth) as i32).collect::<Vec<i32>>()
+        );
+        tensor
+            .address_iter()
+            .enumerate()
+            .for_each(|(i, address)| assert_eq!(tensor[address], i as i32));
+        assert_eq!(tensor.address_iter().count(), width * height * depth);
+    }
+
+    #[test]
+    #[should_panic(expected = "Address (3, 0, 0) is out of bounds for a 3x2x2 tensor")]
+    fn index_out_of_bounds_test() {
+        let tensor = Tensor3D::new(3, 2, 2, |_| 0).unwrap();
+        let _ = tensor[Address3D { x: 3, y: 0, z: 0 }];
+    }
+
+    #[test]
+    fn set_test() {
+        let mut tensor = Tensor3D::new(6, 5, 4, |_| 0usize).unwrap();
+        let addresses = tensor.address_iter().collect::<Vec<Address3D>>();
+        for address in addresses {
+            tensor[address] = tensor.index_address(address);
+        }
+        tensor
+            .address_value_iter()
+            .for_each(|(address, value)| assert_eq!(*value, tensor.index_address(address)));
+    }
+
+    #[test]
+    fn parse_test() {
+        let layers = vec![
+            vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']],
+            vec![vec!['g', 'h', 'i'], vec!['j', 'k', 'l']],
+        ];
+        let tensor = Tensor3D::parse(&layers).unwrap();
+        assert_eq!(tensor[Address3D { x: 2, y: 0, z: 0 }], 'c');
+        assert_eq!(tensor[Address3D { x: 0, y: 1, z: 1 }], 'j');
+        assert_eq!(
+            tensor.largest_contained_address(),
+            Address3D { x: 2, y: 1, z: 1 }
+        );
+
+        assert!(Tensor3D::parse(&[vec![vec![1, 2], vec![3]]]).is_err());
+        assert!(Tensor3D::parse(&[vec![vec![1, 2]], vec![vec![3, 4], vec![5, 6]]]).is_err());
+        assert!(Tensor3D::<i32>::parse::<Vec<Vec<i32>>, Vec<i32>>(&[]).is_err());
+        assert!(Tensor3D::new(0, 1, 1, |_| 0).is_none());
+    }
+
+    proptest! {
+        #[test]
+        fn contains_address_test(x in -10..10, y in -10..10, z in -10..10, width in 1usize..8, height in 1usize..8, depth in 1usize..8) {
+            let tensor = Tensor3D::new(width, height, depth, |_| 0u8).unwrap();
+            let address = Address3D { x, y, z };
+            let inside = x >= 0 && y >= 0 && z >= 0 && x < width as i32 && y < height as i32 && z < depth as i32;
+            assert_eq!(tensor.contains_address(address), inside);
+            assert_eq!(tensor.get(address).is_some(), inside);
+        }
+
+        #[test]
+        fn arithmetic_test(x1 in -1000i32..1000, y1 in -1000i32..1000, z1 in -1000i32..1000, x2 in -1000i32..1000, y2 in -1000i32..1000, z2 in -1000i32..1000) {
+            let a1 = Address3D { x: x1, y: y1, z: z1 };
+            let a2 = Address3D { x: x2, y: y2, z: z2 };
+            assert_eq!(a1 - a2, a1 + (-a2));
+            assert_eq!(a1 + a2 - a2, a1);
+            assert_eq!(Address3D::from(<[i32; 3]>::from(a1)), a1);
+        }
+    }
+}